@@ -143,6 +143,22 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_mean_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        B::int_prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_prod_dim(tensor, dim)
+    }
+
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_cumprod(tensor, dim)
+    }
+
     fn int_repeat<const D: usize>(
         tensor: IntTensor<B, D>,
         dim: usize,
@@ -281,4 +297,25 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         B::int_min_dim_with_indexes(tensor, dim)
     }
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+    fn int_sort_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
 }
@@ -963,6 +963,133 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn prod<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, 1> {
+        #[derive(Debug)]
+        struct Prod<const D: usize>;
+
+        impl<B: Backend, const D: usize> Backward<B, 1, 1> for Prod<D> {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let input = ops.state;
+
+                unary::<B, 1, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&input);
+                    let input = B::reshape(input, Shape::new([shape.num_elements()]));
+                    let grad = B::mul(product_of_others::<B, 1>(input, 0), grad);
+
+                    B::reshape(grad, shape)
+                });
+            }
+        }
+
+        match Prod.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::prod(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::prod(tensor.primitive)),
+        }
+    }
+
+    fn prod_dim<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct ProdDim;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for ProdDim {
+            type State = (B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::mul(product_of_others::<B, D>(input, dim), grad)
+                });
+            }
+        }
+
+        match ProdDim.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), dim),
+                B::prod_dim(tensor.primitive, dim),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::prod_dim(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumsum<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cumsum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cumsum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match Cumsum.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumprod<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cumprod;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cumprod {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The positions after the first zero don't contribute to any output, the
+                    // positions before it can use d(y_j)/dx_i = y_j / x_i for j >= i.
+                    let zeros = B::equal_elem(input.clone(), 0.elem());
+                    let zeros_count = B::cumsum(
+                        B::mask_fill(
+                            B::zeros(B::shape(&input), &B::device(&input)),
+                            zeros.clone(),
+                            1.elem(),
+                        ),
+                        dim,
+                    );
+                    let before_zero = B::lower_elem(zeros_count.clone(), 0.5.elem());
+                    let first_zero =
+                        B::bool_and(zeros.clone(), B::equal_elem(zeros_count, 1.elem()));
+
+                    let input_safe = B::mask_fill(input.clone(), zeros, 1.elem());
+                    let grad_before = B::div(
+                        reverse_cumsum::<B, D>(B::mul(grad.clone(), output), dim),
+                        input_safe,
+                    );
+                    let grad_before = B::mask_fill(grad_before, B::bool_not(before_zero), 0.elem());
+
+                    // The first zero gets the sum of the outputs computed without it.
+                    let output_without =
+                        B::cumprod(B::mask_fill(input, first_zero.clone(), 1.elem()), dim);
+                    let grad_first = reverse_cumsum::<B, D>(B::mul(grad, output_without), dim);
+                    let grad_first = B::mask_fill(grad_first, B::bool_not(first_zero), 0.elem());
+
+                    B::add(grad_before, grad_first)
+                });
+            }
+        }
+
+        let output = B::cumprod(tensor.primitive.clone(), dim);
+
+        match Cumprod.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish((tensor.primitive, output.clone(), dim), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
     fn to_full_precision<const D: usize>(
         tensor: &ADTensor<B, D>,
     ) -> ADTensor<B::FullPrecisionBackend, D> {
@@ -1248,6 +1375,264 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn abs<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Abs;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Abs {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The sign is zero at the kink, which is a valid subgradient.
+                    B::mul(grad, B::sign(ops.state))
+                });
+            }
+        }
+
+        match Abs.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::abs(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::abs(tensor.primitive)),
+        }
+    }
+
+    fn sign<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGradient
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::sign(tensor.primitive))
+    }
+
+    fn floor<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGradient
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::floor(tensor.primitive))
+    }
+
+    fn ceil<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGradient
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::ceil(tensor.primitive))
+    }
+
+    fn round<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGradient
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::round(tensor.primitive))
+    }
+
+    fn recip<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Recip;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Recip {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::neg(B::powf(ops.state, 2.0));
+
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        let output = B::recip(tensor.primitive);
+
+        match Recip.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(output.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn clamp_min<const D: usize>(tensor: ADTensor<B, D>, min: FloatElem<B>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct ClampMin;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for ClampMin {
+            type State = (B::TensorPrimitive<D>, FloatElem<B>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (tensor, min) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient flows through the values equal to the bound.
+                    let mask = B::lower_elem(tensor, min);
+
+                    B::mask_fill(grad, mask, 0.elem())
+                });
+            }
+        }
+
+        match ClampMin.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), min),
+                B::clamp_min(tensor.primitive, min),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::clamp_min(tensor.primitive, min)),
+        }
+    }
+
+    fn clamp_max<const D: usize>(tensor: ADTensor<B, D>, max: FloatElem<B>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct ClampMax;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for ClampMax {
+            type State = (B::TensorPrimitive<D>, FloatElem<B>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (tensor, max) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient flows through the values equal to the bound.
+                    let mask = B::greater_elem(tensor, max);
+
+                    B::mask_fill(grad, mask, 0.elem())
+                });
+            }
+        }
+
+        match ClampMax.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), max),
+                B::clamp_max(tensor.primitive, max),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::clamp_max(tensor.primitive, max)),
+        }
+    }
+
+    fn clamp<const D: usize>(
+        tensor: ADTensor<B, D>,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Clamp;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Clamp {
+            type State = (B::TensorPrimitive<D>, FloatElem<B>, FloatElem<B>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (tensor, min, max) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient flows through the values equal to the bounds.
+                    let grad = B::mask_fill(grad, B::lower_elem(tensor.clone(), min), 0.elem());
+
+                    B::mask_fill(grad, B::greater_elem(tensor, max), 0.elem())
+                });
+            }
+        }
+
+        match Clamp.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), min, max),
+                B::clamp(tensor.primitive, min, max),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::clamp(tensor.primitive, min, max)),
+        }
+    }
+
+    fn atan2<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Atan2 {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, rhs) = ops.state;
+                let (shape_lhs, shape_rhs) = (B::shape(&lhs), B::shape(&rhs));
+                let denominator = B::add(B::powf(lhs.clone(), 2.0), B::powf(rhs.clone(), 2.0));
+                let [denominator_4lhs, denominator_4rhs] =
+                    duplicate(&ops.parents, Some(denominator));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let value = B::div(rhs, denominator_4lhs.unwrap());
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_lhs)
+                    },
+                    |grad| {
+                        let value = B::div(B::neg(lhs), denominator_4rhs.unwrap());
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_rhs)
+                    },
+                );
+            }
+        }
+
+        match Atan2
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (lhs.primitive.clone(), rhs.primitive.clone()),
+                B::atan2(lhs.primitive, rhs.primitive),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn pow<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Pow;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Pow {
+            type State = (
+                B::TensorPrimitive<D>,
+                B::TensorPrimitive<D>,
+                B::TensorPrimitive<D>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, rhs, output) = ops.state;
+                let (shape_lhs, shape_rhs) = (B::shape(&lhs), B::shape(&rhs));
+                let [lhs_4lhs, lhs_4rhs] = duplicate(&ops.parents, Some(lhs));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // A null exponent has a null gradient, even when the base is zero.
+                        let exponent = B::sub_scalar(rhs.clone(), 1.elem());
+                        let exponent =
+                            B::mask_fill(exponent, B::equal_elem(rhs.clone(), 0.elem()), 0.elem());
+                        let value = B::mul(rhs, B::pow(lhs_4lhs.unwrap(), exponent));
+
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_lhs)
+                    },
+                    |grad| {
+                        // A null base has a null gradient with respect to the exponent.
+                        let lhs = lhs_4rhs.unwrap();
+                        let log = B::mask_fill(
+                            B::log(lhs.clone()),
+                            B::equal_elem(lhs, 0.elem()),
+                            0.elem(),
+                        );
+                        let value = B::mul(output, log);
+
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_rhs)
+                    },
+                );
+            }
+        }
+
+        match Pow
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::pow(lhs.primitive.clone(), rhs.primitive.clone());
+                prep.finish((lhs.primitive, rhs.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::pow(lhs.primitive, rhs.primitive)),
+        }
+    }
+
     fn cat<const D: usize>(tensors: Vec<ADTensor<B, D>>, dim: usize) -> ADTensor<B, D> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend, const D: usize> {
@@ -1374,6 +1759,49 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             }
         }
     }
+
+    fn sort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> ADTensor<B, D> {
+        Self::sort_with_indices(tensor, dim, descending).0
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        // The gradient flows back to the original positions through the gather.
+        let indices = B::argsort(tensor.primitive.clone(), dim, descending);
+        let tensor = Self::gather(dim, tensor, indices.clone());
+
+        (tensor, indices)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::argsort(tensor.primitive, dim, descending)
+    }
+}
+
+/// Backward step of the piecewise constant operations, which have a null gradient everywhere
+/// they are differentiable.
+#[derive(Debug)]
+struct ZeroGradient;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for ZeroGradient {
+    type State = ();
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            B::zeros(B::shape(&grad), &B::device(&grad))
+        });
+    }
 }
 
 /// Make sure the grad tensor has the given shape.
@@ -1400,3 +1828,67 @@ fn broadcast_shape<B: Backend, const D: usize>(
 
     grad
 }
+
+/// Reverses the order of the elements along the given dimension.
+fn flip<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let size = B::shape(&tensor).dims[dim];
+    let indexes = (0..size).rev().map(|index| (index as i64).elem()).collect();
+    let indexes = B::int_from_data(Data::new(indexes, Shape::new([size])), &B::device(&tensor));
+
+    B::index_select(tensor, dim, indexes)
+}
+
+/// Cumulative sum starting from the end of the given dimension.
+///
+/// Each element is the sum of all the elements from its position up to the end of `dim`.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    flip::<B, D>(B::cumsum(flip::<B, D>(tensor, dim), dim), dim)
+}
+
+/// Cumulative product excluding the current element, the first element being one.
+fn exclusive_cumprod<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let shape = B::shape(&tensor);
+    let size = shape.dims[dim];
+
+    if size == 0 {
+        return tensor;
+    }
+
+    let mut dims = shape.dims;
+    dims[dim] = 1;
+    let ones = B::ones(Shape::new(dims), &B::device(&tensor));
+
+    if size == 1 {
+        return ones;
+    }
+
+    let mut ranges = shape.dims.map(|size| 0..size);
+    ranges[dim] = 0..size - 1;
+    let cumprod = B::index(B::cumprod(tensor, dim), ranges);
+
+    B::cat(vec![ones, cumprod], dim)
+}
+
+/// Product of all the other elements along the given dimension, computed without any division so
+/// that zeros are supported.
+fn product_of_others<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let before = exclusive_cumprod::<B, D>(tensor.clone(), dim);
+    let after = flip::<B, D>(
+        exclusive_cumprod::<B, D>(flip::<B, D>(tensor, dim), dim),
+        dim,
+    );
+
+    B::mul(before, after)
+}
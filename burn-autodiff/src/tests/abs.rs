@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(ad_abs)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_abs() {
+        let data_1 = Data::<f32, 2>::from([[-1.0, 2.0], [0.0, -3.0]]);
+        let data_2 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().abs().mul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[-1.0, 2.0], [0.0, -4.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[1.0, 2.0], [0.0, 3.0]]));
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::adaptive_max_pool2d, Data};
+
+    #[test]
+    fn test_adaptive_max_pool2d_dyn_window() {
+        let x = TestADTensor::from_floats([[
+            [
+                [0.0000, 0.9917, -0.2555, -0.9258],
+                [0.4941, 0.7985, -0.6999, -0.6181],
+                [0.8592, 0.3967, -0.9614, -0.1490],
+                [0.9998, -0.1086, -0.9718, 0.3591],
+                [0.8793, -0.5856, -0.7284, 0.7733],
+            ],
+            [
+                [0.5291, -0.9097, -0.2947, 0.9856],
+                [0.0407, -0.9961, 0.2160, 0.9404],
+                [-0.4583, -0.8223, 0.6702, 0.6496],
+                [-0.8376, -0.4338, 0.9494, 0.1891],
+                [-0.9981, 0.0681, 0.9806, -0.3208],
+            ],
+        ]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[
+            [
+                [0., 2., 1., 0.],
+                [0., 1., 0., 0.],
+                [0., 0., 0., 0.],
+                [2., 1., 0., 1.],
+                [0., 0., 0., 1.],
+            ],
+            [
+                [1., 0., 0., 1.],
+                [1., 0., 1., 0.],
+                [0., 0., 0., 0.],
+                [0., 0., 2., 0.],
+                [0., 1., 2., 0.],
+            ],
+        ]]);
+
+        let output = adaptive_max_pool2d(x.clone(), [3, 3]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
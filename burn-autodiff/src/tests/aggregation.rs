@@ -118,4 +118,86 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[18.0, 18.0], [71.0, 71.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_prod() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().prod();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[24.0, 12.0], [8.0, 6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_dim() {
+        let tensor_1 =
+            TestADTensor::from_floats([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .prod_dim(1)
+            .mul(TestADTensor::from_floats([[1.0], [2.0]]));
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 3.0, 2.0], [4.0, -8.0, -1.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_with_zeros() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 0.0], [3.0, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().prod();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 12.0], [0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_dim_with_zeros() {
+        let tensor_1 = TestADTensor::from_floats([[2.0, 0.0, 3.0], [0.0, 0.0, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .prod_dim(1)
+            .mul(TestADTensor::from_floats([[1.0], [2.0]]));
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 6.0, 0.0], [0.0, 0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_logsumexp() {
+        let tensor_1 =
+            TestADTensor::from_floats([[0.0, 1.0, 2.0], [1000.0, 1000.0, 1000.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().logsumexp(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([
+                [0.090031, 0.244728, 0.665241],
+                [1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0],
+            ]),
+            4,
+        );
+    }
 }
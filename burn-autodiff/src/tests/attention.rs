@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::AttentionOptions;
+    use burn_tensor::{activation, Bool, Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_scaled_dot_product_attention_grad() {
+        let test = AttentionTestCase { causal: false };
+
+        test.assert_grads_match_ops();
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_causal_mask_grad() {
+        let test = AttentionTestCase { causal: true };
+
+        test.assert_grads_match_ops();
+    }
+
+    struct AttentionTestCase {
+        causal: bool,
+    }
+
+    impl AttentionTestCase {
+        fn assert_grads_match_ops(self) {
+            let [batch_size, n_heads, seq_length, d_k] = [2, 3, 4, 5];
+            let distribution = Distribution::Uniform(-1.0, 1.0);
+            let shape = [batch_size, n_heads, seq_length, d_k];
+            let query = TestTensor::random(shape, distribution);
+            let key = TestTensor::random(shape, distribution);
+            let value = TestTensor::random(shape, distribution);
+            let target = TestTensor::random(shape, distribution);
+            let mask = match self.causal {
+                true => {
+                    let mask: Vec<bool> = (0..seq_length * seq_length)
+                        .map(|index| index % seq_length > index / seq_length)
+                        .collect();
+
+                    Some(Tensor::<TestADBackend, 4, Bool>::from_data(Data::new(
+                        mask,
+                        Shape::new([1, 1, seq_length, seq_length]),
+                    )))
+                }
+                false => None,
+            };
+
+            let grads_op = Self::grads(&query, &key, &value, &target, |q, k, v| {
+                scaled_dot_product_attention(
+                    q,
+                    k,
+                    v,
+                    mask.clone(),
+                    AttentionOptions::new(0.4, -1.0e4),
+                )
+            });
+            let grads_ops = Self::grads(&query, &key, &value, &target, |q, k, v| {
+                let scores = q.matmul(k.transpose()).mul_scalar(0.4);
+                let scores = match mask.clone() {
+                    Some(mask) => scores.mask_fill(mask, -1.0e4),
+                    None => scores,
+                };
+
+                activation::softmax(scores, 3).matmul(v)
+            });
+
+            for (grad_op, grad_ops) in grads_op.into_iter().zip(grads_ops) {
+                grad_op
+                    .into_data()
+                    .assert_approx_eq(&grad_ops.into_data(), 3);
+            }
+        }
+
+        fn grads<F>(
+            query: &TestTensor<4>,
+            key: &TestTensor<4>,
+            value: &TestTensor<4>,
+            target: &TestTensor<4>,
+            attention: F,
+        ) -> [TestTensor<4>; 3]
+        where
+            F: Fn(
+                Tensor<TestADBackend, 4>,
+                Tensor<TestADBackend, 4>,
+                Tensor<TestADBackend, 4>,
+            ) -> Tensor<TestADBackend, 4>,
+        {
+            let query = TestADTensor::from_data(query.to_data()).require_grad();
+            let key = TestADTensor::from_data(key.to_data()).require_grad();
+            let value = TestADTensor::from_data(value.to_data()).require_grad();
+            let target = TestADTensor::from_data(target.to_data());
+
+            let output = attention(query.clone(), key.clone(), value.clone());
+            let grads = output.mul(target).sum().backward();
+
+            [
+                query.grad(&grads).unwrap(),
+                key.grad(&grads).unwrap(),
+                value.grad(&grads).unwrap(),
+            ]
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_clamp)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_clamp() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().clamp(1.0, 4.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[0.0, 1.0, 1.0], [1.0, 1.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_clamp_min() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().clamp_min(2.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[0.0, 0.0, 1.0], [1.0, 1.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_clamp_max() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().clamp_max(2.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[1.0, 1.0, 1.0], [0.0, 0.0, 0.0]])
+        );
+    }
+}
@@ -0,0 +1,265 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Data, Shape};
+
+    #[test]
+    fn test_conv3d_basic() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[420., 420.], [420., 420.]], [[420., 420.], [420., 420.]]],
+                    [[[708., 708.], [708., 708.]], [[708., 708.], [708., 708.]]],
+                ],
+                [
+                    [[[420., 420.], [420., 420.]], [[420., 420.], [420., 420.]]],
+                    [[[708., 708.], [708., 708.]], [[708., 708.], [708., 708.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([72., 72.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_stride_padding_dilation() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 1,
+            padding_1: 0,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 1,
+            depth: 4,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[0., 24., 0.], [0., 51., 0.], [0., 27., 0.]],
+                    [[0., 30., 0.], [0., 63., 0.], [0., 33., 0.]],
+                    [[0., 24., 0.], [0., 51., 0.], [0., 27., 0.]],
+                    [[0., 30., 0.], [0., 63., 0.], [0., 33., 0.]],
+                ],
+                [
+                    [[0., 36., 0.], [0., 75., 0.], [0., 39., 0.]],
+                    [[0., 42., 0.], [0., 87., 0.], [0., 45., 0.]],
+                    [[0., 36., 0.], [0., 75., 0.], [0., 39., 0.]],
+                    [[0., 42., 0.], [0., 87., 0.], [0., 45., 0.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[46.], [58.]], [[82.], [94.]]],
+                    [[[190.], [202.]], [[226.], [238.]]],
+                ],
+                [
+                    [[[46.], [58.]], [[82.], [94.]]],
+                    [[[190.], [202.]], [[226.], [238.]]],
+                ],
+                [
+                    [[[46.], [58.]], [[82.], [94.]]],
+                    [[[190.], [202.]], [[226.], [238.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([18., 18., 18.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 1,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 2,
+            height: 2,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[4., 10., 6.], [8., 18., 10.]],
+                    [[4., 10., 6.], [8., 18., 10.]],
+                ],
+                [
+                    [[20., 42., 22.], [24., 50., 26.]],
+                    [[20., 42., 22.], [24., 50., 26.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[[14., 18.], [26., 30.]]]],
+                [[[[14., 18.], [26., 30.]]]],
+                [[[[62., 66.], [74., 78.]]]],
+                [[[[62., 66.], [74., 78.]]]],
+            ]),
+            bias: TestTensor::from_floats([8., 8., 8., 8.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose1d, ops::ConvTransposeOptions, Data, Shape};
+
+    #[test]
+    fn test_conv_transpose1d_basic() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+                [[15., 15., 15., 15.], [51., 51., 51., 51.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[44., 44., 44.], [44., 44., 44.]],
+                [[76., 76., 76.], [76., 76., 76.]],
+            ]),
+            bias: TestTensor::from_floats([12., 12.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_different_channels() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size: 3,
+            padding: 1,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[27., 36., 36., 21.], [81., 117., 117., 75.]],
+                [[27., 36., 36., 21.], [81., 117., 117., 75.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[36., 44., 30.], [36., 44., 30.], [36., 44., 30.]],
+                [[60., 76., 54.], [60., 76., 54.], [60., 76., 54.]],
+            ]),
+            bias: TestTensor::from_floats([8., 8., 8.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_stride_padding_out() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 1,
+            padding_out: 1,
+            stride: 2,
+            dilation: 1,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[12., 15., 15., 15.], [36., 51., 51., 51.]],
+                [[12., 15., 15., 15.], [36., 51., 51., 51.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[36., 44., 44.], [36., 44., 44.]],
+                [[60., 76., 76.], [60., 76., 76.]],
+            ]),
+            bias: TestTensor::from_floats([16., 16.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_dilation() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 1,
+            padding_out: 0,
+            stride: 1,
+            dilation: 2,
+            groups: 1,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[12., 15., 15., 8.], [36., 51., 51., 32.]],
+                [[12., 15., 15., 8.], [36., 51., 51., 32.]],
+            ]),
+            weight: TestTensor::from_floats([
+                [[36., 44., 30.], [36., 44., 30.]],
+                [[60., 76., 54.], [60., 76., 54.]],
+            ]),
+            bias: TestTensor::from_floats([12., 12.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose1d_groups() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 1,
+            padding_out: 0,
+            stride: 1,
+            dilation: 1,
+            groups: 2,
+            length: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [[3., 3., 3., 1.], [9., 12., 12., 7.]],
+                [[3., 3., 3., 1.], [9., 12., 12., 7.]],
+            ]),
+            weight: TestTensor::from_floats([[[36., 44., 30.]], [[60., 76., 54.]]]),
+            bias: TestTensor::from_floats([8., 8.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose1dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: usize,
+        padding: usize,
+        padding_out: usize,
+        stride: usize,
+        dilation: usize,
+        groups: usize,
+        length: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<3>,
+        weight: TestTensor<3>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose1dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.length]);
+            let shape_weight = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+
+            let output = conv_transpose1d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    [self.stride],
+                    [self.padding],
+                    [self.padding_out],
+                    [self.dilation],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose2d, ops::ConvTransposeOptions, Data, Shape};
+
+    #[test]
+    fn test_conv_transpose2d_basic() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 0,
+            padding_2: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 4,
+            width: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                    ],
+                    [
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                    ],
+                ],
+                [
+                    [
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                        [153., 153., 153., 153.],
+                    ],
+                    [
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                        [477., 477., 477., 477.],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[752., 752., 752.], [752., 752., 752.], [752., 752., 752.]],
+                    [[752., 752., 752.], [752., 752., 752.], [752., 752., 752.]],
+                ],
+                [
+                    [
+                        [1264., 1264., 1264.],
+                        [1264., 1264., 1264.],
+                        [1264., 1264., 1264.],
+                    ],
+                    [
+                        [1264., 1264., 1264.],
+                        [1264., 1264., 1264.],
+                        [1264., 1264., 1264.],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([72., 72.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_different_channels_and_kernels() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 4,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [90., 90., 90., 90.],
+                        [153., 153., 153., 153.],
+                        [63., 63., 63., 63.],
+                    ],
+                    [
+                        [252., 252., 252., 252.],
+                        [477., 477., 477., 477.],
+                        [225., 225., 225., 225.],
+                    ],
+                ],
+                [
+                    [
+                        [90., 90., 90., 90.],
+                        [153., 153., 153., 153.],
+                        [63., 63., 63., 63.],
+                    ],
+                    [
+                        [252., 252., 252., 252.],
+                        [477., 477., 477., 477.],
+                        [225., 225., 225., 225.],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[312., 312., 312.], [248., 248., 248.]],
+                    [[312., 312., 312.], [248., 248., 248.]],
+                    [[312., 312., 312.], [248., 248., 248.]],
+                ],
+                [
+                    [[504., 504., 504.], [440., 440., 440.]],
+                    [[504., 504., 504.], [440., 440., 440.]],
+                    [[504., 504., 504.], [440., 440., 440.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([24., 24., 24.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_stride_padding_out() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            stride_1: 2,
+            stride_2: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[84., 120., 76.], [108., 153., 96.], [108., 153., 96.]],
+                    [[228., 336., 220.], [324., 477., 312.], [324., 477., 312.]],
+                ],
+                [
+                    [[84., 120., 76.], [108., 153., 96.], [108., 153., 96.]],
+                    [[228., 336., 220.], [324., 477., 312.], [324., 477., 312.]],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[120., 174., 112.], [162., 234., 150.], [162., 234., 150.]],
+                    [[120., 174., 112.], [162., 234., 150.], [162., 234., 150.]],
+                ],
+                [
+                    [[192., 282., 184.], [270., 396., 258.], [270., 396., 258.]],
+                    [[192., 282., 184.], [270., 396., 258.], [270., 396., 258.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([60., 60.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_dilation() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 2,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[84., 120., 76.], [108., 153., 96.], [60., 84., 52.]],
+                    [[228., 336., 220.], [324., 477., 312.], [204., 300., 196.]],
+                ],
+                [
+                    [[84., 120., 76.], [108., 153., 96.], [60., 84., 52.]],
+                    [[228., 336., 220.], [324., 477., 312.], [204., 300., 196.]],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[120., 174., 112.], [162., 234., 150.], [96., 138., 88.]],
+                    [[120., 174., 112.], [162., 234., 150.], [96., 138., 88.]],
+                ],
+                [
+                    [[192., 282., 184.], [270., 396., 258.], [168., 246., 160.]],
+                    [[192., 282., 184.], [270., 396., 258.], [168., 246., 160.]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([30., 30.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose2d_groups() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]],
+                [[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]],
+                [[96., 141., 92.], [135., 198., 129.], [84., 123., 80.]],
+                [[132., 195., 128.], [189., 279., 183.], [120., 177., 116.]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[24., 33., 20.], [27., 36., 21.], [12., 15., 8.]]],
+                [[[60., 87., 56.], [81., 117., 75.], [48., 69., 44.]]],
+                [[[96., 141., 92.], [135., 198., 129.], [84., 123., 80.]]],
+                [[[132., 195., 128.], [189., 279., 183.], [120., 177., 116.]]],
+            ]),
+            bias: TestTensor::from_floats([9., 9.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose2dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        stride_1: usize,
+        stride_2: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        groups: usize,
+        height: usize,
+        width: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<4>,
+        weight: TestTensor<4>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose2dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
+            let shape_weight = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+
+            let output = conv_transpose2d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2],
+                    [self.padding_1, self.padding_2],
+                    [self.padding_out_1, self.padding_out_2],
+                    [self.dilation_1, self.dilation_2],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+        }
+    }
+}
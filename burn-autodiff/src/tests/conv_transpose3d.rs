@@ -0,0 +1,279 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose3d, ops::ConvTransposeOptions, Data, Shape};
+
+    #[test]
+    fn test_conv_transpose3d_basic() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[[120., 120.], [120., 120.]], [[120., 120.], [120., 120.]]],
+                    [[[376., 376.], [376., 376.]], [[376., 376.], [376., 376.]]],
+                ],
+                [
+                    [[[120., 120.], [120., 120.]], [[120., 120.], [120., 120.]]],
+                    [[[376., 376.], [376., 376.]], [[376., 376.], [376., 376.]]],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[184., 184.], [184., 184.]], [[184., 184.], [184., 184.]]],
+                    [[[184., 184.], [184., 184.]], [[184., 184.], [184., 184.]]],
+                ],
+                [
+                    [[[312., 312.], [312., 312.]], [[312., 312.], [312., 312.]]],
+                    [[[312., 312.], [312., 312.]], [[312., 312.], [312., 312.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([54., 54.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose3d_stride_padding_dilation() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 2,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[258., 258., 258.], [234., 234., 234.]],
+                    [[444., 444., 444.], [396., 396., 396.]],
+                ],
+                [
+                    [[690., 690., 690.], [666., 666., 666.]],
+                    [[1308., 1308., 1308.], [1260., 1260., 1260.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [
+                        [[30., 30.], [51., 51.], [21., 21.]],
+                        [[42., 42.], [66., 66.], [24., 24.]],
+                    ],
+                    [
+                        [[30., 30.], [51., 51.], [21., 21.]],
+                        [[42., 42.], [66., 66.], [24., 24.]],
+                    ],
+                    [
+                        [[30., 30.], [51., 51.], [21., 21.]],
+                        [[42., 42.], [66., 66.], [24., 24.]],
+                    ],
+                ],
+                [
+                    [
+                        [[66., 66.], [123., 123.], [57., 57.]],
+                        [[114., 114.], [210., 210.], [96., 96.]],
+                    ],
+                    [
+                        [[66., 66.], [123., 123.], [57., 57.]],
+                        [[114., 114.], [210., 210.], [96., 96.]],
+                    ],
+                    [
+                        [[66., 66.], [123., 123.], [57., 57.]],
+                        [[114., 114.], [210., 210.], [96., 96.]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([48., 48., 48.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 1,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 2,
+            height: 2,
+            width: 3,
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[16., 28., 12.], [16., 28., 12.]],
+                    [[16., 28., 12.], [16., 28., 12.]],
+                ],
+                [
+                    [[48., 92., 44.], [48., 92., 44.]],
+                    [[48., 92., 44.], [48., 92., 44.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[[48., 40.], [48., 40.]]], [[[48., 40.], [48., 40.]]]],
+                [
+                    [[[144., 136.], [144., 136.]]],
+                    [[[144., 136.], [144., 136.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([12., 12., 12., 12.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv_transpose3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor_2 = tensor_1.clone().cumsum(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 5.0, 3.0], [15.0, 11.0, 6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let tensor_1 =
+            TestADTensor::from_floats([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor_2 = tensor_1.clone().cumprod(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[23.0, 11.0, 6.0], [18.5, -29.0, -3.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let tensor_1 =
+            TestADTensor::from_floats([[2.0, 0.0, 3.0, 4.0], [0.0, 5.0, 0.0, 2.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0, 3.0, 4.0], [1.0, 1.0, 1.0, 1.0]]);
+
+        let tensor_2 = tensor_1.clone().cumprod(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[1.0, 118.0, 0.0, 0.0], [6.0, 0.0, 0.0, 0.0]]),
+            5,
+        );
+    }
+}
@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(ad_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest() {
+        let test = InterpolateTestCase {
+            output_size: [4, 5],
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_grad(TestTensor::from_floats([[[
+            [4., 4., 2.],
+            [2., 2., 1.],
+            [2., 2., 1.],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let test = InterpolateTestCase {
+            output_size: [4, 5],
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_grad(TestTensor::from_floats([[[
+            [2.2000, 2.4750, 2.2000],
+            [2.0000, 2.2500, 2.0000],
+            [2.2000, 2.4750, 2.2000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let test = InterpolateTestCase {
+            output_size: [4, 5],
+            mode: InterpolateMode::Bilinear,
+            align_corners: true,
+        };
+
+        test.assert_grad(TestTensor::from_floats([[[
+            [2.0000, 2.6667, 2.0000],
+            [2.0000, 2.6667, 2.0000],
+            [2.0000, 2.6667, 2.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic() {
+        let test = InterpolateTestCase {
+            output_size: [4, 5],
+            mode: InterpolateMode::Bicubic,
+            align_corners: false,
+        };
+
+        test.assert_grad(TestTensor::from_floats([[[
+            [2.1632, 2.2849, 2.1632],
+            [2.2175, 2.3422, 2.2175],
+            [2.1632, 2.2849, 2.1632],
+        ]]]));
+    }
+
+    struct InterpolateTestCase {
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+        align_corners: bool,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_grad(self, x_grad: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, 3, 3]);
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = interpolate(
+                x.clone(),
+                self.output_size,
+                InterpolateOptions::new(self.mode, self.align_corners),
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::max_pool1d, Data};
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 3;
+        let padding = 0;
+        let stride = 1;
+
+        let x = TestADTensor::from_floats([[[
+            0.0000, 0.7457, -0.9937, 0.5784, 0.2229, -0.8755, 0.9437, -0.3821,
+        ]]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[0., 2., 0., 2., 0., 0., 2., 0.]]]);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_padding_and_stride() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestADTensor::from_floats([[[
+            0.0000, 0.7457, -0.9937, 0.5784, 0.2229, -0.8755, 0.9437, -0.3821,
+        ]]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[0., 2., 0., 1., 0., 0., 1., 0.]]]);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
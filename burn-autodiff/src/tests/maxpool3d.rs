@@ -0,0 +1,113 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [[0., 0., 0.], [0., 0., 0.], [0., 0., 0.]],
+            [[0., 0., 0.], [0., 1., 1.], [0., 1., 1.]],
+            [[0., 0., 0.], [0., 1., 1.], [0., 1., 1.]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 2,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[0., 0., 0., 0.], [0., 2., 0., 2.]],
+                [[0., 0., 0., 0.], [0., 0., 0., 0.]],
+                [[0., 0., 0., 0.], [0., 2., 0., 2.]],
+            ],
+            [
+                [[0., 0., 0., 0.], [0., 2., 0., 2.]],
+                [[0., 0., 0., 0.], [0., 0., 0., 0.]],
+                [[0., 0., 0., 0.], [0., 2., 0., 2.]],
+            ],
+        ]]));
+    }
+
+    struct MaxPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl MaxPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = max_pool3d(
+                x.clone(),
+                [self.kernel_size_1, self.kernel_size_2, self.kernel_size_3],
+                [self.stride_1, self.stride_2, self.stride_3],
+                [self.padding_1, self.padding_2, self.padding_3],
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
@@ -1,42 +1,65 @@
 #![allow(missing_docs)]
 
+mod abs;
+mod adaptive_avgpool1d;
+mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
+mod atan2;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod broadcast;
 mod cat;
+mod clamp;
 mod complex;
 mod conv1d;
 mod conv2d;
+mod conv3d;
+mod conv_transpose1d;
+mod conv_transpose2d;
+mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod div;
 mod erf;
 mod exp;
+mod flip;
 mod gather_scatter;
 mod gelu;
 mod index;
 mod index_select;
+mod interpolate;
 mod log;
 mod log1p;
 mod mask;
 mod matmul;
 mod maxmin;
+mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod mul;
 mod multithread;
 mod neg;
+mod pad;
 mod pow;
+mod pow_tensor;
+mod recip;
 mod relu;
 mod reshape;
+mod round;
 mod sin;
 mod softmax;
+mod sort;
 mod sqrt;
 mod sub;
 mod tanh;
 mod transpose;
+mod triangular;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -54,23 +77,40 @@ macro_rules! testgen_all {
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv3d!();
+        burn_autodiff::testgen_ad_conv_transpose1d!();
+        burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
+        burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
+        burn_autodiff::testgen_ad_interpolate!();
+        burn_autodiff::testgen_ad_attention!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
         burn_autodiff::testgen_ad_multithread!();
+        burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_maxmin!();
+        burn_autodiff::testgen_ad_atan2!();
         burn_autodiff::testgen_ad_cat!();
+        burn_autodiff::testgen_ad_clamp!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_index!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_index_select!();
@@ -80,13 +120,19 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_pow!();
+        burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_round!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_transpose!();
+        burn_autodiff::testgen_ad_triangular!();
     };
 }
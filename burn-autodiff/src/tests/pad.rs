@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode};
+
+    #[test]
+    fn should_diff_constant_pad() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([
+            [1.0, 2.0, 3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0, 9.0, 10.0],
+            [11.0, 12.0, 13.0, 14.0, 15.0],
+        ]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1
+            .clone()
+            .pad([(1, 1), (1, 1)], PadMode::Constant(1.0))
+            .mul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[7.0, 8.0, 9.0]]));
+    }
+
+    #[test]
+    fn should_diff_reflect_pad() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().pad([(0, 0), (2, 1)], PadMode::Reflect);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 3.0, 2.0]]));
+    }
+
+    #[test]
+    fn should_diff_replicate_pad() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().pad([(0, 0), (2, 2)], PadMode::Replicate);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 1.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_diff_circular_pad() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().pad([(0, 0), (1, 2)], PadMode::Circular);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 2.0, 2.0]]));
+    }
+}
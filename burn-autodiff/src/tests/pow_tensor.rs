@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_pow)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_pow() {
+        let data_1 = Data::<f32, 2>::from([[2.0, 3.0], [0.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[3.0, 2.0], [0.0, 0.5]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().pow(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[12.0, 6.0], [0.0, 0.25]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[5.5452, 9.8875], [0.0, 2.7726]]), 3);
+    }
+
+    #[test]
+    fn should_diff_pow_with_broadcasting() {
+        let data_1 = Data::<f32, 2>::from([[2.0, 3.0], [4.0, 5.0]]);
+        let data_2 = Data::<f32, 2>::from([[2.0], [1.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().pow(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[4.0, 6.0], [1.0, 1.0]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[12.6601], [13.5924]]), 3);
+    }
+}
@@ -0,0 +1,20 @@
+#[burn_tensor_testgen::testgen(ad_recip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_recip() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [4.0, -0.5]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().recip();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, -0.25], [-0.0625, -4.0]]), 3);
+    }
+}
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_round)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_rounding_ops_with_zero_gradient() {
+        let data = Data::<f32, 2>::from([[-1.5, 0.2], [0.7, 2.5]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1
+            .clone()
+            .floor()
+            .add(tensor_1.clone().ceil())
+            .add(tensor_1.clone().round())
+            .add(tensor_1.clone().sign())
+            .add(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 1.0], [1.0, 1.0]]));
+    }
+}
@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[4.0, -7.0], [2.0, 3.0]]).require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+        let tensor_4 = tensor_3.sort(1);
+        let tensor_5 = tensor_4.mul(TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]));
+        let grads = tensor_5.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 7.0], [-16.0, 18.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-4.0, -7.0], [5.0, -5.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_topk() {
+        let tensor_1 = TestADTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().mul_scalar(2.0).topk(2, 1);
+        let tensor_3 = tensor_2.mul(TestADTensor::from_floats([[1.0, 10.0], [100.0, 1000.0]]));
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0, 20.0], [0.0, 200.0, 2000.0]]), 5);
+    }
+}
@@ -1,7 +1,17 @@
 use crate as burn;
 
 use crate::{config::Config, tensor::Tensor};
-use burn_tensor::{backend::Backend, ElementConversion};
+use burn_tensor::backend::Backend;
+
+#[cfg(feature = "std")]
+use crate::{
+    module::{ADModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+};
+#[cfg(feature = "std")]
+use burn_tensor::backend::ADBackend;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
 #[derive(Config)]
@@ -11,6 +21,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip all the gradients together by their global norm.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +36,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -30,12 +44,20 @@ impl GradientClippingConfig {
 /// Gradient Clipping provides a way to mitigate exploding gradients
 /// by clipping every component of the gradient by value or by norm during
 /// backpropagation.
+#[derive(Clone, Debug)]
 pub enum GradientClipping {
     /// Clip the gradient by value.
     Value(f32),
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip all the gradients together by their global norm.
+    ///
+    /// A single L2 norm is computed over the gradients of every parameter, which are all scaled
+    /// by the same factor when the norm exceeds the threshold. The gradients of every parameter
+    /// are only clipped together by [clip_gradients](GradientClipping::clip_gradients).
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
@@ -48,10 +70,16 @@ impl GradientClipping {
     /// # Returns
     ///
     /// The clipped gradient.
+    ///
+    /// # Notes
+    ///
+    /// The global norm of a single gradient is its own norm, so
+    /// [global norm](GradientClipping::GlobalNorm) clipping is the same as clipping by norm here.
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(max_norm) => self.clip_by_norm(grad, *max_norm),
         }
     }
 
@@ -60,12 +88,7 @@ impl GradientClipping {
         grad: Tensor<B, D>,
         threshold: f32,
     ) -> Tensor<B, D> {
-        let greater_mask = grad.clone().greater_elem(threshold);
-        let lower_mask = grad.clone().lower_elem(-threshold);
-
-        let clipped_grad = grad.mask_fill(greater_mask, threshold);
-
-        clipped_grad.mask_fill(lower_mask, -threshold)
+        grad.clamp(-threshold, threshold)
     }
 
     fn l2_norm<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
@@ -75,18 +98,159 @@ impl GradientClipping {
         sum.sqrt()
     }
 
+    /// The factor scaling a norm down to the threshold, or one when the norm is below it.
+    ///
+    /// It is computed on the device, so the norm never has to be read back on the host.
+    fn norm_scale<B: Backend>(norm: Tensor<B, 1>, threshold: f32) -> Tensor<B, 1> {
+        let norm_clipped = norm
+            .clone()
+            .mask_fill(norm.lower_elem(threshold), threshold);
+
+        norm_clipped.powf(-1.0).mul_scalar(threshold)
+    }
+
     fn clip_by_norm<B: Backend, const D: usize>(
         &self,
         grad: Tensor<B, D>,
         threshold: f32,
     ) -> Tensor<B, D> {
         let norm = Self::l2_norm(grad.clone());
-        let norm_float = norm.into_scalar().elem::<f32>();
-        if norm_float > threshold {
-            let scale = threshold / norm_float;
-            grad.mul_scalar(scale)
-        } else {
-            grad
+        let scale = Self::norm_scale(norm, threshold);
+
+        grad.mul(scale.unsqueeze())
+    }
+}
+
+#[cfg(feature = "std")]
+impl GradientClipping {
+    /// Clip the gradients of every parameter of the module.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module the gradients belong to.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients.
+    pub fn clip_gradients<B: ADBackend, M: ADModule<B>>(
+        &self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> GradientsParams {
+        match self {
+            GradientClipping::GlobalNorm(_) => self.clip_gradients_with_norm(module, grads).0,
+            _ => {
+                let mut visitor = GradientsClipper::<M, B>::new(self, None, grads);
+                module.visit(&mut visitor);
+                visitor.grads
+            }
+        }
+    }
+
+    /// Clip the gradients of every parameter of the module and compute their global L2 norm
+    /// before clipping, which can be logged to monitor the training.
+    ///
+    /// The norm is computed on the device of the gradients, no synchronization with the host is
+    /// required.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module the gradients belong to.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients and the global norm of the gradients before clipping.
+    pub fn clip_gradients_with_norm<B: ADBackend, M: ADModule<B>>(
+        &self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> (GradientsParams, Tensor<B::InnerBackend, 1>) {
+        let norm = Self::global_norm(module, &grads);
+        let scale = match self {
+            GradientClipping::GlobalNorm(max_norm) => {
+                Some(Self::norm_scale(norm.clone(), *max_norm))
+            }
+            _ => None,
+        };
+
+        let mut visitor = GradientsClipper::<M, B>::new(self, scale, grads);
+        module.visit(&mut visitor);
+
+        (visitor.grads, norm)
+    }
+
+    /// Compute the L2 norm of the gradients of every parameter of the module, as if they were
+    /// concatenated in a single vector.
+    pub fn global_norm<B: ADBackend, M: ADModule<B>>(
+        module: &M,
+        grads: &GradientsParams,
+    ) -> Tensor<B::InnerBackend, 1> {
+        let mut visitor = GradientsNorm::<M, B>::new(grads, None);
+        module.visit(&mut visitor);
+
+        match visitor.sum_squared {
+            Some(sum_squared) => sum_squared.sqrt(),
+            None => Tensor::zeros([1]),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(new)]
+struct GradientsNorm<'a, M: ADModule<B>, B: ADBackend> {
+    grads: &'a GradientsParams,
+    sum_squared: Option<Tensor<B::InnerBackend, 1>>,
+    phantom: PhantomData<M>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, M: ADModule<B>, B: ADBackend> ModuleVisitor<B> for GradientsNorm<'a, M, B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let sum_squared = grad.powf(2.0).sum();
+
+            self.sum_squared = Some(match self.sum_squared.take() {
+                Some(sum) => {
+                    let device = sum.device();
+                    sum.add(sum_squared.to_device(&device))
+                }
+                None => sum_squared,
+            });
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(new)]
+struct GradientsClipper<'a, M: ADModule<B>, B: ADBackend> {
+    clipping: &'a GradientClipping,
+    scale: Option<Tensor<B::InnerBackend, 1>>,
+    grads: GradientsParams,
+    phantom: PhantomData<M>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, M: ADModule<B>, B: ADBackend> ModuleVisitor<B> for GradientsClipper<'a, M, B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            let grad = match (&self.scale, self.clipping) {
+                (Some(scale), _) => {
+                    let scale = scale.clone().to_device(&grad.device());
+                    grad.mul(scale.unsqueeze())
+                }
+                (None, GradientClipping::Value(threshold)) => {
+                    self.clipping.clip_by_value(grad, *threshold)
+                }
+                (None, GradientClipping::Norm(max_norm)) => {
+                    self.clipping.clip_by_norm(grad, *max_norm)
+                }
+                // The global norm scale is always computed before visiting the gradients.
+                (None, GradientClipping::GlobalNorm(_)) => grad,
+            };
+
+            self.grads.register::<B::InnerBackend, D>(id.clone(), grad);
         }
     }
 }
@@ -94,8 +258,9 @@ impl GradientClipping {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Tensor;
-    use crate::TestBackend;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{TestADBackend, TestBackend};
 
     #[test]
     fn test_clip_by_value() {
@@ -112,6 +277,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_clip_by_global_norm_a_single_gradient() {
+        let gradient: Tensor<TestBackend, 1> = Tensor::from_floats([3.0, 4.0]);
+
+        let clipped_gradient = GradientClipping::GlobalNorm(2.5).clip_gradient(gradient);
+
+        clipped_gradient
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, 2.0]), 4);
+    }
+
     #[test]
     fn test_clip_by_norm() {
         let gradient: Tensor<TestBackend, 2> = Tensor::from_floats([
@@ -126,4 +302,47 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    fn test_clip_by_global_norm() {
+        let layer: Linear<TestADBackend> = LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let grads = GradientsParams::from_grads(layer.forward(x).backward(), &layer);
+        let weight: Tensor<TestBackend, 2> = grads.get(&layer.weight.id).unwrap();
+        let bias: Tensor<TestBackend, 1> = grads.get(&layer.bias.as_ref().unwrap().id).unwrap();
+        let norm_expected = weight
+            .clone()
+            .powf(2.0)
+            .sum()
+            .add(bias.powf(2.0).sum())
+            .sqrt()
+            .into_scalar();
+
+        let (grads, norm) =
+            GradientClipping::GlobalNorm(0.5).clip_gradients_with_norm(&layer, grads);
+        let norm_clipped = GradientClipping::global_norm(&layer, &grads).into_scalar();
+        let weight_clipped: Tensor<TestBackend, 2> = grads.get(&layer.weight.id).unwrap();
+
+        assert!(norm_expected > 0.5);
+        assert!((norm.into_scalar() - norm_expected).abs() < 1e-4);
+        assert!((norm_clipped - 0.5).abs() < 1e-4);
+        weight_clipped
+            .into_data()
+            .assert_approx_eq(&weight.mul_scalar(0.5 / norm_expected).into_data(), 4);
+    }
+
+    #[test]
+    fn test_clip_by_global_norm_below_threshold() {
+        let layer: Linear<TestADBackend> = LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let grads = GradientsParams::from_grads(layer.forward(x).backward(), &layer);
+        let weight: Tensor<TestBackend, 2> = grads.get(&layer.weight.id).unwrap();
+
+        let grads = GradientClipping::GlobalNorm(1e6).clip_gradients(&layer, grads);
+        let weight_clipped: Tensor<TestBackend, 2> = grads.get(&layer.weight.id).unwrap();
+
+        weight_clipped
+            .into_data()
+            .assert_approx_eq(&weight.into_data(), 4);
+    }
 }
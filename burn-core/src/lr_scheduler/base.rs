@@ -9,6 +9,12 @@ pub trait LRScheduler: Send + Sync {
     /// learning rate.
     fn step(&mut self) -> LearningRate;
 
+    /// Report the value of the monitored metric, such as the validation loss, at the end of an
+    /// epoch.
+    ///
+    /// Only metric-aware schedulers use this value, the default implementation ignores it.
+    fn report(&mut self, _metric: f64) {}
+
     /// Get the current state of the scheduler as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
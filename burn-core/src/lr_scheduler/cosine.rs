@@ -0,0 +1,175 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [cosine annealing](CosineAnnealingLRScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct CosineAnnealingLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The minimum learning rate, reached at the end of each cycle.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    /// The number of steps of the first cycle.
+    num_iters: usize,
+    /// The factor by which the length of the cycle is multiplied after each restart.
+    #[config(default = 1)]
+    cycle_mult: usize,
+}
+
+/// Cosine annealing learning rate scheduler with warm restarts as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+///
+/// The learning rate follows a cosine curve from the initial to the minimum learning rate, then
+/// restarts from the initial learning rate with a cycle `cycle_mult` times longer.
+///
+/// The first step returns the initial learning rate.
+#[derive(Clone, Debug)]
+pub struct CosineAnnealingLRScheduler {
+    init_lr: LearningRate,
+    min_lr: LearningRate,
+    num_iters: usize,
+    cycle_mult: usize,
+    step: usize,
+    position: usize,
+    cycle_len: usize,
+}
+
+impl CosineAnnealingLRSchedulerConfig {
+    /// Initialize a new [cosine annealing](CosineAnnealingLRScheduler) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of iterations or the cycle multiplier is zero.
+    pub fn init(&self) -> CosineAnnealingLRScheduler {
+        assert!(
+            self.num_iters > 0,
+            "The number of iterations should be greater than zero."
+        );
+        assert!(
+            self.cycle_mult > 0,
+            "The cycle multiplier should be greater than zero."
+        );
+
+        CosineAnnealingLRScheduler {
+            init_lr: self.init_lr,
+            min_lr: self.min_lr,
+            num_iters: self.num_iters,
+            cycle_mult: self.cycle_mult,
+            step: 0,
+            position: 0,
+            cycle_len: self.num_iters,
+        }
+    }
+}
+
+impl CosineAnnealingLRScheduler {
+    /// Returns the position in the current cycle and the length of the current cycle, computed
+    /// from the number of steps when resuming from a record.
+    fn cycle_position(&self) -> (usize, usize) {
+        if self.cycle_mult == 1 {
+            return (self.step % self.num_iters, self.num_iters);
+        }
+
+        let mut position = self.step;
+        let mut cycle_len = self.num_iters;
+
+        while position >= cycle_len {
+            position -= cycle_len;
+            cycle_len = cycle_len.saturating_mul(self.cycle_mult);
+        }
+
+        (position, cycle_len)
+    }
+}
+
+impl LRScheduler for CosineAnnealingLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let progress = self.position as f64 / self.cycle_len as f64;
+        self.step += 1;
+        self.position += 1;
+
+        if self.position == self.cycle_len {
+            self.position = 0;
+            self.cycle_len = self.cycle_len.saturating_mul(self.cycle_mult);
+        }
+
+        let cosine = (core::f64::consts::PI * progress).cos();
+
+        self.min_lr + 0.5 * (self.init_lr - self.min_lr) * (1.0 + cosine)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        (self.position, self.cycle_len) = self.cycle_position();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_annealing() {
+        let mut scheduler = CosineAnnealingLRSchedulerConfig::new(1.0, 4)
+            .with_min_lr(0.2)
+            .init();
+
+        let lrs: Vec<_> = (0..4).map(|_| scheduler.step()).collect();
+
+        assert_lrs_approx_eq(&lrs, &[1.0, 0.8828427, 0.6, 0.3171573]);
+    }
+
+    #[test]
+    fn test_warm_restarts_with_longer_cycles() {
+        let mut scheduler = CosineAnnealingLRSchedulerConfig::new(1.0, 2)
+            .with_cycle_mult(2)
+            .init();
+
+        let lrs: Vec<_> = (0..7).map(|_| scheduler.step()).collect();
+
+        assert_lrs_approx_eq(&lrs, &[1.0, 0.5, 1.0, 0.8535534, 0.5, 0.1464466, 1.0]);
+    }
+
+    #[test]
+    fn test_resume_from_record() {
+        let config = CosineAnnealingLRSchedulerConfig::new(1.0, 3).with_cycle_mult(2);
+        let mut scheduler = config.init();
+        for _ in 0..5 {
+            scheduler.step();
+        }
+
+        let mut scheduler_resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(scheduler_resumed.step(), scheduler.step());
+    }
+
+    #[test]
+    fn test_resume_from_record_with_constant_cycles() {
+        let config = CosineAnnealingLRSchedulerConfig::new(1.0, 4);
+        let mut scheduler = config.init().load_record(4_000_000_001);
+
+        let lrs: Vec<_> = (0..4).map(|_| scheduler.step()).collect();
+
+        assert_lrs_approx_eq(&lrs, &[0.8535534, 0.5, 0.1464466, 1.0]);
+    }
+
+    fn assert_lrs_approx_eq(actual: &[LearningRate], expected: &[LearningRate]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "Expected learning rate {expected}, got {actual}"
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create an [exponential](ExponentialLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct ExponentialLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The multiplicative factor applied to the learning rate at each step.
+    gamma: f64,
+}
+
+/// Exponential learning rate scheduler, decaying the learning rate by `gamma` at every step.
+///
+/// The first step returns the initial learning rate.
+#[derive(Clone, Debug)]
+pub struct ExponentialLRScheduler {
+    init_lr: LearningRate,
+    gamma: f64,
+    step: usize,
+}
+
+impl ExponentialLRSchedulerConfig {
+    /// Initialize a new [exponential](ExponentialLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> ExponentialLRScheduler {
+        ExponentialLRScheduler {
+            init_lr: self.init_lr,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LRScheduler for ExponentialLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.init_lr * self.gamma.powi(self.step as i32);
+        self.step += 1;
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decay_every_step() {
+        let mut scheduler = ExponentialLRSchedulerConfig::new(1.0, 0.5).init();
+
+        let lrs: Vec<_> = (0..4).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![1.0, 0.5, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_resume_from_record() {
+        let config = ExponentialLRSchedulerConfig::new(1.0, 0.9);
+        let mut scheduler = config.init();
+        for _ in 0..5 {
+            scheduler.step();
+        }
+
+        let mut scheduler_resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(scheduler_resumed.step(), scheduler.step());
+    }
+}
@@ -1,9 +1,30 @@
 /// Constant learning rate scheduler
 pub mod constant;
 
+/// Cosine annealing learning rate scheduler
+pub mod cosine;
+
+/// Exponential learning rate scheduler
+pub mod exponential;
+
 /// Noam Learning rate schedule
 pub mod noam;
 
+/// One cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Learning rate scheduler reducing the learning rate when a metric stops improving
+pub mod plateau;
+
+/// Polynomial learning rate scheduler
+pub mod polynomial;
+
+/// Step learning rate scheduler
+pub mod step;
+
+/// Warmup learning rate scheduler
+pub mod warmup;
+
 mod base;
 
 pub use base::*;
@@ -0,0 +1,152 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [one cycle](OneCycleLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct OneCycleLRSchedulerConfig {
+    /// The maximum learning rate, reached at the end of the warmup phase.
+    max_lr: LearningRate,
+    /// The total number of steps in the cycle.
+    total_steps: usize,
+    /// The fraction of the cycle spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    /// The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    /// The final learning rate is `max_lr / (div_factor * final_div_factor)`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+/// One cycle learning rate scheduler as described in [Super-Convergence: Very Fast Training of
+/// Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// The learning rate is annealed with a cosine from the initial learning rate to the maximum
+/// learning rate, then from the maximum learning rate to the final learning rate. After
+/// `total_steps` steps, the final learning rate is kept.
+///
+/// The first step returns the initial learning rate.
+#[derive(Clone, Debug)]
+pub struct OneCycleLRScheduler {
+    init_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    warmup_end: f64,
+    total_end: f64,
+    step: usize,
+}
+
+impl OneCycleLRSchedulerConfig {
+    /// Initialize a new [one cycle](OneCycleLRScheduler) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of steps is lower than two or if `pct_start` isn't between
+    /// zero and one.
+    pub fn init(&self) -> OneCycleLRScheduler {
+        assert!(
+            self.total_steps > 1,
+            "The total number of steps should be greater than one."
+        );
+        assert!(
+            self.pct_start > 0.0 && self.pct_start < 1.0,
+            "The warmup fraction pct_start should be between zero and one."
+        );
+
+        let init_lr = self.max_lr / self.div_factor;
+
+        OneCycleLRScheduler {
+            init_lr,
+            max_lr: self.max_lr,
+            final_lr: init_lr / self.final_div_factor,
+            warmup_end: self.pct_start * self.total_steps as f64 - 1.0,
+            total_end: self.total_steps as f64 - 1.0,
+            step: 0,
+        }
+    }
+}
+
+fn annealing_cos(start: LearningRate, end: LearningRate, progress: f64) -> LearningRate {
+    let cosine = (core::f64::consts::PI * progress).cos() + 1.0;
+
+    end + (start - end) / 2.0 * cosine
+}
+
+impl LRScheduler for OneCycleLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let step = f64::min(self.step as f64, self.total_end);
+        self.step += 1;
+
+        // Without any warmup step, the cycle starts directly at the maximum learning rate.
+        if step <= self.warmup_end && self.warmup_end > 0.0 {
+            annealing_cos(self.init_lr, self.max_lr, step / self.warmup_end)
+        } else {
+            let progress = (step - self.warmup_end) / (self.total_end - self.warmup_end);
+            annealing_cos(self.max_lr, self.final_lr, progress)
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_cycle() {
+        let mut scheduler = OneCycleLRSchedulerConfig::new(1.0, 7)
+            .with_pct_start(3.0 / 7.0)
+            .with_div_factor(10.0)
+            .with_final_div_factor(10.0)
+            .init();
+
+        let lrs: Vec<_> = (0..8).map(|_| scheduler.step()).collect();
+        let expected = [0.1, 0.55, 1.0, 0.8550179, 0.505, 0.1549821, 0.01, 0.01];
+
+        for (lr, expected) in lrs.iter().zip(expected) {
+            assert!(
+                (lr - expected).abs() < 1e-6,
+                "Expected {expected}, got {lr}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_one_cycle_without_warmup_steps() {
+        let mut scheduler = OneCycleLRSchedulerConfig::new(1.0, 10)
+            .with_pct_start(0.1)
+            .init();
+
+        let lrs: Vec<_> = (0..10).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs[0], 1.0);
+        assert!(lrs.iter().all(|lr| lr.is_finite()));
+        assert!(lrs.windows(2).all(|lrs| lrs[1] < lrs[0]));
+    }
+
+    #[test]
+    fn test_resume_from_record() {
+        let config = OneCycleLRSchedulerConfig::new(1.0, 100);
+        let mut scheduler = config.init();
+        for _ in 0..42 {
+            scheduler.step();
+        }
+
+        let mut scheduler_resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(scheduler_resumed.step(), scheduler.step());
+    }
+}
@@ -0,0 +1,234 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, record::Record, LearningRate};
+
+/// Whether the monitored metric should be minimized or maximized.
+#[derive(Config, Debug, PartialEq)]
+pub enum PlateauMode {
+    /// The metric improves when it decreases, e.g. a loss.
+    Min,
+    /// The metric improves when it increases, e.g. an accuracy.
+    Max,
+}
+
+/// Configuration to create a [reduce on plateau](ReduceLROnPlateau) learning rate scheduler.
+#[derive(Config)]
+pub struct ReduceLROnPlateauConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The factor by which the learning rate is multiplied when reduced.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of epochs without improvement after which the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    /// The relative change from the best value required to count as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    /// The number of epochs to wait after a reduction before resuming normal operation.
+    #[config(default = 0)]
+    cooldown: usize,
+    /// The lower bound of the learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    /// Whether the monitored metric should be minimized or maximized.
+    #[config(default = "PlateauMode::Min")]
+    mode: PlateauMode,
+}
+
+/// Learning rate scheduler reducing the learning rate when a metric has stopped improving.
+///
+/// The monitored metric is provided with [report](LRScheduler::report), usually by the learner
+/// at the end of each validation epoch. When no improvement is seen for more than `patience`
+/// reports, the learning rate is multiplied by `factor`, without going below `min_lr`.
+#[derive(Clone, Debug)]
+pub struct ReduceLROnPlateau {
+    factor: f64,
+    patience: usize,
+    threshold: f64,
+    cooldown: usize,
+    min_lr: LearningRate,
+    mode: PlateauMode,
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_epochs: usize,
+    cooldown_counter: usize,
+}
+
+/// [Reduce on plateau](ReduceLROnPlateau) state.
+#[derive(Record, Clone, Debug)]
+pub struct ReduceLROnPlateauRecord {
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_epochs: usize,
+    cooldown_counter: usize,
+}
+
+impl ReduceLROnPlateauConfig {
+    /// Initialize a new [reduce on plateau](ReduceLROnPlateau) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the factor is not in the range `[0, 1)`.
+    pub fn init(&self) -> ReduceLROnPlateau {
+        assert!(
+            (0.0..1.0).contains(&self.factor),
+            "The factor should be in the range [0, 1)."
+        );
+
+        ReduceLROnPlateau {
+            factor: self.factor,
+            patience: self.patience,
+            threshold: self.threshold,
+            cooldown: self.cooldown,
+            min_lr: self.min_lr,
+            mode: self.mode.clone(),
+            lr: self.init_lr,
+            best: None,
+            num_bad_epochs: 0,
+            cooldown_counter: 0,
+        }
+    }
+}
+
+impl ReduceLROnPlateau {
+    fn is_better(&self, metric: f64, best: f64) -> bool {
+        match self.mode {
+            PlateauMode::Min => metric < best - best.abs() * self.threshold,
+            PlateauMode::Max => metric > best + best.abs() * self.threshold,
+        }
+    }
+}
+
+impl LRScheduler for ReduceLROnPlateau {
+    type Record = ReduceLROnPlateauRecord;
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn report(&mut self, metric: f64) {
+        if metric.is_nan() {
+            return;
+        }
+
+        match self.best {
+            Some(best) if !self.is_better(metric, best) => self.num_bad_epochs += 1,
+            _ => {
+                self.best = Some(metric);
+                self.num_bad_epochs = 0;
+            }
+        }
+
+        if self.cooldown_counter > 0 {
+            self.cooldown_counter -= 1;
+            self.num_bad_epochs = 0;
+        }
+
+        if self.num_bad_epochs > self.patience {
+            self.lr = f64::max(self.lr * self.factor, self.min_lr);
+            self.cooldown_counter = self.cooldown;
+            self.num_bad_epochs = 0;
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        ReduceLROnPlateauRecord {
+            lr: self.lr,
+            best: self.best,
+            num_bad_epochs: self.num_bad_epochs,
+            cooldown_counter: self.cooldown_counter,
+        }
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.lr = record.lr;
+        self.best = record.best;
+        self.num_bad_epochs = record.num_bad_epochs;
+        self.cooldown_counter = record.cooldown_counter;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+    #[test]
+    fn test_reduce_after_patience() {
+        let mut scheduler = ReduceLROnPlateauConfig::new(1.0)
+            .with_factor(0.5)
+            .with_patience(1)
+            .init();
+
+        let lrs: Vec<_> = [1.0, 0.5, 0.6, 0.7, 0.8, 0.9]
+            .into_iter()
+            .map(|metric| {
+                let lr = scheduler.step();
+                scheduler.report(metric);
+                lr
+            })
+            .collect();
+
+        assert_eq!(lrs, vec![1.0, 1.0, 1.0, 1.0, 0.5, 0.5]);
+        assert_eq!(scheduler.step(), 0.25);
+    }
+
+    #[test]
+    fn test_max_mode_with_cooldown_and_min_lr() {
+        let mut scheduler = ReduceLROnPlateauConfig::new(1.0)
+            .with_factor(0.1)
+            .with_patience(0)
+            .with_cooldown(1)
+            .with_min_lr(0.05)
+            .with_mode(PlateauMode::Max)
+            .init();
+
+        let lrs: Vec<_> = [0.5, 0.6, 0.6, 0.6, 0.6, 0.6]
+            .into_iter()
+            .map(|metric| {
+                scheduler.report(metric);
+                scheduler.step()
+            })
+            .collect();
+
+        assert_eq!(lrs, vec![1.0, 1.0, 0.1, 0.1, 0.05, 0.05]);
+    }
+
+    #[test]
+    fn test_ignore_small_improvements() {
+        let mut scheduler = ReduceLROnPlateauConfig::new(1.0)
+            .with_patience(0)
+            .with_threshold(0.1)
+            .init();
+
+        scheduler.report(1.0);
+        scheduler.report(0.95);
+
+        assert_eq!(scheduler.step(), 0.1);
+    }
+
+    #[test]
+    fn test_resume_from_recorded_state() {
+        let config = ReduceLROnPlateauConfig::new(1.0)
+            .with_factor(0.5)
+            .with_patience(1);
+        let mut scheduler = config.init();
+        for metric in [1.0, 1.0, 1.0] {
+            scheduler.report(metric);
+        }
+        scheduler.report(2.0);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(scheduler.to_record(), ()).unwrap();
+
+        let record = recorder.load(bytes).unwrap();
+        let mut scheduler_loaded = config.init().load_record(record);
+        scheduler.report(2.0);
+        scheduler_loaded.report(2.0);
+
+        assert_eq!(scheduler_loaded.step(), 0.25);
+        assert_eq!(scheduler_loaded.step(), scheduler.step());
+    }
+}
@@ -0,0 +1,116 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [polynomial](PolynomialLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct PolynomialLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The learning rate reached after `num_iters` steps.
+    #[config(default = 0.0)]
+    final_lr: LearningRate,
+    /// The number of steps to go from the initial to the final learning rate.
+    num_iters: usize,
+    /// The power of the polynomial, a power of one gives a linear schedule.
+    #[config(default = 1.0)]
+    power: f64,
+}
+
+/// Polynomial learning rate scheduler, interpolating between the initial and the final learning
+/// rate during `num_iters` steps, then keeping the final learning rate.
+///
+/// With the default power of one, this is a linear schedule. The final learning rate can also be
+/// greater than the initial one, which is useful for linear warmup.
+///
+/// The first step returns the initial learning rate.
+#[derive(Clone, Debug)]
+pub struct PolynomialLRScheduler {
+    init_lr: LearningRate,
+    final_lr: LearningRate,
+    num_iters: usize,
+    power: f64,
+    step: usize,
+}
+
+impl PolynomialLRSchedulerConfig {
+    /// Initialize a new [polynomial](PolynomialLRScheduler) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of iterations is zero.
+    pub fn init(&self) -> PolynomialLRScheduler {
+        assert!(
+            self.num_iters > 0,
+            "The number of iterations should be greater than zero."
+        );
+
+        PolynomialLRScheduler {
+            init_lr: self.init_lr,
+            final_lr: self.final_lr,
+            num_iters: self.num_iters,
+            power: self.power,
+            step: 0,
+        }
+    }
+}
+
+impl LRScheduler for PolynomialLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let progress = usize::min(self.step, self.num_iters) as f64 / self.num_iters as f64;
+        self.step += 1;
+
+        self.final_lr + (self.init_lr - self.final_lr) * (1.0 - progress).powf(self.power)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_decay_then_constant() {
+        let mut scheduler = PolynomialLRSchedulerConfig::new(1.0, 4).init();
+
+        let lrs: Vec<_> = (0..6).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![1.0, 0.75, 0.5, 0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_quadratic_warmup() {
+        let mut scheduler = PolynomialLRSchedulerConfig::new(0.0, 2)
+            .with_final_lr(1.0)
+            .with_power(2.0)
+            .init();
+
+        let lrs: Vec<_> = (0..3).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![0.0, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn test_resume_from_record() {
+        let config = PolynomialLRSchedulerConfig::new(1.0, 10).with_power(2.0);
+        let mut scheduler = config.init();
+        for _ in 0..3 {
+            scheduler.step();
+        }
+
+        let mut scheduler_resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(scheduler_resumed.step(), scheduler.step());
+    }
+}
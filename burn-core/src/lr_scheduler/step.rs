@@ -0,0 +1,95 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [step](StepLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct StepLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps between each decay of the learning rate.
+    step_size: usize,
+    /// The multiplicative factor applied to the learning rate at each decay.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Step learning rate scheduler, decaying the learning rate by `gamma` every `step_size` steps.
+///
+/// The first step returns the initial learning rate.
+#[derive(Clone, Debug)]
+pub struct StepLRScheduler {
+    init_lr: LearningRate,
+    step_size: usize,
+    gamma: f64,
+    step: usize,
+}
+
+impl StepLRSchedulerConfig {
+    /// Initialize a new [step](StepLRScheduler) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is zero.
+    pub fn init(&self) -> StepLRScheduler {
+        assert!(
+            self.step_size > 0,
+            "The step size should be greater than zero."
+        );
+
+        StepLRScheduler {
+            init_lr: self.init_lr,
+            step_size: self.step_size,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LRScheduler for StepLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = (self.step / self.step_size) as i32;
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decay_every_step_size() {
+        let mut scheduler = StepLRSchedulerConfig::new(1.0, 2).with_gamma(0.5).init();
+
+        let lrs: Vec<_> = (0..6).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![1.0, 1.0, 0.5, 0.5, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_resume_from_record() {
+        let config = StepLRSchedulerConfig::new(1.0, 2).with_gamma(0.5);
+        let mut scheduler = config.init();
+        for _ in 0..3 {
+            scheduler.step();
+        }
+
+        let mut scheduler_resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(scheduler_resumed.step(), scheduler.step());
+    }
+}
@@ -0,0 +1,151 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [warmup](WarmupLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct WarmupLRSchedulerConfig {
+    /// The number of steps during which the warmup scheduler is used.
+    warmup_steps: usize,
+}
+
+/// Learning rate scheduler chaining two [learning rate schedulers](LRScheduler).
+///
+/// The warmup scheduler is used for the first `warmup_steps` steps, then the main scheduler takes
+/// over, starting from its own first step. Since both are schedulers, chains can be nested to
+/// compose more than two phases.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::lr_scheduler::{
+///     cosine::CosineAnnealingLRSchedulerConfig, polynomial::PolynomialLRSchedulerConfig,
+///     warmup::WarmupLRSchedulerConfig,
+/// };
+///
+/// let warmup = PolynomialLRSchedulerConfig::new(0.0, 100)
+///     .with_final_lr(1e-3)
+///     .init();
+/// let main = CosineAnnealingLRSchedulerConfig::new(1e-3, 10_000).init();
+/// let scheduler = WarmupLRSchedulerConfig::new(100).init(warmup, main);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WarmupLRScheduler<W, S> {
+    warmup: W,
+    scheduler: S,
+    warmup_steps: usize,
+    step: usize,
+}
+
+impl WarmupLRSchedulerConfig {
+    /// Initialize a new [warmup](WarmupLRScheduler) learning rate scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `warmup` - The scheduler used during the warmup steps.
+    /// * `scheduler` - The scheduler used after the warmup steps.
+    pub fn init<W: LRScheduler, S: LRScheduler>(
+        &self,
+        warmup: W,
+        scheduler: S,
+    ) -> WarmupLRScheduler<W, S> {
+        WarmupLRScheduler {
+            warmup,
+            scheduler,
+            warmup_steps: self.warmup_steps,
+            step: 0,
+        }
+    }
+}
+
+impl<W: LRScheduler, S: LRScheduler> LRScheduler for WarmupLRScheduler<W, S> {
+    type Record = (W::Record, S::Record, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let lr = match self.step < self.warmup_steps {
+            true => self.warmup.step(),
+            false => self.scheduler.step(),
+        };
+        self.step += 1;
+
+        lr
+    }
+
+    fn report(&mut self, metric: f64) {
+        match self.step <= self.warmup_steps {
+            true => self.warmup.report(metric),
+            false => self.scheduler.report(metric),
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (
+            self.warmup.to_record(),
+            self.scheduler.to_record(),
+            self.step,
+        )
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let (warmup, scheduler, step) = record;
+
+        self.warmup = self.warmup.load_record(warmup);
+        self.scheduler = self.scheduler.load_record(scheduler);
+        self.step = step;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lr_scheduler::{
+        exponential::{ExponentialLRScheduler, ExponentialLRSchedulerConfig},
+        polynomial::{PolynomialLRScheduler, PolynomialLRSchedulerConfig},
+    };
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+    #[test]
+    fn test_switch_scheduler_after_warmup() {
+        let mut scheduler = create_scheduler();
+
+        let lrs: Vec<_> = (0..5).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![0.0, 0.5, 1.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_resume_from_recorded_state() {
+        let mut scheduler = create_scheduler();
+        for _ in 0..3 {
+            scheduler.step();
+        }
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(scheduler.to_record(), ()).unwrap();
+
+        let record = recorder.load(bytes).unwrap();
+        let mut scheduler_resumed = create_scheduler().load_record(record);
+
+        for _ in 0..3 {
+            assert_eq!(scheduler_resumed.step(), scheduler.step());
+        }
+    }
+
+    type TestScheduler = WarmupLRScheduler<
+        PolynomialLRScheduler,
+        WarmupLRScheduler<ExponentialLRScheduler, PolynomialLRScheduler>,
+    >;
+
+    fn create_scheduler() -> TestScheduler {
+        let warmup = PolynomialLRSchedulerConfig::new(0.0, 2)
+            .with_final_lr(1.0)
+            .init();
+        let decay = ExponentialLRSchedulerConfig::new(1.0, 0.5).init();
+        let constant = PolynomialLRSchedulerConfig::new(0.1, 1).init();
+
+        // Nested to ensure chains can be composed.
+        let main = WarmupLRSchedulerConfig::new(10).init(decay, constant);
+        WarmupLRSchedulerConfig::new(2).init(warmup, main)
+    }
+}
@@ -182,6 +182,16 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 pub trait ModuleVisitor<B: Backend> {
     /// Visit a tensor in the module.
     fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>);
+
+    /// Called when entering a sub-module, before any of its tensors are visited.
+    ///
+    /// The name is the field name of the sub-module, or its index when the sub-module is an
+    /// element of a collection. Together with [exit_module](ModuleVisitor::exit_module), it can
+    /// be used to track the path of each visited tensor.
+    fn enter_module(&mut self, _name: &str) {}
+
+    /// Called when exiting a sub-module, after all of its tensors have been visited.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
@@ -1,5 +1,5 @@
 use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use burn_tensor::backend::{ADBackend, Backend};
 use core::fmt::Debug;
 
@@ -20,6 +20,14 @@ where
         self.map(|module| module.map(mapper))
     }
 
+    fn to_device(self, device: &B::Device) -> Self {
+        self.map(|module| module.to_device(device))
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.map(|module| module.fork(device))
+    }
+
     fn load_record(self, record: Self::Record) -> Self {
         self.zip(record)
             .map(|(module, record)| module.load_record(record))
@@ -59,8 +67,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -68,6 +79,16 @@ where
         self.into_iter().map(|module| module.map(mapper)).collect()
     }
 
+    fn to_device(self, device: &B::Device) -> Self {
+        self.into_iter()
+            .map(|module| module.to_device(device))
+            .collect()
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.into_iter().map(|module| module.fork(device)).collect()
+    }
+
     fn into_record(self) -> Self::Record {
         self.into_iter().map(Module::into_record).collect()
     }
@@ -118,8 +139,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -127,6 +151,14 @@ where
         self.map(|module| module.map(mapper))
     }
 
+    fn to_device(self, device: &B::Device) -> Self {
+        self.map(|module| module.to_device(device))
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.map(|module| module.fork(device))
+    }
+
     fn load_record(self, record: Self::Record) -> Self {
         self.into_iter()
             .zip(record)
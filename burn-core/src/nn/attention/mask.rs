@@ -10,16 +10,30 @@ pub fn generate_autoregressive_mask<B: Backend>(
     seq_length: usize,
     device: &B::Device,
 ) -> Tensor<B, 3, Bool> {
-    let mut mask = Tensor::<B, 3, Int>::zeros([1, seq_length, seq_length]);
+    // Each position can only attend to itself and the positions before it.
+    let mask = Tensor::<B, 3, Int>::ones_device([batch_size, seq_length, seq_length], device);
 
-    for i in 0..(seq_length - 1) {
-        let values = Tensor::<B, 3, Int>::ones([1, 1, seq_length - (i + 1)]);
-        mask = mask.index_assign([0..1, i..i + 1, i + 1..seq_length], values);
-    }
-
-    mask = mask.to_device(device).repeat(0, batch_size);
+    mask.triu(1).equal_elem(1_i64.elem::<i64>())
+}
 
-    mask.equal_elem(1_i64.elem::<i64>())
+/// Generate a sliding window attention mask.
+///
+/// Like the [autoregressive mask](generate_autoregressive_mask), but each position can only attend
+/// to itself and the `window_size - 1` positions before it.
+pub fn generate_sliding_window_mask<B: Backend>(
+    batch_size: usize,
+    seq_length: usize,
+    window_size: usize,
+    device: &B::Device,
+) -> Tensor<B, 3, Bool> {
+    // The positions after the current one and the ones outside of the window are masked.
+    let mask = Tensor::<B, 3, Int>::ones_device([batch_size, seq_length, seq_length], device);
+    let after = mask.clone().triu(1).equal_elem(1_i64.elem::<i64>());
+    let outside = mask
+        .tril(-(window_size as i64))
+        .equal_elem(1_i64.elem::<i64>());
+
+    after.bool_or(outside)
 }
 
 /// Generate a padding attention mask.
@@ -116,6 +130,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_sliding_window_mask() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let mask = generate_sliding_window_mask::<TestBackend>(1, 4, 2, &device);
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[
+                [false, true, true, true],
+                [false, false, true, true],
+                [true, false, false, true],
+                [true, true, false, false],
+            ]])
+        );
+    }
+
     #[test]
     fn test_generate_padding_mask() {
         let device = <TestBackend as Backend>::Device::default();
@@ -5,7 +5,7 @@ use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, module, ops::AttentionOptions, Bool, Tensor},
 };
 use libm::sqrtf;
 
@@ -16,6 +16,11 @@ pub struct MultiHeadAttentionConfig {
     d_model: usize,
     /// The number of heads.
     n_heads: usize,
+    /// The number of key and value heads, each one shared by a group of `n_heads / n_heads_kv`
+    /// query heads. Default: `n_heads`
+    ///
+    /// Use `1` for multi-query attention, or a divisor of `n_heads` for grouped-query attention.
+    n_heads_kv: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     dropout: f64,
@@ -24,6 +29,9 @@ pub struct MultiHeadAttentionConfig {
     /// A value too low might result in NaN.
     #[config(default = -1.0e4)]
     min_float: f64,
+    /// The rotary positional encoding applied to the queries and keys, if any.
+    /// Its `d_model` must be the size of each head, `d_model / n_heads`.
+    rotary_encoding: Option<nn::RotaryEncodingConfig>,
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -31,9 +39,12 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - query: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input and `n_heads_kv * d_model / n_heads`
+///   output features.
+/// - value: [Linear](nn::Linear) layer with `d_model` input and `n_heads_kv * d_model / n_heads`
+///   output features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - rotary_encoding: Optional [RotaryEncoding](nn::RotaryEncoding) applied to the queries and keys.
 #[derive(Module, Debug)]
 pub struct MultiHeadAttention<B: Backend> {
     query: nn::Linear<B>,
@@ -42,7 +53,9 @@ pub struct MultiHeadAttention<B: Backend> {
     output: nn::Linear<B>,
     dropout: nn::Dropout,
     activation: nn::GELU,
+    rotary_encoding: Option<nn::RotaryEncoding>,
     n_heads: usize,
+    n_heads_kv: usize,
     d_k: usize,
     min_float: f64,
 }
@@ -60,16 +73,19 @@ pub struct MhaInput<B: Backend> {
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self) -> MultiHeadAttention<B> {
-        let linear = |config: &Self| nn::LinearConfig::new(config.d_model, config.d_model).init();
+        let linear = |d_output| nn::LinearConfig::new(self.d_model, d_output).init();
+        let d_kv = self.n_heads_kv() * self.d_model / self.n_heads;
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self.d_model),
+            key: linear(d_kv),
+            value: linear(d_kv),
+            output: linear(self.d_model),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
+            rotary_encoding: self.init_rotary_encoding(),
             n_heads: self.n_heads,
+            n_heads_kv: self.n_heads_kv(),
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
         }
@@ -81,22 +97,47 @@ impl MultiHeadAttentionConfig {
         &self,
         record: MultiHeadAttentionRecord<B>,
     ) -> MultiHeadAttention<B> {
-        let linear = |config: &Self, record| {
-            nn::LinearConfig::new(config.d_model, config.d_model).init_with(record)
-        };
+        let linear =
+            |d_output, record| nn::LinearConfig::new(self.d_model, d_output).init_with(record);
+        let d_kv = self.n_heads_kv() * self.d_model / self.n_heads;
 
         MultiHeadAttention {
-            query: linear(self, record.query),
-            key: linear(self, record.key),
-            value: linear(self, record.value),
-            output: linear(self, record.output),
+            query: linear(self.d_model, record.query),
+            key: linear(d_kv, record.key),
+            value: linear(d_kv, record.value),
+            output: linear(self.d_model, record.output),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
+            rotary_encoding: self.init_rotary_encoding(),
             n_heads: self.n_heads,
+            n_heads_kv: self.n_heads_kv(),
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
         }
     }
+
+    fn n_heads_kv(&self) -> usize {
+        let n_heads_kv = self.n_heads_kv.unwrap_or(self.n_heads);
+
+        assert_eq!(
+            self.n_heads % n_heads_kv,
+            0,
+            "The number of heads must be a multiple of the number of key and value heads"
+        );
+
+        n_heads_kv
+    }
+
+    fn init_rotary_encoding(&self) -> Option<nn::RotaryEncoding> {
+        self.rotary_encoding.as_ref().map(|config| {
+            assert_eq!(
+                config.d_model,
+                self.d_model / self.n_heads,
+                "The rotary encoding d_model must be the size of each head"
+            );
+            config.init()
+        })
+    }
 }
 
 impl<B: Backend> MhaInput<B> {
@@ -139,7 +180,7 @@ impl<B: Backend> MhaInput<B> {
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights [batch_size, seq_length_1, seq_length_2].
+    /// The attention weights [batch_size, n_heads, seq_length_1, seq_length_2].
     pub weights: Tensor<B, 4>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
@@ -155,25 +196,63 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let (context, weights) =
+            self.forward_with(input, |query, key, value, mask_pad, mask_attn| {
+                self.attention_with_weights(query, key, value, mask_pad, mask_attn)
+            });
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
+        MhaOutput { weights, context }
+    }
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+    /// Applies the forward pass on the input tensors, returning only the context.
+    ///
+    /// The attention weights aren't returned, so the attention is computed with the
+    /// [scaled dot-product attention](crate::tensor::module::scaled_dot_product_attention)
+    /// operation when the dropout isn't active, letting backends avoid materializing the attention
+    /// matrix.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_fused(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        let (context, _) = self.forward_with(input, |query, key, value, mask_pad, mask_attn| {
+            (
+                self.attention_fused(query, key, value, mask_pad, mask_attn),
+                (),
+            )
+        });
+
+        context
+    }
 
-        let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
-        let context = self.output.forward(context);
+    /// Applies the forward pass using a cache.
+    ///
+    /// With a [sliding window](MhaCache::sliding_window) cache, only the last `window_size`
+    /// positions are attended to, and the weights and the context only cover those positions. The
+    /// masks can still be given for the whole sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
+        let (context, weights) =
+            self.forward_cache_with(input, cache, |query, key, value, mask_pad, mask_attn| {
+                self.attention_with_weights(query, key, value, mask_pad, mask_attn)
+            });
 
         MhaOutput { weights, context }
     }
 
-    /// Applies the forward pass using a cache.
+    /// Applies the forward pass using a cache, returning only the context.
+    ///
+    /// See [forward_fused](MultiHeadAttention::forward_fused) and
+    /// [forward_cache](MultiHeadAttention::forward_cache).
     ///
     /// # Shapes
     ///
@@ -181,30 +260,179 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - key: `[batch_size, seq_length_2, d_model]`
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
-    pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
+    pub fn forward_cache_fused(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> Tensor<B, 3> {
+        let (context, _) =
+            self.forward_cache_with(input, cache, |query, key, value, mask_pad, mask_attn| {
+                (
+                    self.attention_fused(query, key, value, mask_pad, mask_attn),
+                    (),
+                )
+            });
+
+        context
+    }
 
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
+    fn forward_with<O, F>(&self, input: MhaInput<B>, attention: F) -> (Tensor<B, 3>, O)
+    where
+        F: FnOnce(
+            Tensor<B, 4>,
+            Tensor<B, 4>,
+            Tensor<B, 4>,
+            Option<Tensor<B, 2, Bool>>,
+            Option<Tensor<B, 3, Bool>>,
+        ) -> (Tensor<B, 4>, O),
+    {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let query = self.rotary(query, 0);
+        let key = self.attention_linear(input.key, &self.key, self.n_heads_kv);
+        let key = self.repeat_kv(self.rotary(key, 0));
+        let value = self.attention_linear(input.value, &self.value, self.n_heads_kv);
+        let value = self.repeat_kv(value);
 
-        let context = weights.clone().matmul(value);
+        let (context, weights) = attention(query, key, value, input.mask_pad, input.mask_attn);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+
+        (context, weights)
+    }
+
+    fn forward_cache_with<O, F>(
+        &self,
+        input: MhaInput<B>,
+        cache: &mut MhaCache<B>,
+        attention: F,
+    ) -> (Tensor<B, 3>, O)
+    where
+        F: FnOnce(
+            Tensor<B, 4>,
+            Tensor<B, 4>,
+            Tensor<B, 4>,
+            Option<Tensor<B, 2, Bool>>,
+            Option<Tensor<B, 3, Bool>>,
+        ) -> (Tensor<B, 4>, O),
+    {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let [_, seq_length_2, _] = input.key.dims();
+
+        // The cache only computes the projection of the newest tokens, which are the last ones of
+        // the sequence.
+        let query = cache.query.forward(input.query, |t| {
+            let start = seq_length_1 - t.dims()[1];
+            let query = self.attention_linear(t, &self.query, self.n_heads);
+            self.rotary(query, start)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            let start = seq_length_2 - t.dims()[1];
+            let key = self.attention_linear(t, &self.key, self.n_heads_kv);
+            self.rotary(key, start)
+        });
+        let value = cache.value.forward(input.value, |t| {
+            self.attention_linear(t, &self.value, self.n_heads_kv)
+        });
+
+        // Keys and values are cached once per key-value head, and only shared across the query
+        // heads afterward to keep the cache small.
+        let key = self.repeat_kv(key);
+        let value = self.repeat_kv(value);
+
+        let [_, _, seq_length_query, _] = query.dims();
+        let [_, _, seq_length_key, _] = key.dims();
+
+        let mask_pad = input.mask_pad.map(|mask_pad| {
+            let [batch_size, seq_length] = mask_pad.dims();
+            mask_pad.index([0..batch_size, seq_length - seq_length_key..seq_length])
+        });
+        let mask_attn = input.mask_attn.map(|mask_attn| {
+            let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
+            mask_attn.index([
+                0..batch_size,
+                seq_length_1 - seq_length_query..seq_length_1,
+                seq_length_2 - seq_length_key..seq_length_2,
+            ])
+        });
+
+        let (context, weights) = attention(query, key, value, mask_pad, mask_attn);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_query, d_model]);
 
         let context = cache.output.forward(context, |t| self.output.forward(t));
 
-        MhaOutput { weights, context }
+        (context, weights)
+    }
+
+    fn attention_with_weights(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+        let context = weights.clone().matmul(value);
+
+        (context, weights)
+    }
+
+    fn attention_fused(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> Tensor<B, 4> {
+        // The operation can't apply the dropout on the attention scores.
+        if self.dropout.is_active::<B>() {
+            let (context, _) = self.attention_with_weights(query, key, value, mask_pad, mask_attn);
+
+            return context;
+        }
+
+        let [_, _, seq_length_1, _] = query.dims();
+        let mask = self.attn_mask(mask_pad, mask_attn, seq_length_1);
+        let options = AttentionOptions::new(1.0 / sqrtf(self.d_k as f32) as f64, self.min_float);
+
+        module::scaled_dot_product_attention(query, key, value, mask, options)
+    }
+
+    /// Merges the padding and the attention masks into a mask of shape
+    /// `[batch_size, 1, seq_length_1, seq_length_2]`.
+    fn attn_mask(
+        &self,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        seq_length_1: usize,
+    ) -> Option<Tensor<B, 4, Bool>> {
+        let mask_pad = mask_pad.map(|mask_pad| {
+            let [batch_size, seq_length_2] = mask_pad.dims();
+            mask_pad
+                .reshape([batch_size, 1, seq_length_2])
+                .repeat(1, seq_length_1)
+        });
+
+        let mask = match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => {
+                let [batch_size, _, _] = mask_pad.dims();
+                let mask_attn = match mask_attn.dims()[0] == batch_size {
+                    true => mask_attn,
+                    false => mask_attn.repeat(0, batch_size),
+                };
+                Some(mask_pad.bool_or(mask_attn))
+            }
+            (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+        };
+
+        mask.map(|mask| {
+            let [batch_size, seq_length_1, seq_length_2] = mask.dims();
+            mask.reshape([batch_size, 1, seq_length_1, seq_length_2])
+        })
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -242,11 +470,37 @@ impl<B: Backend> MultiHeadAttention<B> {
         activation::softmax(attn_scores, 3)
     }
 
-    fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+    fn rotary(&self, x: Tensor<B, 4>, start: usize) -> Tensor<B, 4> {
+        match &self.rotary_encoding {
+            Some(rotary_encoding) => rotary_encoding.apply(x, start),
+            None => x,
+        }
+    }
+
+    fn repeat_kv(&self, x: Tensor<B, 4>) -> Tensor<B, 4> {
+        let n_groups = self.n_heads / self.n_heads_kv;
+
+        if n_groups == 1 {
+            return x;
+        }
+
+        let [batch_size, n_heads_kv, seq_length, d_k] = x.dims();
+
+        x.reshape([batch_size, n_heads_kv, 1, seq_length * d_k])
+            .repeat(2, n_groups)
+            .reshape([batch_size, self.n_heads, seq_length, d_k])
+    }
+
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
 }
@@ -263,6 +517,7 @@ pub struct MhaCache<B: Backend> {
 
 enum MhaLinearCache<B: Backend, const D: usize> {
     Autoregressive(TensorCache<B, D>, usize),
+    SlidingWindow(TensorCache<B, D>, usize, usize),
     Full(TensorCache<B, D>),
 }
 
@@ -277,6 +532,18 @@ impl<B: Backend> MhaCache<B> {
         }
     }
 
+    /// Initialize a cache for autoregressive inference that only keeps the queries, keys, values
+    /// and outputs of the last `window_size` positions, bounding the memory used for long
+    /// sequences.
+    pub fn sliding_window(window_size: usize) -> Self {
+        Self {
+            query: MhaLinearCache::SlidingWindow(TensorCache::empty(), 2, window_size),
+            key: MhaLinearCache::SlidingWindow(TensorCache::empty(), 2, window_size),
+            value: MhaLinearCache::SlidingWindow(TensorCache::empty(), 2, window_size),
+            output: MhaLinearCache::SlidingWindow(TensorCache::empty(), 1, window_size),
+        }
+    }
+
     /// Initialize a cache for autoregressive inference, but with a fixed memory used for keys and
     /// values (cross-attention).
     pub fn autoregressive_cross_attention() -> Self {
@@ -299,6 +566,9 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
             MhaLinearCache::Autoregressive(cache, dim) => {
                 cache.forward_autoregressive(tensor, *dim, func)
             }
+            MhaLinearCache::SlidingWindow(cache, dim, window_size) => {
+                cache.forward_sliding_window(tensor, *dim, *window_size, func)
+            }
             MhaLinearCache::Full(cache) => cache.forward_full(tensor, func),
         }
     }
@@ -307,7 +577,11 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{
+        nn::attention::{generate_autoregressive_mask, generate_sliding_window_mask},
+        nn::cache::CacheState,
+        TestBackend,
+    };
     use alloc::vec::Vec;
     use burn::tensor::{Distribution, Shape};
     use burn_tensor::Int;
@@ -406,6 +680,35 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_forward_fused_should_have_same_context() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 5, 12, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+        let mask_pad = Tensor::<TestBackend, 2, Int>::from_data([
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1],
+            [0, 0, 0, 1, 1],
+        ])
+        .equal_elem(1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input.clone());
+        let output_2 = mha.forward_fused(input);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
         let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
@@ -440,4 +743,201 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_rotary_encoding_with_autoregressive_cache_should_match_masked_forward() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_rotary_encoding(Some(nn::RotaryEncodingConfig::new(16, d_model / n_heads)))
+            .init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::autoregressive();
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().index([0..batch_size, 0..i, 0..d_model]);
+            let input = MhaInput::self_attn(tensor);
+            let next_tok = mha.forward_cache(input, &mut cache).context.index([
+                0..batch_size,
+                i - 1..i,
+                0..d_model,
+            ]);
+            output_2.push(next_tok);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_grouped_query_attention_shapes() {
+        let [batch_size, seq_length, d_model, n_heads, n_heads_kv] = [7, 13, 32, 4, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_n_heads_kv(Some(n_heads_kv))
+            .init::<TestBackend>();
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        ));
+
+        let output = mha.forward(input);
+
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length, d_model]),
+            "Context should have the correct shape",
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length, seq_length]),
+            "Weights should have the correct shape",
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_share_kv_heads() {
+        let [batch_size, seq_length, d_model] = [2, 5, 8];
+        let mha = MultiHeadAttentionConfig::new(d_model, 4)
+            .with_n_heads_kv(Some(1))
+            .init::<TestBackend>();
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        ));
+
+        let key = mha.attention_linear(input.key, &mha.key, mha.n_heads_kv);
+        let key = mha.repeat_kv(key);
+
+        for head in 1..4 {
+            key.clone()
+                .index([0..batch_size, head..head + 1])
+                .into_data()
+                .assert_approx_eq(&key.clone().index([0..batch_size, 0..1]).into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn test_grouped_query_attention_autoregressive_decoding() {
+        let [batch_size, seq_length, d_model, n_heads, n_heads_kv] = [3, 4, 12, 6, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_n_heads_kv(Some(n_heads_kv))
+            .init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::autoregressive();
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().index([0..batch_size, 0..i, 0..d_model]);
+            let input = MhaInput::self_attn(tensor);
+            let next_tok = mha.forward_cache(input, &mut cache).context.index([
+                0..batch_size,
+                i - 1..i,
+                0..d_model,
+            ]);
+            output_2.push(next_tok);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_sliding_window_cache_should_match_sliding_window_mask() {
+        let [batch_size, seq_length, d_model, n_heads, window_size] = [3, 6, 12, 2, 3];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_rotary_encoding(Some(nn::RotaryEncodingConfig::new(16, d_model / n_heads)))
+            .init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+        let mask_attn =
+            generate_sliding_window_mask(batch_size, seq_length, window_size, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::sliding_window(window_size);
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().index([0..batch_size, 0..i, 0..d_model]);
+            let input = MhaInput::self_attn(tensor);
+            let output = mha.forward_cache(input, &mut cache);
+
+            let window = usize::min(i, window_size);
+            assert_eq!(output.weights.dims()[3], window);
+            assert_eq!(output.context.dims()[1], window);
+            output_2.push(
+                output
+                    .context
+                    .index([0..batch_size, window - 1..window, 0..d_model]),
+            );
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_sliding_window_cache_should_bound_cached_tensors() {
+        let [batch_size, seq_length, d_model, n_heads, window_size] = [2, 7, 8, 2, 3];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+        let mut cache = MhaCache::sliding_window(window_size);
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().index([0..batch_size, 0..i, 0..d_model]);
+            mha.forward_cache(MhaInput::self_attn(tensor), &mut cache);
+        }
+
+        assert_eq!(cached_dims(&cache.query)[2], window_size);
+        assert_eq!(cached_dims(&cache.key)[2], window_size);
+        assert_eq!(cached_dims(&cache.value)[2], window_size);
+        assert_eq!(cached_dims(&cache.output)[1], window_size);
+    }
+
+    fn cached_dims<const D: usize>(cache: &MhaLinearCache<TestBackend, D>) -> [usize; D] {
+        let cache = match cache {
+            MhaLinearCache::Autoregressive(cache, _) => cache,
+            MhaLinearCache::SlidingWindow(cache, _, _) => cache,
+            MhaLinearCache::Full(cache) => cache,
+        };
+
+        match &cache.state {
+            CacheState::Value(tensor) => tensor.dims(),
+            CacheState::Empty => panic!("The cache should contain a tensor."),
+        }
+    }
 }
@@ -33,6 +33,32 @@ impl<B: Backend, const D: usize> TensorCache<B, D> {
         tensor_new
     }
 
+    pub(crate) fn forward_sliding_window<F>(
+        &mut self,
+        tensor: Tensor<B, 3>,
+        dim_cat: usize,
+        window_size: usize,
+        func: F,
+    ) -> Tensor<B, D>
+    where
+        F: Fn(Tensor<B, 3>) -> Tensor<B, D>,
+    {
+        let tensor_new = self.forward_autoregressive(tensor, dim_cat, func);
+        let dims = tensor_new.dims();
+
+        if dims[dim_cat] <= window_size {
+            return tensor_new;
+        }
+
+        let mut indexes = dims.map(|dim| 0..dim);
+        indexes[dim_cat] = dims[dim_cat] - window_size..dims[dim_cat];
+
+        let tensor_new = tensor_new.index(indexes);
+
+        self.state = CacheState::Value(tensor_new.clone());
+        tensor_new
+    }
+
     pub(crate) fn forward_full<F>(&mut self, tensor: Tensor<B, 3>, func: F) -> Tensor<B, D>
     where
         F: Fn(Tensor<B, 3>) -> Tensor<B, D>,
@@ -0,0 +1,219 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::conv3d;
+use burn_tensor::ops::conv::calculate_conv_padding;
+use burn_tensor::ops::ConvOptions;
+use libm::sqrt;
+
+/// Configuration to create an [3D convolution](Conv3d) layer.
+#[derive(Config, Debug)]
+pub struct Conv3dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1, 1]")]
+    pub stride: [usize; 3],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1, 1]")]
+    pub dilation: [usize; 3],
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "Conv3dPaddingConfig::Valid")]
+    pub padding: Conv3dPaddingConfig,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// Padding configuration for 3D convolution [config](Conv3dConfig).
+#[derive(Module, Config, Debug)]
+pub enum Conv3dPaddingConfig {
+    /// Dynamicaly calculate the amount of padding necessary to ensure that the output size will be
+    /// the same as the input.
+    Same,
+    /// Same as no padding.
+    Valid,
+    /// Applies the specified amount of padding to all inputs.
+    Explicit(usize, usize, usize),
+}
+
+/// Applies a 3D convolution over input tensors.
+///
+/// # Params
+///
+/// - weight: Tensor of shape `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2, kernel_size_3]`
+///   initialized from a uniform distribution `U(-k, k)` where
+///   `k = sqrt(1 / channels_in * kernel_size_1 * kernel_size_2 * kernel_size_3)`
+///
+/// - bias:   Tensor of shape `[channels_out]`, initialized from a uniform distribution `U(-k, k)`
+///   where `k = sqrt(1 / channels_in * kernel_size_1 * kernel_size_2 * kernel_size_3)`
+#[derive(Module, Debug)]
+pub struct Conv3d<B: Backend> {
+    weight: Param<Tensor<B, 5>>,
+    bias: Option<Param<Tensor<B, 1>>>,
+    stride: [usize; 3],
+    kernel_size: [usize; 3],
+    dilation: [usize; 3],
+    groups: usize,
+    padding: Conv3dPaddingConfig,
+}
+
+impl Conv3dConfig {
+    /// Initialize a new [conv3d](Conv3d) module.
+    pub fn init<B: Backend>(&self) -> Conv3d<B> {
+        let shape = [
+            self.channels[1],
+            self.channels[0] / self.groups,
+            self.kernel_size[0],
+            self.kernel_size[1],
+            self.kernel_size[2],
+        ];
+        let fan_in = self.channels[0] / self.groups * self.kernel_size.iter().product::<usize>();
+        let weight = self.initializer.init_with(shape, Some(fan_in), None);
+        let bias = if self.bias {
+            Some(
+                self.initializer
+                    .init_with([self.channels[1]], Some(fan_in), None),
+            )
+        } else {
+            None
+        };
+
+        Conv3d {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+
+    /// Initialize a new [conv3d](Conv3d) module with a [record](Conv3dRecord).
+    pub fn init_with<B: Backend>(&self, record: Conv3dRecord<B>) -> Conv3d<B> {
+        Conv3d {
+            weight: record.weight,
+            bias: record.bias,
+            stride: self.stride,
+            dilation: self.dilation,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+}
+
+impl<B: Backend> Conv3d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, depth_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            [depth_in, height_in, width_in],
+            &self.kernel_size,
+            &self.stride,
+        );
+        conv3d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+}
+
+impl Conv3dPaddingConfig {
+    pub(crate) fn calculate_padding_3d(
+        &self,
+        size: [usize; 3],
+        kernel_size: &[usize; 3],
+        stride: &[usize; 3],
+    ) -> [usize; 3] {
+        let same_padding = || {
+            let p1 = calculate_conv_padding(kernel_size[0], stride[0], size[0], size[0]);
+            let p2 = calculate_conv_padding(kernel_size[1], stride[1], size[1], size[1]);
+            let p3 = calculate_conv_padding(kernel_size[2], stride[2], size[2], size[2]);
+
+            [p1, p2, p3]
+        };
+
+        match self {
+            Conv3dPaddingConfig::Same => same_padding(),
+            Conv3dPaddingConfig::Valid => [0, 0, 0],
+            Conv3dPaddingConfig::Explicit(v1, v2, v3) => [*v1, *v2, *v3],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::{Data, Distribution};
+
+    use super::*;
+    use crate::TestBackend;
+    use libm::sqrt;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 1], [3, 3, 3]);
+        let k = (config.channels[0] * config.kernel_size.iter().product::<usize>()) as f64;
+        let k = sqrt(1.0 / k) as f32;
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(
+            config.initializer,
+            Initializer::KaimingUniform {
+                gain: 1.0 / sqrt(3.0),
+                fan_out_only: false
+            }
+        );
+        conv.weight.to_data().assert_within_range(-k..k);
+    }
+
+    #[test]
+    fn initializer_zeros() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 2], [3, 3, 3]).with_initializer(Initializer::Zeros);
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::Zeros);
+        conv.weight
+            .to_data()
+            .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
+    }
+
+    #[test]
+    fn same_padding_keeps_spatial_dims() {
+        let conv = Conv3dConfig::new([4, 6], [3, 3, 3])
+            .with_groups(2)
+            .with_padding(Conv3dPaddingConfig::Same)
+            .init::<TestBackend>();
+        let input = Tensor::<TestBackend, 5>::random([2, 4, 3, 5, 6], Distribution::Standard);
+
+        let output = conv.forward(input);
+
+        assert_eq!(conv.weight.shape().dims, [6, 2, 3, 3, 3]);
+        assert_eq!(output.dims(), [2, 6, 3, 5, 6]);
+    }
+}
@@ -0,0 +1,172 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::conv_transpose1d;
+use burn_tensor::ops::ConvTransposeOptions;
+use libm::sqrt;
+
+/// Configuration to create an [1D transposed convolution](ConvTranspose1d) layer.
+#[derive(Config)]
+pub struct ConvTranspose1dConfig {
+    /// The number of input channels.
+    pub channels_in: usize,
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride of the convolution.
+    #[config(default = "1")]
+    pub stride: usize,
+    /// Spacing between kernel elements.
+    #[config(default = "1")]
+    pub dilation: usize,
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "0")]
+    pub padding: usize,
+    /// The additional size added to one side of the output shape.
+    #[config(default = "0")]
+    pub padding_out: usize,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// Applies a 1D transposed convolution over input tensors.
+///
+/// # Params
+///
+/// - weight: Tensor of shape `[channels_in, channels_out / groups, kernel_size]` initialized from
+///   a uniform distribution `U(-k, k)` where `k = sqrt(1 / channels_out / groups * kernel_size)`
+///
+/// - bias:   Tensor of shape `[channels_out]`, initialized from a uniform distribution `U(-k, k)`
+///   where `k = sqrt(1 / channels_out / groups * kernel_size)`
+#[derive(Module, Debug)]
+pub struct ConvTranspose1d<B: Backend> {
+    weight: Param<Tensor<B, 3>>,
+    bias: Option<Param<Tensor<B, 1>>>,
+    stride: usize,
+    kernel_size: usize,
+    dilation: usize,
+    groups: usize,
+    padding: usize,
+    padding_out: usize,
+}
+
+impl ConvTranspose1dConfig {
+    /// Initialize a new [conv transpose 1d](ConvTranspose1d) module.
+    pub fn init<B: Backend>(&self) -> ConvTranspose1d<B> {
+        let shape = [
+            self.channels_in,
+            self.channels_out / self.groups,
+            self.kernel_size,
+        ];
+        let fan_in = self.channels_out / self.groups * self.kernel_size;
+        let weight = self.initializer.init_with(shape, Some(fan_in), None);
+        let bias = if self.bias {
+            Some(
+                self.initializer
+                    .init_with([self.channels_out], Some(fan_in), None),
+            )
+        } else {
+            None
+        };
+
+        ConvTranspose1d {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+            padding_out: self.padding_out,
+        }
+    }
+
+    /// Initialize a new [conv transpose 1d](ConvTranspose1d) module with a
+    /// [record](ConvTranspose1dRecord).
+    pub fn init_with<B: Backend>(&self, record: ConvTranspose1dRecord<B>) -> ConvTranspose1d<B> {
+        ConvTranspose1d {
+            weight: record.weight,
+            bias: record.bias,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            groups: self.groups,
+            padding: self.padding,
+            padding_out: self.padding_out,
+        }
+    }
+}
+
+impl<B: Backend> ConvTranspose1d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, length_in],
+    /// - output: [batch_size, channels_out, length_out],
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        conv_transpose1d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvTransposeOptions::new(
+                [self.stride],
+                [self.padding],
+                [self.padding_out],
+                [self.dilation],
+                self.groups,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::{Data, Distribution};
+
+    use super::*;
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn initializer_zeros() {
+        TestBackend::seed(0);
+
+        let config = ConvTranspose1dConfig::new(5, 2, 5).with_initializer(Initializer::Zeros);
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::Zeros);
+        conv.weight
+            .to_data()
+            .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
+    }
+
+    #[test]
+    fn forward_output_shape_and_gradients() {
+        let conv = ConvTranspose1dConfig::new(2, 4, 3)
+            .with_stride(2)
+            .with_dilation(2)
+            .with_padding(1)
+            .with_padding_out(1)
+            .init::<TestADBackend>();
+        let input = Tensor::random([2, 2, 5], Distribution::Standard);
+
+        let output = conv.forward(input);
+        let grads = output.backward();
+
+        assert_eq!(output.dims(), [2, 4, 12]);
+        assert_eq!(conv.weight.grad(&grads).unwrap().dims(), conv.weight.dims());
+    }
+}
@@ -38,7 +38,7 @@ impl Dropout {
     /// - input: `[..., any]`
     /// - output: `[..., any]`
     pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        if !B::ad_enabled() || self.prob == 0.0 {
+        if !self.is_active::<B>() {
             return input;
         }
 
@@ -48,6 +48,11 @@ impl Dropout {
 
         x * (1.0 / prob_keep)
     }
+
+    /// Whether elements are dropped, which is only the case during training.
+    pub(crate) fn is_active<B: Backend>(&self) -> bool {
+        B::ad_enabled() && self.prob > 0.0
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,137 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+
+use burn_tensor::{activation, backend::Backend, Bool, Tensor};
+
+/// Calculate the binary cross entropy loss from the input logits and the targets.
+///
+/// The sigmoid is applied to the logits as part of the loss, which is more numerically stable
+/// than applying it beforehand.
+#[derive(Clone, Debug)]
+pub struct BinaryCrossEntropyWithLogitsLoss<B: Backend> {
+    pos_weight: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> Default for BinaryCrossEntropyWithLogitsLoss<B> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<B: Backend> BinaryCrossEntropyWithLogitsLoss<B> {
+    /// Create the criterion.
+    ///
+    /// The optional `pos_weight` of shape `[num_targets]` multiplies the loss of the positive
+    /// examples of each target, trading recall for precision.
+    pub fn new(pos_weight: Option<Tensor<B, 1>>) -> Self {
+        Self { pos_weight }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(logits, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        // -log(sigmoid(x)) = log(1 + exp(-|x|)) + max(-x, 0), which can't overflow.
+        let neg_log_sigmoid = logits
+            .clone()
+            .abs()
+            .neg()
+            .exp()
+            .log1p()
+            .add(activation::relu(logits.clone().neg()));
+
+        let neg_log_sigmoid = match &self.pos_weight {
+            Some(pos_weight) => {
+                let pos_weight = pos_weight.clone().unsqueeze::<D>().sub_scalar(1.0);
+                let log_weight = targets.clone().mul(pos_weight).add_scalar(1.0);
+
+                neg_log_sigmoid.mul(log_weight)
+            }
+            None => neg_log_sigmoid,
+        };
+
+        // -log(1 - sigmoid(x)) = x - log(sigmoid(x)).
+        targets
+            .ones_like()
+            .sub(targets)
+            .mul(logits)
+            .add(neg_log_sigmoid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::Reduction;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_loss() {
+        let logits =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, -1.0, 2.0], [-3.0, 0.2, 40.0]]));
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]));
+
+        let bce = BinaryCrossEntropyWithLogitsLoss::default();
+        let loss_no_reduction = bce.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = bce.forward(logits, targets, Reduction::Auto);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[0.4741, 0.3133, 2.1269], [3.0486, 0.5981, 40.0]]),
+            3,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([7.7602]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_loss_pos_weight() {
+        let logits =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, -1.0, 2.0], [-3.0, 0.2, 40.0]]));
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]));
+        let pos_weight = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 3.0, 0.5]));
+
+        let bce = BinaryCrossEntropyWithLogitsLoss::new(Some(pos_weight));
+        let loss_no_reduction = bce.forward_no_reduction(logits, targets);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[0.9482, 0.3133, 2.1269], [6.0972, 1.7944, 40.0]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, Bool, Int, Tensor};
+
+/// Calculate the cosine embedding loss from two batches of embeddings and the targets telling
+/// whether each pair is similar (`1`) or dissimilar (`-1`).
+#[derive(Clone, Debug)]
+pub struct CosineEmbeddingLoss<B: Backend> {
+    margin: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CosineEmbeddingLoss<B> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<B: Backend> CosineEmbeddingLoss<B> {
+    /// Create the criterion.
+    ///
+    /// Dissimilar pairs only contribute to the loss when their cosine similarity is greater than
+    /// `margin`.
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: [batch_size, d_model]
+    /// - input_2: [batch_size, d_model]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(input_1, input_2, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        weights: Option<Tensor<B, 1>>,
+        mask: Option<Tensor<B, 1, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(input_1, input_2, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = input_1.dims();
+
+        let dot = input_1.clone().mul(input_2.clone()).sum_dim(1);
+        let norm_1 = input_1.powf(2.0).sum_dim(1).add_scalar(EPSILON);
+        let norm_2 = input_2.powf(2.0).sum_dim(1).add_scalar(EPSILON);
+        let cosine = dot.div(norm_1.mul(norm_2).sqrt()).reshape([batch_size]);
+
+        let similar = cosine.ones_like().sub(cosine.clone());
+        let dissimilar = activation::relu(cosine.sub_scalar(self.margin));
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+const EPSILON: f32 = 1e-12;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let input_1 =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [1.0, 1.0], [3.0, 4.0]]));
+        let input_2 =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0], [2.0, 2.0], [4.0, 3.0]]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, -1, -1]));
+
+        let loss = CosineEmbeddingLoss::new(0.5);
+        let loss = loss.forward_no_reduction(input_1, input_2, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.2929, 0.5, 0.46]), 3);
+    }
+}
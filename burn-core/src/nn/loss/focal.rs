@@ -0,0 +1,136 @@
+use crate::nn::loss::binary_cross_entropy::BinaryCrossEntropyWithLogitsLoss;
+use crate::nn::loss::reduction::{reduce, Reduction};
+
+use burn_tensor::{activation, backend::Backend, Bool, Tensor};
+
+/// Calculate the focal loss from the input logits and the binary targets.
+///
+/// The [binary cross entropy](BinaryCrossEntropyWithLogitsLoss) of each element is scaled by
+/// `(1 - p_t)^gamma`, where `p_t` is the probability of the correct class, so that the well
+/// classified examples contribute less to the loss.
+#[derive(Clone, Debug)]
+pub struct FocalLoss<B: Backend> {
+    alpha: Option<f32>,
+    gamma: f32,
+    cross_entropy: BinaryCrossEntropyWithLogitsLoss<B>,
+}
+
+impl<B: Backend> FocalLoss<B> {
+    /// Create the criterion.
+    ///
+    /// The optional `alpha` weights the positive examples by `alpha` and the negative ones by
+    /// `1 - alpha`.
+    pub fn new(alpha: Option<f32>, gamma: f32) -> Self {
+        Self {
+            alpha,
+            gamma,
+            cross_entropy: BinaryCrossEntropyWithLogitsLoss::new(None),
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(logits, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let cross_entropy = self
+            .cross_entropy
+            .forward_no_reduction(logits.clone(), targets.clone());
+
+        let probs = activation::sigmoid(logits);
+        let negatives = targets.ones_like().sub(targets.clone());
+        // 1 - p_t = p * (1 - y) + (1 - p) * y
+        let probs_wrong = probs
+            .clone()
+            .mul(negatives.clone())
+            .add(probs.ones_like().sub(probs).mul(targets.clone()));
+
+        let tensor = cross_entropy.mul(probs_wrong.powf(self.gamma));
+
+        match self.alpha {
+            Some(alpha) => {
+                let alpha = targets
+                    .mul_scalar(alpha)
+                    .add(negatives.mul_scalar(1.0 - alpha));
+
+                tensor.mul(alpha)
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::Reduction;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_focal_loss() {
+        let logits =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, -1.0, 2.0], [-3.0, 0.0, 4.0]]));
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0, 0.0], [1.0, 1.0, 1.0]]));
+
+        let focal = FocalLoss::new(Some(0.25), 2.0);
+        let loss_no_reduction = focal.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = focal.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[0.0169, 0.0170, 1.2376], [0.6916, 0.0433, 0.0000]]),
+            3,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([2.0063]), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_without_focusing_is_cross_entropy() {
+        let logits =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, -1.0, 2.0], [-3.0, 0.0, 4.0]]));
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0, 0.0], [1.0, 1.0, 1.0]]));
+
+        let loss_1 =
+            FocalLoss::new(None, 0.0).forward_no_reduction(logits.clone(), targets.clone());
+        let loss_2 =
+            BinaryCrossEntropyWithLogitsLoss::new(None).forward_no_reduction(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
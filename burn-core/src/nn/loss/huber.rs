@@ -0,0 +1,205 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use crate::nn::loss::L1Loss;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Tensor};
+
+/// Calculate the Huber loss from the input logits and the targets.
+///
+/// The loss is quadratic for errors smaller than `delta` and linear otherwise, making it less
+/// sensitive to outliers than the mean squared error.
+#[derive(Clone, Debug)]
+pub struct HuberLoss<B: Backend> {
+    delta: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> HuberLoss<B> {
+    /// Create the criterion.
+    ///
+    /// # Panics
+    ///
+    /// If `delta` isn't positive.
+    pub fn new(delta: f32) -> Self {
+        assert!(
+            delta > 0.0,
+            "The delta of the Huber loss must be positive, got {delta}."
+        );
+
+        Self {
+            delta,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(logits, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = logits.sub(targets).abs();
+        let is_linear = error.clone().greater_elem(self.delta);
+
+        let quadratic = error.clone().powf(2.0).mul_scalar(0.5);
+        let linear = error.sub_scalar(0.5 * self.delta).mul_scalar(self.delta);
+
+        quadratic.mask_where(is_linear, linear)
+    }
+}
+
+/// Calculate the smooth L1 loss from the input logits and the targets.
+///
+/// This is the [Huber loss](HuberLoss) with `delta = beta`, divided by `beta`, so that the linear
+/// part has a slope of one like the [L1 loss](L1Loss). It is the L1 loss when `beta` is zero.
+#[derive(Clone, Debug)]
+pub struct SmoothL1Loss<B: Backend> {
+    huber: Option<HuberLoss<B>>,
+}
+
+impl<B: Backend> SmoothL1Loss<B> {
+    /// Create the criterion.
+    ///
+    /// # Panics
+    ///
+    /// If `beta` is negative.
+    pub fn new(beta: f32) -> Self {
+        assert!(
+            beta >= 0.0,
+            "The beta of the smooth L1 loss can't be negative, got {beta}."
+        );
+
+        let huber = match beta == 0.0 {
+            true => None,
+            false => Some(HuberLoss::new(beta)),
+        };
+
+        Self { huber }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(logits, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        match &self.huber {
+            Some(huber) => huber
+                .forward_no_reduction(logits, targets)
+                .div_scalar(huber.delta),
+            None => L1Loss::new().forward_no_reduction(logits, targets),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::Reduction;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_huber_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 1.0], [3.0, 0.0]]));
+
+        let huber = HuberLoss::new(2.0);
+        let loss_no_reduction = huber.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_sum = huber.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.125, 0.5], [0.0, 6.0]]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([6.625]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 1.0], [3.0, 0.0]]));
+
+        let loss = SmoothL1Loss::new(2.0).forward_no_reduction(logits, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([[0.0625, 0.25], [0.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss_with_zero_beta_is_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 1.0], [3.0, 0.0]]));
+
+        let loss = SmoothL1Loss::new(0.0).forward_no_reduction(logits, targets);
+
+        assert_eq!(loss.into_data(), Data::from([[0.5, 1.0], [0.0, 4.0]]));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Tensor};
+
+/// Calculate the Kullback-Leibler divergence loss from the input log-probabilities and the
+/// target distribution.
+#[derive(Clone, Debug)]
+pub struct KLDivLoss<B: Backend> {
+    log_target: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for KLDivLoss<B> {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<B: Backend> KLDivLoss<B> {
+    /// Create the criterion.
+    ///
+    /// When `log_target` is true, the targets are expected to be log-probabilities like the
+    /// inputs, otherwise they are probabilities.
+    pub fn new(log_target: bool) -> Self {
+        Self {
+            log_target,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        log_probs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(log_probs, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        log_probs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(log_probs, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        log_probs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp().mul(targets.sub(log_probs));
+        }
+
+        // Targets of zero don't contribute to the loss, their logarithm is replaced by zero to
+        // avoid multiplying zero by minus infinity.
+        let zeros = targets.clone().lower_equal_elem(0.0);
+        let log_targets = targets.clone().mask_fill(zeros, 1.0).log();
+
+        targets.mul(log_targets.sub(log_probs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::Reduction;
+    use crate::TestBackend;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn test_kl_div_loss() {
+        let logits =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [0.5, 0.5, -1.0]]));
+        let targets =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.2, 0.3, 0.5], [0.1, 0.9, 0.0]]));
+        let log_probs = activation::log_softmax(logits, 1);
+
+        let kl_div = KLDivLoss::new(false);
+        let loss = kl_div.forward_no_reduction(log_probs, targets);
+
+        loss.into_data().assert_approx_eq(
+            &Data::from([[0.1596, 0.0611, -0.1428], [-0.1504, 0.6242, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_kl_div_loss_log_target() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[0.2, 0.3, 0.5]]));
+        let log_probs = activation::log_softmax(logits, 1);
+
+        let loss_1 =
+            KLDivLoss::new(false).forward(log_probs.clone(), targets.clone(), Reduction::Sum);
+        let loss_2 = KLDivLoss::new(true).forward(log_probs, targets.log(), Reduction::Sum);
+
+        loss_1
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0780]), 3);
+        loss_2
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0780]), 3);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Tensor};
+
+/// Calculate the mean absolute error loss from the input logits and the targets.
+#[derive(Clone, Debug)]
+pub struct L1Loss<B: Backend> {
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for L1Loss<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> L1Loss<B> {
+    /// Create the criterion.
+    pub fn new() -> Self {
+        Self {
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(logits, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        weights: Option<Tensor<B, D>>,
+        mask: Option<Tensor<B, D, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        logits.sub(targets).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::Reduction;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [3.0, 2.0]]));
+
+        let l1 = L1Loss::new();
+        let loss_no_reduction = l1.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = l1.forward(logits, targets, Reduction::Auto);
+
+        assert_eq!(
+            loss_no_reduction.into_data(),
+            Data::from([[1.0, 1.0], [0.0, 2.0]])
+        );
+        assert_eq!(loss.into_data(), Data::from([1.0]));
+    }
+}
@@ -1,7 +1,23 @@
+mod binary_cross_entropy;
+mod cosine_embedding;
 mod cross_entropy;
+mod focal;
+mod huber;
+mod kl_div;
+mod l1;
 mod mse;
+mod nll;
 mod reduction;
+mod triplet_margin;
 
+pub use binary_cross_entropy::*;
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use focal::*;
+pub use huber::*;
+pub use kl_div::*;
+pub use l1::*;
 pub use mse::*;
+pub use nll::*;
 pub use reduction::*;
+pub use triplet_margin::*;
@@ -0,0 +1,150 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Calculate the negative log likelihood loss from the input log-probabilities and the targets.
+#[derive(Clone, Debug)]
+pub struct NLLLoss<B: Backend> {
+    pad_index: Option<usize>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> NLLLoss<B> {
+    /// Create the criterion.
+    ///
+    /// The targets equal to `pad_index` are ignored, both in the loss and in its mean.
+    pub fn new(pad_index: Option<usize>) -> Self {
+        Self {
+            pad_index,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: [batch_size, num_targets]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(log_probs, targets, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    ///
+    /// The padded targets are always ignored, they don't count in the mean.
+    pub fn forward_weighted(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        weights: Option<Tensor<B, 1>>,
+        mask: Option<Tensor<B, 1, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let mask = match (self.padding_mask(&targets), mask) {
+            (Some(ignored), Some(mask)) => Some(ignored.bool_or(mask)),
+            (ignored, mask) => ignored.or(mask),
+        };
+        let tensor = self.forward_no_reduction(log_probs, targets);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// The loss of the padded targets is zero.
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size] = targets.dims();
+
+        let mask = self.padding_mask(&targets);
+        let tensor = log_probs
+            .gather(1, targets.reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .neg();
+
+        match mask {
+            Some(mask) => tensor.mask_fill(mask, 0.0),
+            None => tensor,
+        }
+    }
+}
+
+impl<B: Backend> NLLLoss<B> {
+    fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
+        self.pad_index
+            .map(|pad_index| targets.clone().equal_elem(pad_index as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::{CrossEntropyLoss, Reduction};
+    use crate::TestBackend;
+    use burn_tensor::{activation, Data, Distribution};
+
+    #[test]
+    fn test_nll_loss_is_cross_entropy_of_log_probs() {
+        let [batch_size, num_targets] = [4, 5];
+        let logits = Tensor::<TestBackend, 2>::random(
+            [batch_size, num_targets],
+            Distribution::Normal(0., 1.0),
+        );
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]));
+
+        let loss_1 = NLLLoss::new(None).forward(
+            activation::log_softmax(logits.clone(), 1),
+            targets.clone(),
+            Reduction::Mean,
+        );
+        let loss_2 = CrossEntropyLoss::new(None).forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_nll_loss_with_pad_token_weights_and_mask() {
+        let log_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [-0.5, -1.0, -2.0],
+            [-1.5, -0.2, -3.0],
+            [-0.7, -0.9, -1.1],
+            [-2.0, -0.1, -4.0],
+        ]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 2, 1, 1]));
+        let weights = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 1.0, 1.0, 1.0]));
+        let mask =
+            Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([false, false, true, false]));
+
+        let nll = NLLLoss::new(Some(2));
+        let loss_no_reduction = nll.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss = nll.forward_weighted(
+            log_probs.clone(),
+            targets.clone(),
+            Some(weights),
+            Some(mask),
+            Reduction::Mean,
+        );
+        let loss_sum = nll.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 0.0, 0.9, 0.1]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.366667]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([1.5]), 3);
+    }
+}
@@ -1,4 +1,9 @@
+use burn_tensor::{backend::Backend, Bool, Tensor};
+
 /// The reduction type for the loss.
+///
+/// With weights, the mean is normalized by the sum of the weights instead of the number of
+/// elements. The masked elements are ignored, and the mean is zero when all of them are masked.
 pub enum Reduction {
     /// The mean of the losses will be returned.
     Mean,
@@ -9,3 +14,105 @@ pub enum Reduction {
     /// The mean of the losses will be returned.
     Auto,
 }
+
+/// Reduce the element-wise losses, multiplying them by their weights and ignoring the masked
+/// ones.
+///
+/// The mean is the weighted mean of the elements that aren't masked, `sum(w * l) / sum(w)`, and
+/// it is zero when every element is masked.
+pub(crate) fn reduce<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    weights: Option<Tensor<B, D>>,
+    mask: Option<Tensor<B, D, Bool>>,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    if weights.is_none() && mask.is_none() {
+        return match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        };
+    }
+
+    let (tensor, total_weight) = match weights {
+        Some(weights) => (tensor.mul(weights.clone()), weights),
+        None => (tensor.clone(), tensor.ones_like()),
+    };
+
+    let (tensor, total_weight) = match mask {
+        Some(mask) => (
+            tensor.mask_fill(mask.clone(), 0.0),
+            total_weight.mask_fill(mask, 0.0),
+        ),
+        None => (tensor, total_weight),
+    };
+
+    match reduction {
+        Reduction::Mean | Reduction::Auto => {
+            let total_weight = total_weight.sum();
+            // Avoid a division by zero when there is nothing to average.
+            let empty = total_weight.clone().equal_elem(0.0);
+
+            tensor.sum().div(total_weight.mask_fill(empty, 1.0))
+        }
+        Reduction::Sum => tensor.sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::L1Loss;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_reduce_weighted_mean() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 4.0]));
+        let weights = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 3.0, 0.0]));
+
+        let loss = reduce(tensor, Some(weights), None, Reduction::Mean);
+
+        loss.into_data().assert_approx_eq(&Data::from([1.75]), 3);
+    }
+
+    #[test]
+    fn test_reduce_all_masked_should_be_zero() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 4.0]));
+        let mask = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, true, true]));
+
+        let loss = reduce(tensor.clone(), None, Some(mask.clone()), Reduction::Mean);
+        let loss_weighted = reduce(
+            tensor.clone(),
+            Some(tensor.ones_like()),
+            Some(mask),
+            Reduction::Auto,
+        );
+
+        assert_eq!(loss.into_data(), Data::from([0.0]));
+        assert_eq!(loss_weighted.into_data(), Data::from([0.0]));
+    }
+
+    #[test]
+    fn test_forward_weighted_ignores_masked_elements() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [3.0, 2.0]]));
+        let weights = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [1.0, 0.5]]));
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, true], [false, false]]));
+
+        let l1 = L1Loss::new();
+        let loss = l1.forward_weighted(
+            logits.clone(),
+            targets.clone(),
+            Some(weights.clone()),
+            Some(mask.clone()),
+            Reduction::Mean,
+        );
+        let loss_sum =
+            l1.forward_weighted(logits, targets, Some(weights), Some(mask), Reduction::Sum);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.857142]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([3.0]), 3);
+    }
+}
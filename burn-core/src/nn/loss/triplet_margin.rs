@@ -0,0 +1,125 @@
+use crate::nn::loss::reduction::{reduce, Reduction};
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, Bool, Tensor};
+
+/// Calculate the triplet margin loss, pushing the anchors closer to the positive examples than to
+/// the negative ones by at least a margin.
+#[derive(Clone, Debug)]
+pub struct TripletMarginLoss<B: Backend> {
+    margin: f32,
+    p: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for TripletMarginLoss<B> {
+    fn default() -> Self {
+        Self::new(1.0, 2.0)
+    }
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    /// Create the criterion, where the distances are computed with the `p`-norm.
+    pub fn new(margin: f32, p: f32) -> Self {
+        Self {
+            margin,
+            p,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: [batch_size, d_model]
+    /// - positive: [batch_size, d_model]
+    /// - negative: [batch_size, d_model]
+    pub fn forward(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        self.forward_weighted(anchor, positive, negative, None, None, reduction)
+    }
+
+    /// Compute the criterion on the input tensors, multiplying the loss of each element by its
+    /// weight and ignoring the elements where the mask is true.
+    ///
+    /// The weights and the mask have the shape of the [unreduced](Self::forward_no_reduction)
+    /// loss.
+    pub fn forward_weighted(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        weights: Option<Tensor<B, 1>>,
+        mask: Option<Tensor<B, 1, Bool>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(anchor, positive, negative);
+
+        reduce(tensor, weights, mask, reduction)
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = anchor.dims();
+
+        let distance_positive = self.distance(anchor.clone(), positive);
+        let distance_negative = self.distance(anchor, negative);
+
+        let tensor = distance_positive
+            .sub(distance_negative)
+            .add_scalar(self.margin);
+
+        activation::relu(tensor).reshape([batch_size])
+    }
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    fn distance(&self, x1: Tensor<B, 2>, x2: Tensor<B, 2>) -> Tensor<B, 2> {
+        // The epsilon keeps the gradient of the norm finite when both inputs are equal.
+        x1.sub(x2)
+            .add_scalar(EPSILON)
+            .abs()
+            .powf(self.p)
+            .sum_dim(1)
+            .powf(1.0 / self.p)
+    }
+}
+
+const EPSILON: f32 = 1e-6;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let anchor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 0.0], [1.0, 1.0]]));
+        let positive = Tensor::<TestBackend, 2>::from_data(Data::from([[3.0, 4.0], [1.0, 2.0]]));
+        let negative = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [4.0, 5.0]]));
+
+        let loss = TripletMarginLoss::new(1.0, 2.0);
+        let loss_l2 = loss.forward_no_reduction(anchor.clone(), positive.clone(), negative.clone());
+        let loss_l1 =
+            TripletMarginLoss::new(1.0, 1.0).forward_no_reduction(anchor, positive, negative);
+
+        loss_l2
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 0.0]), 3);
+        loss_l1
+            .into_data()
+            .assert_approx_eq(&Data::from([7.0, 0.0]), 3);
+    }
+}
@@ -22,8 +22,11 @@ mod gelu;
 mod initializer;
 mod linear;
 mod norm;
+mod pos_encoding;
 mod relu;
 mod rnn;
+mod rope_encoding;
+mod upsample;
 
 pub use dropout::*;
 pub use embedding::*;
@@ -31,5 +34,8 @@ pub use gelu::*;
 pub use initializer::*;
 pub use linear::*;
 pub use norm::*;
+pub use pos_encoding::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rope_encoding::*;
+pub use upsample::*;
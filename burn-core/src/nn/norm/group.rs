@@ -0,0 +1,213 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [GroupNorm](GroupNorm) layer.
+#[derive(Config)]
+pub struct GroupNormConfig {
+    /// The number of groups to separate the channels into.
+    pub num_groups: usize,
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// If a learnable per-channel scale and shift should be applied. Default: true
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies Group Normalization over an input tensor as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// `Y = groupnorm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct GroupNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    num_groups: usize,
+    num_channels: usize,
+    epsilon: f64,
+}
+
+impl GroupNormConfig {
+    /// Initialize a new [group norm](GroupNorm) module.
+    pub fn init<B: Backend>(&self) -> GroupNorm<B> {
+        assert_eq!(
+            self.num_channels % self.num_groups,
+            0,
+            "The number of channels must be divisible by the number of groups"
+        );
+
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        GroupNorm {
+            gamma,
+            beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [group norm](GroupNorm) module with a [record](GroupNormRecord).
+    pub fn init_with<B: Backend>(&self, record: GroupNormRecord<B>) -> GroupNorm<B> {
+        GroupNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> GroupNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_channels, *]`
+    /// - output: `[batch_size, num_channels, *]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        group_norm(
+            input,
+            self.num_groups,
+            self.num_channels,
+            self.gamma.as_ref().map(|gamma| gamma.val()),
+            self.beta.as_ref().map(|beta| beta.val()),
+            self.epsilon,
+        )
+    }
+}
+
+/// Normalize each group of channels using its own mean and biased variance, then apply the
+/// optional per-channel affine transformation.
+pub(crate) fn group_norm<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    num_groups: usize,
+    num_channels: usize,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    let shape = input.shape();
+    let batch_size = shape.dims[0];
+    let num_elements = shape.num_elements();
+
+    let input = input.reshape([
+        batch_size,
+        num_groups,
+        num_elements / (batch_size * num_groups),
+    ]);
+    let (var, mean) = input.clone().var_mean_bias(2);
+    let output = input
+        .sub(mean)
+        .div(var.add_scalar(epsilon).sqrt())
+        .reshape(shape);
+
+    let mut affine_shape = [1; D];
+    affine_shape[1] = num_channels;
+
+    let output = match gamma {
+        Some(gamma) => output.mul(gamma.reshape(affine_shape)),
+        None => output,
+    };
+
+    match beta {
+        Some(beta) => output.add(beta.reshape(affine_shape)),
+        None => output,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn group_norm_forward() {
+        let module = GroupNormConfig::new(3, 6).init::<TestBackend>();
+        let input = Tensor::from_data(Data::from([
+            [
+                [-1.0481, 0.1769],
+                [-0.5202, 0.4157],
+                [0.5029, -1.7379],
+                [-1.9473, 1.3499],
+                [-0.9626, -1.0627],
+                [1.9826, -0.1189],
+            ],
+            [
+                [1.3458, -0.0946],
+                [0.5563, -1.3975],
+                [0.5394, 1.4722],
+                [0.0927, 0.9650],
+                [0.6856, -1.7439],
+                [1.0329, 0.3644],
+            ],
+        ]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-1.3919, 0.7284],
+                    [-0.4782, 1.1417],
+                    [0.6775, -0.9023],
+                    [-1.0499, 1.2746],
+                    [-0.7533, -0.8351],
+                    [1.6526, -0.0641],
+                ],
+                [
+                    [1.2370, -0.1961],
+                    [0.4515, -1.4924],
+                    [-0.4463, 1.3804],
+                    [-1.3211, 0.3871],
+                    [0.5554, -1.6902],
+                    [0.8764, 0.2585],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn group_norm_forward_affine() {
+        let module = GroupNorm::<TestBackend> {
+            gamma: Some(Param::from(Tensor::from_data(Data::from([2.0, 0.5])))),
+            beta: Some(Param::from(Tensor::from_data(Data::from([1.0, -1.0])))),
+            num_groups: 1,
+            num_channels: 2,
+            epsilon: 1e-5,
+        };
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[1.0, 3.0], [5.0, 7.0]]]));
+
+        let output = module.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[-1.6833, 0.1056], [-0.7764, -0.3292]]]), 3);
+    }
+
+    #[test]
+    fn group_norm_without_affine_has_no_params() {
+        let module = GroupNormConfig::new(2, 4)
+            .with_affine(false)
+            .init::<TestBackend>();
+
+        assert!(module.gamma.is_none());
+        assert!(module.beta.is_none());
+        assert_eq!(module.num_params(), 0);
+    }
+}
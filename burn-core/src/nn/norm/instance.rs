@@ -0,0 +1,118 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+use super::group::group_norm;
+
+/// Configuration to create an [InstanceNorm](InstanceNorm) layer.
+#[derive(Config)]
+pub struct InstanceNormConfig {
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// If a learnable per-channel scale and shift should be applied. Default: false
+    #[config(default = false)]
+    pub affine: bool,
+}
+
+/// Applies Instance Normalization over an input tensor as described in the paper [Instance Normalization](https://arxiv.org/abs/1607.08022).
+///
+/// Each channel of each sample is normalized independently, which is equivalent to a
+/// [group norm](super::GroupNorm) with one group per channel.
+#[derive(Module, Debug)]
+pub struct InstanceNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    num_channels: usize,
+    epsilon: f64,
+}
+
+impl InstanceNormConfig {
+    /// Initialize a new [instance norm](InstanceNorm) module.
+    pub fn init<B: Backend>(&self) -> InstanceNorm<B> {
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        InstanceNorm {
+            gamma,
+            beta,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [instance norm](InstanceNorm) module with a [record](InstanceNormRecord).
+    pub fn init_with<B: Backend>(&self, record: InstanceNormRecord<B>) -> InstanceNorm<B> {
+        InstanceNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> InstanceNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_channels, *]`
+    /// - output: `[batch_size, num_channels, *]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        group_norm(
+            input,
+            self.num_channels,
+            self.num_channels,
+            self.gamma.as_ref().map(|gamma| gamma.val()),
+            self.beta.as_ref().map(|beta| beta.val()),
+            self.epsilon,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn instance_norm_forward() {
+        let module = InstanceNormConfig::new(2).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[
+            [0.4916, 0.9671, 1.1808, 1.7698],
+            [0.9596, 1.6893, -1.8840, -0.1375],
+        ]]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [-1.3303, -0.2946, 0.1709, 1.4540],
+                [0.5965, 1.1387, -1.5165, -0.2187],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn instance_norm_affine_params() {
+        let module = InstanceNormConfig::new(3)
+            .with_affine(true)
+            .init::<TestBackend>();
+
+        assert_eq!(module.num_params(), 6);
+    }
+}
@@ -0,0 +1,114 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [RmsNorm](RmsNorm) layer.
+#[derive(Config)]
+pub struct RmsNormConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// If a learnable scale should be applied. Default: true
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies RMS Normalization over an input tensor as described in the paper [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// `Y = X / sqrt(mean(X^2) + eps) * γ`
+#[derive(Module, Debug)]
+pub struct RmsNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    epsilon: f64,
+}
+
+impl RmsNormConfig {
+    /// Initialize a new [rms norm](RmsNorm) module.
+    pub fn init<B: Backend>(&self) -> RmsNorm<B> {
+        let gamma = match self.affine {
+            true => Some(Param::from(Tensor::ones([self.d_model]))),
+            false => None,
+        };
+
+        RmsNorm {
+            gamma,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [rms norm](RmsNorm) module with a [record](RmsNormRecord).
+    pub fn init_with<B: Backend>(&self, record: RmsNormRecord<B>) -> RmsNorm<B> {
+        RmsNorm {
+            gamma: record.gamma,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> RmsNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        // The mean square is the biased variance plus the squared mean.
+        let (var, mean) = input.clone().var_mean_bias(D - 1);
+        let rms = var.add(mean.powf(2.0)).add_scalar(self.epsilon).sqrt();
+
+        let output = input.div(rms);
+
+        match &self.gamma {
+            Some(gamma) => output.mul(gamma.val().unsqueeze()),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn rms_norm_forward() {
+        let module = RmsNormConfig::new(4).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [-0.7949, -1.8760, 1.4621, -0.1090],
+            [0.8753, 1.5153, 0.8565, 1.6844],
+        ]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [-0.6333, -1.4947, 1.1649, -0.0868],
+                [0.6797, 1.1767, 0.6651, 1.3080],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn rms_norm_forward_scaled() {
+        let module = RmsNorm::<TestBackend> {
+            gamma: Some(Param::from(Tensor::from_data(Data::from([2.0, -1.0])))),
+            epsilon: 1e-5,
+        };
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[3.0, 4.0]]]));
+
+        let output = module.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[1.6971, -1.1314]]]), 3);
+    }
+}
@@ -0,0 +1,64 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::Conv3dPaddingConfig;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::avg_pool3d;
+
+/// Configuration to create a [3D avg pooling](AvgPool3d) layer.
+#[derive(Config)]
+pub struct AvgPool3dConfig {
+    /// The number of channels.
+    pub channels: usize,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The strides.
+    #[config(default = "[1, 1, 1]")]
+    pub strides: [usize; 3],
+    /// The padding configuration.
+    #[config(default = "AvgPool3dPaddingConfig::Valid")]
+    pub padding: AvgPool3dPaddingConfig,
+}
+
+/// Padding configuration for 3D avg pooling [config](AvgPool3dConfig).
+pub type AvgPool3dPaddingConfig = Conv3dPaddingConfig;
+
+/// Applies a 3D avg pooling over input tensors.
+#[derive(Module, Debug, Clone)]
+pub struct AvgPool3d {
+    stride: [usize; 3],
+    kernel_size: [usize; 3],
+    padding: AvgPool3dPaddingConfig,
+}
+
+impl AvgPool3dConfig {
+    /// Initialize a new [avg pool 3d](AvgPool3d) module.
+    pub fn init(&self) -> AvgPool3d {
+        AvgPool3d {
+            stride: self.strides,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+        }
+    }
+}
+
+impl AvgPool3d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels, depth_out, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            [depth_in, height_in, width_in],
+            &self.kernel_size,
+            &self.stride,
+        );
+
+        avg_pool3d(input, self.kernel_size, self.stride, padding)
+    }
+}
@@ -0,0 +1,244 @@
+use alloc::vec;
+use core::ops::Range;
+
+use crate as burn;
+
+use super::{Embedding, EmbeddingConfig};
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Tensor};
+use libm::pow;
+
+/// Configuration to create a [PositionalEncoding](PositionalEncoding) layer.
+#[derive(Config)]
+pub struct PositionalEncodingConfig {
+    /// The size of each vector.
+    pub d_model: usize,
+    /// Maximum sequence size to use. Default: 5000
+    #[config(default = 5000)]
+    pub max_sequence_size: usize,
+    /// The maximum timescale of the sinusoids. Default: 10000
+    #[config(default = 10000)]
+    pub max_timescale: usize,
+}
+
+/// Adds fixed sinusoidal positional encodings to the input, as described in the paper
+/// [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
+///
+/// The sinusoids are computed on the device of the input for the positions of each sequence and
+/// are not trained.
+///
+/// `PE(pos, 2i) = sin(pos / max_timescale^(2i / d_model))`
+/// `PE(pos, 2i + 1) = cos(pos / max_timescale^(2i / d_model))`
+#[derive(Module, Clone, Debug)]
+pub struct PositionalEncoding {
+    d_model: usize,
+    max_sequence_size: usize,
+    max_timescale: usize,
+}
+
+impl PositionalEncodingConfig {
+    /// Initialize a new [positional encoding](PositionalEncoding) module.
+    pub fn init(&self) -> PositionalEncoding {
+        assert_eq!(
+            self.d_model % 2,
+            0,
+            "d_model must be even to generate sinusoids"
+        );
+
+        PositionalEncoding {
+            d_model: self.d_model,
+            max_sequence_size: self.max_sequence_size,
+            max_timescale: self.max_timescale,
+        }
+    }
+}
+
+impl PositionalEncoding {
+    /// Applies the forward pass on the input tensor by adding the sinusoids to it.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_batch_size, seq_length, d_model] = input.dims();
+
+        assert!(
+            seq_length <= self.max_sequence_size,
+            "Sequence length {seq_length} exceeds the maximum sequence size {}",
+            self.max_sequence_size
+        );
+        assert_eq!(
+            d_model, self.d_model,
+            "The input d_model must match the positional encoding d_model"
+        );
+
+        let sinusoids = sinusoids::<B>(0..seq_length, d_model, self.max_timescale, &input.device());
+
+        input + sinusoids.unsqueeze()
+    }
+}
+
+/// Generates the sinusoids used by the [positional encoding](PositionalEncoding).
+///
+/// # Shapes
+///
+/// - output: `[length, d_model]`
+pub fn generate_sinusoids<B: Backend>(
+    length: usize,
+    d_model: usize,
+    max_timescale: usize,
+) -> Tensor<B, 2> {
+    assert_eq!(d_model % 2, 0, "d_model must be even to generate sinusoids");
+
+    sinusoids(0..length, d_model, max_timescale, &B::Device::default())
+}
+
+/// Computes the sinusoids of the given positions on the device, only the positions and the
+/// frequencies are created on the host.
+fn sinusoids<B: Backend>(
+    positions: Range<usize>,
+    d_model: usize,
+    max_timescale: usize,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    let length = positions.len();
+    let half = d_model / 2;
+
+    let frequencies = (0..half)
+        .map(|i| pow(max_timescale as f64, -2.0 * i as f64 / d_model as f64) as f32)
+        .collect();
+    let frequencies = Tensor::<B, 2>::from_data(Data::new(frequencies, [1, half].into()).convert())
+        .to_device(device);
+    let angles = positions_tensor::<B>(positions, device).mul(frequencies);
+
+    // The sine and cosine of each frequency are interleaved.
+    let sin = angles.clone().sin().reshape([length, half, 1]);
+    let cos = angles.cos().reshape([length, half, 1]);
+
+    Tensor::cat(vec![sin, cos], 2).reshape([length, d_model])
+}
+
+/// Creates a column tensor with the given positions.
+///
+/// # Shapes
+///
+/// - output: `[length, 1]`
+pub(crate) fn positions_tensor<B: Backend>(
+    positions: Range<usize>,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    let length = positions.len();
+    let positions = positions.map(|position| position as f32).collect();
+
+    Tensor::from_data(Data::new(positions, [length, 1].into()).convert()).to_device(device)
+}
+
+/// Configuration to create a [LearnedPositionalEncoding](LearnedPositionalEncoding) layer.
+#[derive(Config)]
+pub struct LearnedPositionalEncodingConfig {
+    /// The size of each vector.
+    pub d_model: usize,
+    /// Maximum sequence size to use.
+    pub max_sequence_size: usize,
+}
+
+/// Adds trainable positional embeddings to the input.
+///
+/// # Params
+///
+/// - embedding: [Embedding](Embedding) with one vector of size `d_model` for each position.
+#[derive(Module, Debug)]
+pub struct LearnedPositionalEncoding<B: Backend> {
+    embedding: Embedding<B>,
+}
+
+impl LearnedPositionalEncodingConfig {
+    /// Initialize a new [learned positional encoding](LearnedPositionalEncoding) module.
+    pub fn init<B: Backend>(&self) -> LearnedPositionalEncoding<B> {
+        LearnedPositionalEncoding {
+            embedding: EmbeddingConfig::new(self.max_sequence_size, self.d_model).init(),
+        }
+    }
+
+    /// Initialize a new [learned positional encoding](LearnedPositionalEncoding) module with a
+    /// [record](LearnedPositionalEncodingRecord).
+    pub fn init_with<B: Backend>(
+        &self,
+        record: LearnedPositionalEncodingRecord<B>,
+    ) -> LearnedPositionalEncoding<B> {
+        LearnedPositionalEncoding {
+            embedding: EmbeddingConfig::new(self.max_sequence_size, self.d_model)
+                .init_with(record.embedding),
+        }
+    }
+}
+
+impl<B: Backend> LearnedPositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the positional embeddings to it.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, seq_length, _d_model] = input.dims();
+
+        let positions = Tensor::arange_device(0..seq_length, &input.device())
+            .reshape([1, seq_length])
+            .repeat(0, batch_size);
+
+        input + self.embedding.forward(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_generate_sinusoids() {
+        let sinusoids = generate_sinusoids::<TestBackend>(3, 4, 10000);
+
+        sinusoids.into_data().assert_approx_eq(
+            &Data::from([
+                [0.0000, 1.0000, 0.0000, 1.0000],
+                [0.8415, 0.5403, 0.0100, 0.9999],
+                [0.9093, -0.4161, 0.0200, 0.9998],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_positional_encoding_forward() {
+        let pe = PositionalEncodingConfig::new(4)
+            .with_max_sequence_size(10)
+            .init();
+        let input = Tensor::<TestBackend, 3>::ones([2, 3, 4]);
+
+        let output = pe.forward(input);
+
+        let expected = [
+            [1.0000, 2.0000, 1.0000, 2.0000],
+            [1.8415, 1.5403, 1.0100, 1.9999],
+            [1.9093, 0.5839, 1.0200, 1.9998],
+        ];
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([expected, expected]), 3);
+    }
+
+    #[test]
+    fn test_learned_positional_encoding_shapes() {
+        let pe = LearnedPositionalEncodingConfig::new(8, 16).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::zeros([2, 5, 8]);
+
+        let output = pe.forward(input);
+
+        assert_eq!(output.dims(), [2, 5, 8]);
+    }
+}
@@ -1,15 +1,21 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::Module;
+use crate::module::{ConstantRecord, Module};
 use crate::nn::rnn::gate_controller;
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
+use crate::nn::{Dropout, DropoutConfig};
+use crate::record::Record;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::activation;
 
-use super::gate_controller::GateController;
+use super::gate_controller::{GateController, GateControllerRecord};
+use super::state::state_at;
 
 /// The configuration for a [gru](Gru) module.
 #[derive(Config)]
@@ -23,63 +29,168 @@ pub struct GruConfig {
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
-    /// The batch size.
-    pub batch_size: usize,
+    /// The number of stacked layers.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If the sequence should also be processed in reverse order, concatenating the hidden
+    /// states of both directions.
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout rate applied to the output of each layer, except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
 }
 
-/// The Gru module. This implementation is for a unidirectional, stateless, Gru.
+/// The Gru module. This implementation is for a stateless Gru, with one or more stacked layers,
+/// which can be bidirectional.
+///
+/// Records saved before stacked layers were supported can be loaded as a
+/// [GruRecordV1](GruRecordV1) and converted into a [GruRecord](GruRecord).
 #[derive(Module, Debug)]
 pub struct Gru<B: Backend> {
+    /// The cells of each layer, the forward and reverse directions of a layer being next to
+    /// each other.
+    cells: Vec<GruCell<B>>,
+    dropout: Dropout,
+    num_layers: usize,
+    bidirectional: bool,
+    d_hidden: usize,
+}
+
+/// A single layer of a [gru](Gru) module, processing the sequence in one direction.
+#[derive(Module, Debug)]
+pub struct GruCell<B: Backend> {
     update_gate: GateController<B>,
     reset_gate: GateController<B>,
     new_gate: GateController<B>,
-    batch_size: usize,
     d_hidden: usize,
 }
 
+/// The record of a [gru](Gru) module saved before stacked layers were supported, when the module
+/// had a single unidirectional layer.
+#[derive(Record, Debug, Clone)]
+pub struct GruRecordV1<B: Backend> {
+    /// The update gate.
+    pub update_gate: GateControllerRecord<B>,
+    /// The reset gate.
+    pub reset_gate: GateControllerRecord<B>,
+    /// The new gate.
+    pub new_gate: GateControllerRecord<B>,
+    /// The batch size, which isn't part of the module anymore.
+    pub batch_size: ConstantRecord,
+    /// The size of the hidden state.
+    pub d_hidden: ConstantRecord,
+}
+
+impl<B: Backend> From<GruRecordV1<B>> for GruRecord<B> {
+    fn from(record: GruRecordV1<B>) -> Self {
+        let cell = GruCellRecord {
+            update_gate: record.update_gate,
+            reset_gate: record.reset_gate,
+            new_gate: record.new_gate,
+            d_hidden: record.d_hidden,
+        };
+
+        Self {
+            cells: vec![cell],
+            dropout: ConstantRecord::new(),
+            num_layers: ConstantRecord::new(),
+            bidirectional: ConstantRecord::new(),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
 impl GruConfig {
     /// Initialize a new [gru](Gru) module.
     pub fn init<B: Backend>(&self) -> Gru<B> {
-        let d_output = self.d_hidden;
+        let cells = (0..self.num_cells())
+            .map(|index| GruCell::new(self, self.d_input_cell(index)))
+            .collect();
 
-        let update_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let reset_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let new_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
+        self.init_gru(cells)
+    }
+
+    /// Initialize a new [gru](Gru) module with a [record](GruRecord).
+    pub fn init_with<B: Backend>(&self, record: GruRecord<B>) -> Gru<B> {
+        let num_cells = self.num_cells();
+        assert_eq!(
+            record.cells.len(),
+            num_cells,
+            "The record has {} cells, but the configuration expects {num_cells} \
+             ({} layers, {} directions).",
+            record.cells.len(),
+            self.num_layers,
+            self.num_directions(),
         );
 
+        let cells = record
+            .cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| GruCell::new_with(self, self.d_input_cell(index), record))
+            .collect();
+
+        self.init_gru(cells)
+    }
+
+    fn init_gru<B: Backend>(&self, cells: Vec<GruCell<B>>) -> Gru<B> {
         Gru {
-            update_gate,
-            reset_gate,
-            new_gate,
-            batch_size: self.batch_size,
+            cells,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            num_layers: self.num_layers,
+            bidirectional: self.bidirectional,
             d_hidden: self.d_hidden,
         }
     }
 
-    /// Initialize a new [gru](Gru) module.
-    pub fn init_with<B: Backend>(self, record: GruRecord<B>) -> Gru<B> {
+    fn num_cells(&self) -> usize {
+        self.num_layers * self.num_directions()
+    }
+
+    fn num_directions(&self) -> usize {
+        match self.bidirectional {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    fn d_input_cell(&self, index: usize) -> usize {
+        match index / self.num_directions() {
+            0 => self.d_input,
+            _ => self.d_hidden * self.num_directions(),
+        }
+    }
+}
+
+impl<B: Backend> GruCell<B> {
+    fn new(config: &GruConfig, d_input: usize) -> Self {
+        let gate = || {
+            gate_controller::GateController::new(
+                d_input,
+                config.d_hidden,
+                config.bias,
+                config.initializer.clone(),
+            )
+        };
+
+        Self {
+            update_gate: gate(),
+            reset_gate: gate(),
+            new_gate: gate(),
+            d_hidden: config.d_hidden,
+        }
+    }
+
+    fn new_with(config: &GruConfig, d_input: usize, record: GruCellRecord<B>) -> Self {
         let linear_config = LinearConfig {
-            d_input: self.d_input,
-            d_output: self.d_hidden,
-            bias: self.bias,
-            initializer: self.initializer.clone(),
+            d_input,
+            d_output: config.d_hidden,
+            bias: config.bias,
+            initializer: config.initializer.clone(),
         };
 
-        Gru {
+        Self {
             update_gate: gate_controller::GateController::new_with(
                 &linear_config,
                 record.update_gate,
@@ -89,120 +200,143 @@ impl GruConfig {
                 record.reset_gate,
             ),
             new_gate: gate_controller::GateController::new_with(&linear_config, record.new_gate),
-            batch_size: self.batch_size,
-            d_hidden: self.d_hidden,
+            d_hidden: config.d_hidden,
         }
     }
-}
 
-impl<B: Backend> Gru<B> {
-    /// Applies the forward pass on the input tensor. This GRU implementation
-    /// returns a single state tensor with dimensions [batch_size, sequence_length, hidden_size].
+    /// Applies the cell over the whole sequence, in reverse order if `reverse` is true.
     ///
-    /// Parameters:
-    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
-    ///
-    /// Returns:
-    ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
-    pub fn forward(
-        &mut self,
+    /// Returns the hidden state for each element of the sequence, with shape
+    /// `[batch_size, sequence_length, hidden_size]`, and the final hidden state, with shape
+    /// `[batch_size, hidden_size]`.
+    fn forward(
+        &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
-    ) -> Tensor<B, 3> {
-        let seq_length = batched_input.shape().dims[1];
-
-        let mut hidden_state = match state {
-            Some(state) => state,
-            None => Tensor::zeros([self.batch_size, seq_length, self.d_hidden]),
-        };
+        state: Tensor<B, 2>,
+        reverse: bool,
+    ) -> (Tensor<B, 3>, Tensor<B, 2>) {
+        let [batch_size, seq_length, d_input] = batched_input.dims();
+        let mut hidden_t = state;
+        let mut hidden_states = Vec::with_capacity(seq_length);
 
-        for t in 0..seq_length {
-            let indices = Tensor::arange(t..t + 1);
+        for i in 0..seq_length {
+            let t = match reverse {
+                true => seq_length - i - 1,
+                false => i,
+            };
             let input_t = batched_input
                 .clone()
-                .index_select(1, indices.clone())
-                .squeeze(1);
-            let hidden_t = hidden_state
-                .clone()
-                .index_select(1, indices.clone())
-                .squeeze(1);
+                .index([0..batch_size, t..(t + 1), 0..d_input])
+                .reshape([batch_size, d_input]);
 
             // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
+            let biased_ug_input_sum = self
+                .update_gate
+                .gate_product(input_t.clone(), hidden_t.clone());
             let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
 
             // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
+            let biased_rg_input_sum = self
+                .reset_gate
+                .gate_product(input_t.clone(), hidden_t.clone());
             let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
             let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
 
             // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
+            let biased_ng_input_sum = self.new_gate.gate_product(input_t, reset_t);
             let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
 
             // calculate linear interpolation between previous hidden state and candidate state:
             // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
-                .clone()
+            hidden_t = candidate_state
                 .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
+                + update_values.mul(hidden_t);
+
+            hidden_states.push(hidden_t.clone().reshape([batch_size, 1, self.d_hidden]));
+        }
 
-            hidden_state = hidden_state.index_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                state_vector.clone().unsqueeze(),
-            );
+        if reverse {
+            hidden_states.reverse();
         }
 
-        hidden_state
+        (Tensor::cat(hidden_states, 1), hidden_t)
     }
+}
 
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
+impl<B: Backend> Gru<B> {
+    /// Applies the forward pass on the input tensor. This GRU implementation returns the hidden
+    /// state of the last layer for each element in a sequence (i.e., across `seq_length`), as well
+    /// as the final hidden state of every layer.
+    ///
+    /// The batch size is inferred from the input.
     ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
+    /// Parameters:
+    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
+    ///     state: An optional tensor representing the initial hidden state of every layer, with
+    ///            shape [num_layers * num_directions, batch_size, hidden_size].
+    ///            If none is provided, it is initialized to zeros.
+    ///
+    /// Returns:
+    ///     A tuple of tensors, where the first tensor represents the hidden states of the last
+    ///     layer for each sequence element, with shape
+    ///     [batch_size, sequence_length, num_directions * hidden_size], and the second tensor
+    ///     represents the final hidden state of every layer, with shape
+    ///     [num_layers * num_directions, batch_size, hidden_size].
+    ///
+    /// Note: before stacked layers were supported, this method only returned the hidden states
+    /// of every sequence element. The final hidden state of every layer is now returned as well.
+    pub fn forward(
         &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
+        batched_input: Tensor<B, 3>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        let [batch_size, _, _] = batched_input.dims();
+        let num_cells = self.cells.len();
+        let num_directions = num_cells / self.num_layers;
+
+        let state = match state {
+            Some(state) => state,
+            None => Tensor::zeros_device(
+                [num_cells, batch_size, self.d_hidden],
+                &batched_input.device(),
+            ),
+        };
+
+        let mut output = batched_input;
+        let mut hidden_states = Vec::with_capacity(num_cells);
+
+        for layer in 0..self.num_layers {
+            if layer > 0 {
+                output = self.dropout.forward(output);
+            }
+
+            let mut outputs = Vec::with_capacity(num_directions);
+
+            for direction in 0..num_directions {
+                let index = layer * num_directions + direction;
+                let state_cell = state_at(&state, index, batch_size, self.d_hidden);
+                let (output_cell, hidden_state) =
+                    self.cells[index].forward(output.clone(), state_cell, direction == 1);
+
+                outputs.push(output_cell);
+                hidden_states.push(hidden_state.unsqueeze());
             }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
+
+            output = Tensor::cat(outputs, 2);
         }
+
+        (output, Tensor::cat(hidden_states, 0))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{module::Param, nn::LinearRecord, TestBackend};
-    use burn_tensor::Data;
+    use crate::module::Param;
+    use crate::nn::LinearRecord;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
 
     /// Test forward pass with simple input vector.
     ///
@@ -214,7 +348,7 @@ mod tests {
     #[test]
     fn tests_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = GruConfig::new(1, 1, false, 1);
+        let config = GruConfig::new(1, 1, false);
         let mut gru = config.init::<TestBackend>();
 
         fn create_gate_controller(
@@ -239,7 +373,7 @@ mod tests {
             )
         }
 
-        gru.update_gate = create_gate_controller(
+        gru.cells[0].update_gate = create_gate_controller(
             0.5,
             0.0,
             1,
@@ -247,7 +381,7 @@ mod tests {
             false,
             Initializer::XavierNormal { gain: 1.0 },
         );
-        gru.reset_gate = create_gate_controller(
+        gru.cells[0].reset_gate = create_gate_controller(
             0.6,
             0.0,
             1,
@@ -255,7 +389,7 @@ mod tests {
             false,
             Initializer::XavierNormal { gain: 1.0 },
         );
-        gru.new_gate = create_gate_controller(
+        gru.cells[0].new_gate = create_gate_controller(
             0.7,
             0.0,
             1,
@@ -266,10 +400,80 @@ mod tests {
 
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]));
 
-        let state = gru.forward(input, None);
+        let (output, state) = gru.forward(input, None);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.034]]]), 3);
+        state
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.034]]]), 3);
+    }
+
+    #[test]
+    fn test_multi_layer_bidirectional() {
+        let gru = GruConfig::new(4, 3, true)
+            .with_num_layers(3)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+
+        for batch_size in [1, 2] {
+            let input = Tensor::random([batch_size, 5, 4], Distribution::Standard);
+
+            let (output, state) = gru.forward(input, None);
+
+            assert_eq!(output.dims(), [batch_size, 5, 6]);
+            assert_eq!(state.dims(), [6, batch_size, 3]);
+            output
+                .index([0..batch_size, 0..1, 3..6])
+                .reshape([batch_size, 3])
+                .to_data()
+                .assert_approx_eq(
+                    &state
+                        .index([5..6, 0..batch_size, 0..3])
+                        .squeeze(0)
+                        .to_data(),
+                    3,
+                );
+        }
+    }
+
+    #[test]
+    #[should_panic = "The record has 1 cells, but the configuration expects 4"]
+    fn test_init_with_record_of_another_num_layers() {
+        let record = GruConfig::new(4, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        GruConfig::new(4, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init_with::<TestBackend>(record);
+    }
+
+    #[test]
+    fn test_load_record_of_previous_layout() {
+        let config = GruConfig::new(4, 3, true);
+        let gru = config.init::<TestBackend>();
+        let cell = gru.cells[0].clone();
+        let record = GruRecordV1 {
+            update_gate: cell.update_gate.into_record(),
+            reset_gate: cell.reset_gate.into_record(),
+            new_gate: cell.new_gate.into_record(),
+            batch_size: ConstantRecord::new(),
+            d_hidden: ConstantRecord::new(),
+        };
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
 
-        let output = state.index_select(0, Tensor::arange(0..1)).squeeze(0);
+        let bytes = recorder.record(record, ()).unwrap();
+        let record: GruRecordV1<TestBackend> = recorder.load(bytes).unwrap();
+        let loaded = config.init_with::<TestBackend>(record.into());
 
-        output.to_data().assert_approx_eq(&Data::from([[0.034]]), 3);
+        let input = Tensor::random([2, 3, 4], Distribution::Standard);
+        let (output, _) = gru.forward(input.clone(), None);
+        let (output_loaded, _) = loaded.forward(input, None);
+        output
+            .to_data()
+            .assert_approx_eq(&output_loaded.to_data(), 3);
     }
 }
@@ -1,15 +1,21 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::Module;
+use crate::module::{ConstantRecord, Module};
 use crate::nn::rnn::gate_controller;
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
+use crate::nn::{Dropout, DropoutConfig};
+use crate::record::Record;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::activation;
 
-use super::gate_controller::GateController;
+use super::gate_controller::{GateController, GateControllerRecord};
+use super::state::state_at;
 
 /// The configuration for a [lstm](Lstm) module.
 #[derive(Config)]
@@ -23,71 +29,185 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
-    /// The batch size.
-    pub batch_size: usize,
+    /// The number of stacked layers.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If the sequence should also be processed in reverse order, concatenating the hidden
+    /// states of both directions.
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout rate applied to the output of each layer, except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
 }
 
-/// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
+/// The Lstm module. This implementation is for a stateless Lstm, with one or more stacked
+/// layers, which can be bidirectional.
+///
+/// Records saved before stacked layers were supported can be loaded as a
+/// [LstmRecordV1](LstmRecordV1) and converted into a [LstmRecord](LstmRecord).
 #[derive(Module, Debug)]
 pub struct Lstm<B: Backend> {
+    /// The cells of each layer, the forward and reverse directions of a layer being next to
+    /// each other.
+    cells: Vec<LstmCell<B>>,
+    dropout: Dropout,
+    num_layers: usize,
+    bidirectional: bool,
+    d_hidden: usize,
+}
+
+/// A single layer of a [lstm](Lstm) module, processing the sequence in one direction.
+#[derive(Module, Debug)]
+pub struct LstmCell<B: Backend> {
     input_gate: GateController<B>,
     forget_gate: GateController<B>,
     output_gate: GateController<B>,
     cell_gate: GateController<B>,
-    batch_size: usize,
     d_hidden: usize,
 }
 
+/// The record of a [lstm](Lstm) module saved before stacked layers were supported, when the
+/// module had a single unidirectional layer.
+#[derive(Record, Debug, Clone)]
+pub struct LstmRecordV1<B: Backend> {
+    /// The input gate.
+    pub input_gate: GateControllerRecord<B>,
+    /// The forget gate.
+    pub forget_gate: GateControllerRecord<B>,
+    /// The output gate.
+    pub output_gate: GateControllerRecord<B>,
+    /// The cell gate.
+    pub cell_gate: GateControllerRecord<B>,
+    /// The batch size, which isn't part of the module anymore.
+    pub batch_size: ConstantRecord,
+    /// The size of the hidden state.
+    pub d_hidden: ConstantRecord,
+}
+
+impl<B: Backend> From<LstmRecordV1<B>> for LstmRecord<B> {
+    fn from(record: LstmRecordV1<B>) -> Self {
+        let cell = LstmCellRecord {
+            input_gate: record.input_gate,
+            forget_gate: record.forget_gate,
+            output_gate: record.output_gate,
+            cell_gate: record.cell_gate,
+            d_hidden: record.d_hidden,
+        };
+
+        Self {
+            cells: vec![cell],
+            dropout: ConstantRecord::new(),
+            num_layers: ConstantRecord::new(),
+            bidirectional: ConstantRecord::new(),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
+/// The cell state and hidden state of a [lstm](Lstm) module.
+///
+/// Each state tensor has shape `[num_layers * num_directions, batch_size, hidden_size]`, where the
+/// states of the forward and reverse directions of a layer are next to each other.
+#[derive(new, Debug, Clone)]
+pub struct LstmState<B: Backend> {
+    /// The cell state.
+    pub cell: Tensor<B, 3>,
+    /// The hidden state.
+    pub hidden: Tensor<B, 3>,
+}
+
 impl LstmConfig {
     /// Initialize a new [lstm](Lstm) module.
     pub fn init<B: Backend>(&self) -> Lstm<B> {
-        let d_output = self.d_hidden;
+        let cells = (0..self.num_cells())
+            .map(|index| LstmCell::new(self, self.d_input_cell(index)))
+            .collect();
 
-        let input_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let forget_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let output_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let cell_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
+        self.init_lstm(cells)
+    }
+
+    /// Initialize a new [lstm](Lstm) module with a [record](LstmRecord).
+    pub fn init_with<B: Backend>(&self, record: LstmRecord<B>) -> Lstm<B> {
+        let num_cells = self.num_cells();
+        assert_eq!(
+            record.cells.len(),
+            num_cells,
+            "The record has {} cells, but the configuration expects {num_cells} \
+             ({} layers, {} directions).",
+            record.cells.len(),
+            self.num_layers,
+            self.num_directions(),
         );
 
+        let cells = record
+            .cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| LstmCell::new_with(self, self.d_input_cell(index), record))
+            .collect();
+
+        self.init_lstm(cells)
+    }
+
+    fn init_lstm<B: Backend>(&self, cells: Vec<LstmCell<B>>) -> Lstm<B> {
         Lstm {
-            input_gate,
-            forget_gate,
-            output_gate,
-            cell_gate,
-            batch_size: self.batch_size,
+            cells,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            num_layers: self.num_layers,
+            bidirectional: self.bidirectional,
             d_hidden: self.d_hidden,
         }
     }
 
-    /// Initialize a new [lstm](Lstm) module with a [record](LstmRecord).
-    pub fn init_with<B: Backend>(&self, record: LstmRecord<B>) -> Lstm<B> {
+    fn num_cells(&self) -> usize {
+        self.num_layers * self.num_directions()
+    }
+
+    fn num_directions(&self) -> usize {
+        match self.bidirectional {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    fn d_input_cell(&self, index: usize) -> usize {
+        match index / self.num_directions() {
+            0 => self.d_input,
+            _ => self.d_hidden * self.num_directions(),
+        }
+    }
+}
+
+impl<B: Backend> LstmCell<B> {
+    fn new(config: &LstmConfig, d_input: usize) -> Self {
+        let gate = || {
+            gate_controller::GateController::new(
+                d_input,
+                config.d_hidden,
+                config.bias,
+                config.initializer.clone(),
+            )
+        };
+
+        Self {
+            input_gate: gate(),
+            forget_gate: gate(),
+            output_gate: gate(),
+            cell_gate: gate(),
+            d_hidden: config.d_hidden,
+        }
+    }
+
+    fn new_with(config: &LstmConfig, d_input: usize, record: LstmCellRecord<B>) -> Self {
         let linear_config = LinearConfig {
-            d_input: self.d_input,
-            d_output: self.d_hidden,
-            bias: self.bias,
-            initializer: self.initializer.clone(),
+            d_input,
+            d_output: config.d_hidden,
+            bias: config.bias,
+            initializer: config.initializer.clone(),
         };
 
-        Lstm {
+        Self {
             input_gate: gate_controller::GateController::new_with(
                 &linear_config,
                 record.input_gate,
@@ -101,141 +221,173 @@ impl LstmConfig {
                 record.output_gate,
             ),
             cell_gate: gate_controller::GateController::new_with(&linear_config, record.cell_gate),
-            batch_size: self.batch_size,
-            d_hidden: self.d_hidden,
+            d_hidden: config.d_hidden,
         }
     }
-}
 
-impl<B: Backend> Lstm<B> {
-    /// Applies the forward pass on the input tensor. This LSTM implementation
-    /// returns the cell state and hidden state for each element in a sequence (i.e., across `seq_length`),
-    /// producing 3-dimensional tensors where the dimensions represent [batch_size, sequence_length, hidden_size].
+    /// Applies the cell over the whole sequence, in reverse order if `reverse` is true.
     ///
-    /// Parameters:
-    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tuple of tensors representing the initial cell state and hidden state.
-    ///            Each state tensor has shape [batch_size, hidden_size].
-    ///            If no initial state is provided, these tensors are initialized to zeros.
-    ///
-    /// Returns:
-    ///     A tuple of tensors, where the first tensor represents the cell states and
-    ///     the second tensor represents the hidden states for each sequence element.
-    ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size].
-    pub fn forward(
-        &mut self,
+    /// Returns the hidden state for each element of the sequence, with shape
+    /// `[batch_size, sequence_length, hidden_size]`, and the final cell and hidden states, with
+    /// shape `[batch_size, hidden_size]`.
+    fn forward(
+        &self,
         batched_input: Tensor<B, 3>,
-        state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
-    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
-        let seq_length = batched_input.shape().dims[1];
-        let mut batched_cell_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
-        let mut batched_hidden_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
-
-        let (mut cell_state, mut hidden_state) = match state {
-            Some((cell_state, hidden_state)) => (cell_state, hidden_state),
-            None => (
-                Tensor::zeros([self.batch_size, self.d_hidden]),
-                Tensor::zeros([self.batch_size, self.d_hidden]),
-            ),
-        };
+        state: (Tensor<B, 2>, Tensor<B, 2>),
+        reverse: bool,
+    ) -> (Tensor<B, 3>, (Tensor<B, 2>, Tensor<B, 2>)) {
+        let [batch_size, seq_length, d_input] = batched_input.dims();
+        let (mut cell_state, mut hidden_state) = state;
+        let mut hidden_states = Vec::with_capacity(seq_length);
+
+        for i in 0..seq_length {
+            let t = match reverse {
+                true => seq_length - i - 1,
+                false => i,
+            };
+            let input_t = batched_input
+                .clone()
+                .index([0..batch_size, t..(t + 1), 0..d_input])
+                .reshape([batch_size, d_input]);
 
-        for t in 0..seq_length {
-            let indices = Tensor::arange(t..t + 1);
-            let input_t = batched_input.clone().index_select(1, indices).squeeze(1);
             // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
+            let biased_fg_input_sum = self
+                .forget_gate
+                .gate_product(input_t.clone(), hidden_state.clone());
             let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
 
             // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
+            let biased_ig_input_sum = self
+                .input_gate
+                .gate_product(input_t.clone(), hidden_state.clone());
             let add_values = activation::sigmoid(biased_ig_input_sum);
 
             // o(utput)g(ate) tensors
-            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
+            let biased_og_input_sum = self
+                .output_gate
+                .gate_product(input_t.clone(), hidden_state.clone());
             let output_values = activation::sigmoid(biased_og_input_sum);
 
             // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
+            let biased_cg_input_sum = self.cell_gate.gate_product(input_t, hidden_state);
             let candidate_cell_values = biased_cg_input_sum.tanh();
 
-            cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
+            cell_state = forget_values * cell_state + add_values * candidate_cell_values;
             hidden_state = output_values * cell_state.clone().tanh();
 
-            // store the state for this timestep
-            batched_cell_state = batched_cell_state.index_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                cell_state.clone().unsqueeze(),
-            );
-            batched_hidden_state = batched_hidden_state.index_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                hidden_state.clone().unsqueeze(),
-            );
+            // store the hidden state for this timestep
+            hidden_states.push(hidden_state.clone().reshape([batch_size, 1, self.d_hidden]));
+        }
+
+        if reverse {
+            hidden_states.reverse();
         }
 
-        (batched_cell_state, batched_hidden_state)
+        (Tensor::cat(hidden_states, 1), (cell_state, hidden_state))
     }
+}
 
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
+impl<B: Backend> Lstm<B> {
+    /// Applies the forward pass on the input tensor. This LSTM implementation returns the hidden
+    /// state of the last layer for each element in a sequence (i.e., across `seq_length`), as well
+    /// as the final cell state and hidden state of every layer.
+    ///
+    /// The batch size is inferred from the input.
+    ///
+    /// Parameters:
+    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
+    ///     state: An optional [state](LstmState) representing the initial cell state and hidden
+    ///            state of every layer. Each state tensor has shape
+    ///            [num_layers * num_directions, batch_size, hidden_size].
+    ///            If no initial state is provided, these tensors are initialized to zeros.
     ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
+    /// Returns:
+    ///     A tuple, where the first tensor represents the hidden states of the last layer for each
+    ///     sequence element, with shape [batch_size, sequence_length, num_directions * hidden_size],
+    ///     and the second element represents the final [state](LstmState) of every layer.
+    ///
+    /// Note: before stacked layers were supported, this method returned the cell states and the
+    /// hidden states of every sequence element and expected states of shape
+    /// [batch_size, hidden_size]. Only the hidden states of every sequence element are returned
+    /// now, the cell state being available for the last element through the returned state.
+    pub fn forward(
         &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
+        batched_input: Tensor<B, 3>,
+        state: Option<LstmState<B>>,
+    ) -> (Tensor<B, 3>, LstmState<B>) {
+        let [batch_size, _, _] = batched_input.dims();
+        let num_cells = self.cells.len();
+        let num_directions = num_cells / self.num_layers;
+
+        let state = match state {
+            Some(state) => state,
+            None => {
+                let zeros = Tensor::zeros_device(
+                    [num_cells, batch_size, self.d_hidden],
+                    &batched_input.device(),
+                );
+                LstmState::new(zeros.clone(), zeros)
+            }
+        };
+
+        let mut output = batched_input;
+        let mut cell_states = Vec::with_capacity(num_cells);
+        let mut hidden_states = Vec::with_capacity(num_cells);
+
+        for layer in 0..self.num_layers {
+            if layer > 0 {
+                output = self.dropout.forward(output);
             }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
+
+            let mut outputs = Vec::with_capacity(num_directions);
+
+            for direction in 0..num_directions {
+                let index = layer * num_directions + direction;
+                let state_cell = (
+                    state_at(&state.cell, index, batch_size, self.d_hidden),
+                    state_at(&state.hidden, index, batch_size, self.d_hidden),
+                );
+                let (output_cell, (cell_state, hidden_state)) =
+                    self.cells[index].forward(output.clone(), state_cell, direction == 1);
+
+                outputs.push(output_cell);
+                cell_states.push(cell_state.unsqueeze());
+                hidden_states.push(hidden_state.unsqueeze());
+            }
+
+            output = Tensor::cat(outputs, 2);
         }
+
+        let state = LstmState::new(Tensor::cat(cell_states, 0), Tensor::cat(hidden_states, 0));
+
+        (output, state)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{module::Param, nn::LinearRecord, TestBackend};
-    use burn_tensor::Data;
+    use crate::module::Param;
+    use crate::nn::LinearRecord;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn test_with_uniform_initializer() {
         TestBackend::seed(0);
 
-        let config = LstmConfig::new(5, 5, false, 2)
+        let config = LstmConfig::new(5, 5, false)
             .with_initializer(Initializer::Uniform { min: 0.0, max: 1.0 });
         let lstm = config.init::<TestBackend>();
 
         let gate_to_data =
-            |gate: GateController<TestBackend>| gate.input_transform.weight.val().to_data();
+            |gate: &GateController<TestBackend>| gate.input_transform.weight.val().to_data();
 
-        gate_to_data(lstm.input_gate).assert_within_range(0..1);
-        gate_to_data(lstm.forget_gate).assert_within_range(0..1);
-        gate_to_data(lstm.output_gate).assert_within_range(0..1);
-        gate_to_data(lstm.cell_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.cells[0].input_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.cells[0].forget_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.cells[0].output_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.cells[0].cell_gate).assert_within_range(0..1);
     }
 
     /// Test forward pass with simple input vector.
@@ -250,7 +402,7 @@ mod tests {
     #[test]
     fn test_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = LstmConfig::new(1, 1, false, 1);
+        let config = LstmConfig::new(1, 1, false);
         let mut lstm = config.init::<TestBackend>();
 
         fn create_gate_controller(
@@ -275,7 +427,7 @@ mod tests {
             )
         }
 
-        lstm.input_gate = create_gate_controller(
+        lstm.cells[0].input_gate = create_gate_controller(
             0.5,
             0.0,
             1,
@@ -283,7 +435,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.forget_gate = create_gate_controller(
+        lstm.cells[0].forget_gate = create_gate_controller(
             0.7,
             0.0,
             1,
@@ -291,7 +443,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.cell_gate = create_gate_controller(
+        lstm.cells[0].cell_gate = create_gate_controller(
             0.9,
             0.0,
             1,
@@ -299,7 +451,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.output_gate = create_gate_controller(
+        lstm.cells[0].output_gate = create_gate_controller(
             1.1,
             0.0,
             1,
@@ -311,18 +463,111 @@ mod tests {
         // single timestep with single feature
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]));
 
-        let (cell_state_batch, hidden_state_batch) = lstm.forward(input, None);
-        let cell_state = cell_state_batch
-            .index_select(0, Tensor::arange(0..1))
-            .squeeze(0);
-        let hidden_state = hidden_state_batch
-            .index_select(0, Tensor::arange(0..1))
-            .squeeze(0);
+        let (output, state) = lstm.forward(input, None);
+        let cell_state = state.cell.squeeze(0);
+        let hidden_state = state.hidden.squeeze(0);
         cell_state
             .to_data()
             .assert_approx_eq(&Data::from([[0.046]]), 3);
         hidden_state
             .to_data()
-            .assert_approx_eq(&Data::from([[0.024]]), 3)
+            .assert_approx_eq(&Data::from([[0.024]]), 3);
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.024]]]), 3)
+    }
+
+    #[test]
+    fn test_batch_size_inferred_from_input() {
+        let lstm = LstmConfig::new(4, 3, true).init::<TestBackend>();
+
+        for batch_size in [1, 5] {
+            let input = Tensor::random([batch_size, 2, 4], Distribution::Standard);
+
+            let (output, state) = lstm.forward(input, None);
+
+            assert_eq!(output.dims(), [batch_size, 2, 3]);
+            assert_eq!(state.cell.dims(), [1, batch_size, 3]);
+            assert_eq!(state.hidden.dims(), [1, batch_size, 3]);
+        }
+    }
+
+    #[test]
+    fn test_multi_layer_bidirectional() {
+        let lstm = LstmConfig::new(4, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+        let input = Tensor::random([2, 5, 4], Distribution::Standard);
+
+        let (output, state) = lstm.forward(input, None);
+
+        assert_eq!(output.dims(), [2, 5, 6]);
+        assert_eq!(state.cell.dims(), [4, 2, 3]);
+        assert_eq!(state.hidden.dims(), [4, 2, 3]);
+        // The final hidden state of the forward direction is the output of the last element,
+        // while the one of the reverse direction is the output of the first element.
+        output
+            .clone()
+            .index([0..2, 4..5, 0..3])
+            .reshape([2, 3])
+            .to_data()
+            .assert_approx_eq(
+                &state
+                    .hidden
+                    .clone()
+                    .index([2..3, 0..2, 0..3])
+                    .squeeze(0)
+                    .to_data(),
+                3,
+            );
+        output
+            .index([0..2, 0..1, 3..6])
+            .reshape([2, 3])
+            .to_data()
+            .assert_approx_eq(
+                &state.hidden.index([3..4, 0..2, 0..3]).squeeze(0).to_data(),
+                3,
+            );
+    }
+
+    #[test]
+    #[should_panic = "The record has 1 cells, but the configuration expects 4"]
+    fn test_init_with_record_of_another_num_layers() {
+        let record = LstmConfig::new(4, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        LstmConfig::new(4, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init_with::<TestBackend>(record);
+    }
+
+    #[test]
+    fn test_load_record_of_previous_layout() {
+        let config = LstmConfig::new(4, 3, true);
+        let lstm = config.init::<TestBackend>();
+        let cell = lstm.cells[0].clone();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let record = LstmRecordV1 {
+            input_gate: cell.input_gate.into_record(),
+            forget_gate: cell.forget_gate.into_record(),
+            output_gate: cell.output_gate.into_record(),
+            cell_gate: cell.cell_gate.into_record(),
+            batch_size: ConstantRecord::new(),
+            d_hidden: ConstantRecord::new(),
+        };
+
+        let bytes = recorder.record(record, ()).unwrap();
+        let record: LstmRecordV1<TestBackend> = recorder.load(bytes).unwrap();
+        let loaded = config.init_with::<TestBackend>(record.into());
+
+        let input = Tensor::random([2, 3, 4], Distribution::Standard);
+        let (output, _) = lstm.forward(input.clone(), None);
+        let (output_loaded, _) = loaded.forward(input, None);
+        output
+            .to_data()
+            .assert_approx_eq(&output_loaded.to_data(), 3);
     }
 }
@@ -1,4 +1,5 @@
 mod gate_controller;
+mod state;
 
 /// Gated Recurrent Unit module.
 pub mod gru;
@@ -7,4 +8,5 @@ pub mod gru;
 pub mod lstm;
 
 pub use gate_controller::*;
+pub use gru::*;
 pub use lstm::*;
@@ -0,0 +1,15 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Select the state of the cell at the given index, with shape `[batch_size, hidden_size]`.
+pub(crate) fn state_at<B: Backend>(
+    state: &Tensor<B, 3>,
+    index: usize,
+    batch_size: usize,
+    d_hidden: usize,
+) -> Tensor<B, 2> {
+    state
+        .clone()
+        .index([index..(index + 1), 0..batch_size, 0..d_hidden])
+        .reshape([batch_size, d_hidden])
+}
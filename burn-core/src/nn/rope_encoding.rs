@@ -0,0 +1,155 @@
+use alloc::vec;
+
+use crate as burn;
+
+use super::pos_encoding::positions_tensor;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Tensor};
+use libm::pow;
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config)]
+pub struct RotaryEncodingConfig {
+    /// Maximum sequence length of the input.
+    pub max_sequence_length: usize,
+    /// The size of each vector to rotate, usually the size of an attention head.
+    pub d_model: usize,
+    /// The base used to compute the rotation frequencies. Default: 10000.0
+    #[config(default = "10000.0")]
+    pub theta: f64,
+}
+
+/// Applies rotary positional encoding (RoPE) to the input, as described in the paper
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// The first and second halves of the last dimension are rotated together, pair `(i, i + d/2)`
+/// using the angle `pos * theta^(-2i / d_model)`. The rotations are computed on the device of the
+/// input for the encoded positions and are not trained.
+#[derive(Module, Clone, Debug)]
+pub struct RotaryEncoding {
+    max_sequence_length: usize,
+    d_model: usize,
+    theta: f64,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [rotary encoding](RotaryEncoding) module.
+    pub fn init(&self) -> RotaryEncoding {
+        assert_eq!(
+            self.d_model % 2,
+            0,
+            "The d_model of the rotary encoding must be even"
+        );
+
+        RotaryEncoding {
+            max_sequence_length: self.max_sequence_length,
+            d_model: self.d_model,
+            theta: self.theta,
+        }
+    }
+}
+
+impl RotaryEncoding {
+    /// Applies the rotary encoding to the input, the first position being `0`.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, n_heads, seq_length, d_model]`
+    /// - output: `[batch_size, n_heads, seq_length, d_model]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.apply(input, 0)
+    }
+
+    /// Applies the rotary encoding to the input, the first position being `start`.
+    ///
+    /// Useful during autoregressive decoding, where only the newest positions are encoded.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, n_heads, seq_length, d_model]`
+    /// - output: `[batch_size, n_heads, seq_length, d_model]`
+    pub fn apply<B: Backend>(&self, input: Tensor<B, 4>, start: usize) -> Tensor<B, 4> {
+        let [batch_size, n_heads, seq_length, d_model] = input.dims();
+
+        assert!(
+            start + seq_length <= self.max_sequence_length,
+            "Position {} exceeds the maximum sequence length {}",
+            start + seq_length,
+            self.max_sequence_length
+        );
+        assert_eq!(
+            d_model, self.d_model,
+            "The input d_model must match the rotary encoding d_model"
+        );
+
+        let half = d_model / 2;
+        let device = input.device();
+        let frequencies = (0..half)
+            .map(|i| pow(self.theta, -2.0 * i as f64 / d_model as f64) as f32)
+            .collect();
+        let frequencies =
+            Tensor::<B, 2>::from_data(Data::new(frequencies, [1, half].into()).convert())
+                .to_device(&device);
+        let angles = positions_tensor::<B>(start..start + seq_length, &device).mul(frequencies);
+        // Both halves of the last dimension are rotated by the same angles.
+        let angles =
+            Tensor::cat(vec![angles.clone(), angles], 1).reshape([1, 1, seq_length, d_model]);
+        let cos = angles.clone().cos();
+        let sin = angles.sin();
+
+        let first_half = input
+            .clone()
+            .index([0..batch_size, 0..n_heads, 0..seq_length, 0..half]);
+        let second_half =
+            input
+                .clone()
+                .index([0..batch_size, 0..n_heads, 0..seq_length, half..d_model]);
+        let rotated = Tensor::cat(vec![second_half.neg(), first_half], 3);
+
+        input * cos + rotated * sin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_rotary_encoding_forward() {
+        let rope = RotaryEncodingConfig::new(10, 4).init();
+        let input = Tensor::<TestBackend, 4>::from_data(Data::from([[[
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+        ]]]));
+
+        let output = rope.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0000, 2.0000, 3.0000, 4.0000],
+                [-1.9841, 1.9599, 2.4624, 4.0198],
+                [-3.1440, 1.9196, -0.3391, 4.0392],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_rotary_encoding_apply_with_offset() {
+        let rope = RotaryEncodingConfig::new(10, 8).init();
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 6, 8], Distribution::Standard);
+
+        let output_full = rope.forward(input.clone());
+        let output_last = rope.apply(input.index([0..2, 0..3, 5..6, 0..8]), 5);
+
+        output_full
+            .index([0..2, 0..3, 5..6, 0..8])
+            .into_data()
+            .assert_approx_eq(&output_last.into_data(), 3);
+    }
+}
@@ -250,8 +250,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             self_attn_input = self_attn_input.mask_attn(mask_attn.clone());
         }
 
-        let x_1 = self.self_attn.forward(self_attn_input);
-        let x_1 = self.dropout.forward(x_1.context) + x_0;
+        let x_1 = self.self_attn.forward_fused(self_attn_input);
+        let x_1 = self.dropout.forward(x_1) + x_0;
         let x_1 = self.norm_1.forward(x_1);
 
         let mut cross_attn_input =
@@ -263,8 +263,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             cross_attn_input = cross_attn_input.mask_attn(mask_attn.clone());
         }
 
-        let x_2 = self.cross_attn.forward(cross_attn_input);
-        let x_2 = self.dropout.forward(x_2.context) + x_1;
+        let x_2 = self.cross_attn.forward_fused(cross_attn_input);
+        let x_2 = self.dropout.forward(x_2) + x_1;
         let x_2 = self.norm_2.forward(x_2);
 
         let x_3 = self.pwff.forward(x_2.clone());
@@ -301,8 +301,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
 
         let x_1 = self
             .self_attn
-            .forward_cache(self_attn_input, &mut cache.self_attn);
-        let x_1 = self.dropout.forward(x_1.context) + x_0;
+            .forward_cache_fused(self_attn_input, &mut cache.self_attn);
+        let x_1 = self.dropout.forward(x_1) + x_0;
         let x_1 = cache
             .norm_1
             .forward_autoregressive(x_1, 1, |x| self.norm_1.forward(x));
@@ -317,8 +317,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
 
         let x_2 = self
             .cross_attn
-            .forward_cache(mha_input, &mut cache.cross_attn);
-        let x_2 = self.dropout.forward(x_2.context) + x_1;
+            .forward_cache_fused(mha_input, &mut cache.cross_attn);
+        let x_2 = self.dropout.forward(x_2) + x_1;
         let x_2 = cache
             .norm_2
             .forward_autoregressive(x_2, 1, |x| self.norm_2.forward(x));
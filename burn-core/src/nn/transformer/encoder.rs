@@ -227,8 +227,8 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
 
-        let x_1 = self.mha.forward(input_mhs);
-        let x_1 = self.dropout.forward(x_1.context) + input;
+        let x_1 = self.mha.forward_fused(input_mhs);
+        let x_1 = self.dropout.forward(x_1) + input;
         let x_1 = self.norm_1.forward(x_1);
 
         let x_2 = self.pwff.forward(x_1.clone());
@@ -264,8 +264,8 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
 
-        let x_1 = self.mha.forward_cache(input_mhs, &mut cache.mha);
-        let x_1 = self.dropout.forward(x_1.context) + input;
+        let x_1 = self.mha.forward_cache_fused(input_mhs, &mut cache.mha);
+        let x_1 = self.dropout.forward(x_1) + input;
         let x_1 = cache
             .norm_1
             .forward_autoregressive(x_1, 1, |x_1| self.norm_1.forward(x_1));
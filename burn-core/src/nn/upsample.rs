@@ -0,0 +1,140 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+
+/// Configuration to create an [upsample](Upsample) layer.
+///
+/// Either the `output_size` or the `scale_factor` must be provided. When both are set, the
+/// `output_size` takes precedence.
+#[derive(Config)]
+pub struct UpsampleConfig {
+    /// The spatial size of the output.
+    pub output_size: Option<[usize; 2]>,
+    /// The multiplier applied to the spatial size of the input.
+    pub scale_factor: Option<[f32; 2]>,
+    /// The interpolation algorithm. Default: Nearest
+    #[config(default = "UpsampleMode::Nearest")]
+    pub mode: UpsampleMode,
+    /// If the corner pixels of the input and output are aligned. Default: false
+    #[config(default = false)]
+    pub align_corners: bool,
+}
+
+/// Interpolation algorithm used by the [upsample](Upsample) layer.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum UpsampleMode {
+    /// Nearest neighbor interpolation.
+    Nearest,
+    /// Bilinear interpolation.
+    Bilinear,
+    /// Bicubic interpolation.
+    Bicubic,
+}
+
+impl From<UpsampleMode> for InterpolateMode {
+    fn from(mode: UpsampleMode) -> Self {
+        match mode {
+            UpsampleMode::Nearest => InterpolateMode::Nearest,
+            UpsampleMode::Bilinear => InterpolateMode::Bilinear,
+            UpsampleMode::Bicubic => InterpolateMode::Bicubic,
+        }
+    }
+}
+
+/// Resizes the spatial dimensions of input tensors using interpolation.
+#[derive(Module, Debug, Clone)]
+pub struct Upsample {
+    output_size: Option<[usize; 2]>,
+    scale_factor: Option<[f32; 2]>,
+    mode: UpsampleMode,
+    align_corners: bool,
+}
+
+impl UpsampleConfig {
+    /// Initialize a new [upsample](Upsample) module.
+    pub fn init(&self) -> Upsample {
+        assert!(
+            self.output_size.is_some() || self.scale_factor.is_some(),
+            "Either the output size or the scale factor must be provided"
+        );
+
+        Upsample {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: self.mode.clone(),
+            align_corners: self.align_corners,
+        }
+    }
+}
+
+impl Upsample {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels, height_in, width_in] = input.dims();
+        let output_size = match (self.output_size, self.scale_factor) {
+            (Some(output_size), _) => output_size,
+            (None, Some([scale_height, scale_width])) => [
+                (height_in as f32 * scale_height) as usize,
+                (width_in as f32 * scale_width) as usize,
+            ],
+            (None, None) => panic!("Either the output size or the scale factor must be provided"),
+        };
+        let options = InterpolateOptions::new(self.mode.clone().into(), self.align_corners);
+
+        interpolate(input, output_size, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn upsample_nearest_scale_factor() {
+        let module = UpsampleConfig::new()
+            .with_scale_factor(Some([2.0, 2.0]))
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_data(Data::from([[[[1.0, 2.0], [3.0, 4.0]]]]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn upsample_bilinear_align_corners_output_size() {
+        let module = UpsampleConfig::new()
+            .with_output_size(Some([3, 3]))
+            .with_mode(UpsampleMode::Bilinear)
+            .with_align_corners(true)
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_data(Data::from([[[[1.0, 2.0], [3.0, 4.0]]]]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
+    group::ParamGroupSettings,
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// AdaDelta configuration.
+#[derive(Config)]
+pub struct AdaDeltaConfig {
+    /// Coefficient used for computing the running averages of the squared gradients and updates.
+    #[config(default = 0.9)]
+    rho: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// AdaDelta optimizer as described in the paper [ADADELTA: An Adaptive Learning Rate Method](https://arxiv.org/pdf/1212.5701.pdf).
+///
+/// The learning rate is used as a coefficient applied to the update, a value of `1.0` follows
+/// the original paper.
+pub struct AdaDelta<B: Backend> {
+    rho: f32,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// AdaDelta state.
+#[derive(Record, Clone, new)]
+pub struct AdaDeltaState<B: Backend, const D: usize> {
+    weight_decay: Option<WeightDecayState<B, D>>,
+    square_avg: Tensor<B, D>,
+    acc_delta: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for AdaDelta<B> {
+    type State<const D: usize> = AdaDeltaState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_weight_decay = None;
+        let mut state_averages = None;
+
+        if let Some(state) = state {
+            state_weight_decay = state.weight_decay;
+            state_averages = Some((state.square_avg, state.acc_delta));
+        }
+
+        let group_weight_decay = group.override_weight_decay();
+        if let Some(weight_decay) = group_weight_decay.as_ref().or(self.weight_decay.as_ref()) {
+            let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
+            state_weight_decay = Some(state);
+            grad = grad_out;
+        }
+
+        let factor = 1.0 - self.rho;
+        let grad_squared = grad.clone().powf(2.0).mul_scalar(factor);
+
+        let (square_avg, acc_delta) = match state_averages {
+            Some((square_avg, acc_delta)) => (
+                square_avg.mul_scalar(self.rho).add(grad_squared),
+                Some(acc_delta),
+            ),
+            None => (grad_squared, None),
+        };
+
+        let std = square_avg.clone().add_scalar(self.epsilon).sqrt();
+        let delta = match &acc_delta {
+            Some(acc_delta) => acc_delta
+                .clone()
+                .add_scalar(self.epsilon)
+                .sqrt()
+                .div(std)
+                .mul(grad),
+            // The accumulated updates start at zero.
+            None => grad.div(std).mul_scalar(self.epsilon.sqrt()),
+        };
+
+        let delta_squared = delta.clone().powf(2.0).mul_scalar(factor);
+        let acc_delta = match acc_delta {
+            Some(acc_delta) => acc_delta.mul_scalar(self.rho).add(delta_squared),
+            None => delta_squared,
+        };
+
+        let state = AdaDeltaState::new(state_weight_decay, square_avg, acc_delta);
+        let delta = delta.mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.weight_decay = state.weight_decay.map(|state| state.to_device(device));
+        state.square_avg = state.square_avg.to_device(device);
+        state.acc_delta = state.acc_delta.to_device(device);
+        state
+    }
+}
+
+impl AdaDeltaConfig {
+    /// Initialize AdaDelta optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<AdaDelta<B::InnerBackend>, M, B> {
+        let optim = AdaDelta {
+            rho: self.rho,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 1.0;
+
+    #[test]
+    fn test_adadelta_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_adadelta();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(optimizer.to_record(), "/tmp/test_optim_adadelta".into())
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_loaded = recorder.load("/tmp/test_optim_adadelta".into()).unwrap();
+        let optimizer = create_adadelta();
+        let optimizer = optimizer.load_record(state_optim_loaded);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adadelta_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = AdaDeltaConfig::new()
+            .with_rho(0.9)
+            .with_epsilon(1e-6)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3268, 0.1312, 0.3981, 0.3138, 0.0797, 0.0609],
+                [0.0734, -0.0228, -0.3710, 0.2507, 0.1912, -0.2965],
+                [-0.0252, 0.0284, -0.3024, 0.2422, -0.2842, 0.3068],
+                [-0.3044, -0.2278, -0.3779, -0.3045, -0.0825, 0.1562],
+                [0.3240, -0.2242, 0.3657, -0.1789, 0.3736, -0.0364],
+                [-0.0221, -0.0182, 0.1196, 0.1859, 0.0231, 0.3771],
+            ]),
+            Data::from([-0.3969, 0.0820, -0.1034, 0.1112, 0.1302, 0.0066]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 3);
+        weight_updated.assert_approx_eq(&weight_expected, 3);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_adadelta(
+    ) -> OptimizerAdaptor<AdaDelta<TestBackend>, nn::Linear<TestADBackend>, TestADBackend> {
+        let config = AdaDeltaConfig::new();
+        AdaDelta {
+            rho: config.rho,
+            epsilon: config.epsilon,
+            weight_decay: config.weight_decay.as_ref().map(WeightDecay::new),
+        }
+        .into()
+    }
+}
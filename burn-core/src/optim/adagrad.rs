@@ -0,0 +1,256 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
+    group::ParamGroupSettings,
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// AdaGrad configuration.
+#[derive(Config)]
+pub struct AdaGradConfig {
+    /// Learning rate decay, the effective learning rate is `lr / (1 + (t - 1) * lr_decay)`.
+    #[config(default = 0.0)]
+    lr_decay: f64,
+    /// A value required for numerical stability.
+    #[config(default = 1e-5)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// AdaGrad optimizer as described in the paper [Adaptive Subgradient Methods for Online Learning
+/// and Stochastic Optimization](https://jmlr.org/papers/volume12/duchi11a/duchi11a.pdf).
+pub struct AdaGrad<B: Backend> {
+    lr_decay: f64,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// AdaGrad state.
+#[derive(Record, Clone, new)]
+pub struct AdaGradState<B: Backend, const D: usize> {
+    weight_decay: Option<WeightDecayState<B, D>>,
+    time: usize,
+    sum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for AdaGrad<B> {
+    type State<const D: usize> = AdaGradState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_weight_decay = None;
+        let mut state_sum = None;
+        let mut time = 1;
+
+        if let Some(state) = state {
+            state_weight_decay = state.weight_decay;
+            state_sum = Some(state.sum);
+            time = state.time + 1;
+        }
+
+        let group_weight_decay = group.override_weight_decay();
+        if let Some(weight_decay) = group_weight_decay.as_ref().or(self.weight_decay.as_ref()) {
+            let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
+            state_weight_decay = Some(state);
+            grad = grad_out;
+        }
+
+        let grad_squared = grad.clone().powf(2.0);
+        let sum = match state_sum {
+            Some(sum) => sum.add(grad_squared),
+            None => grad_squared,
+        };
+
+        let lr = lr / (1.0 + (time - 1) as f64 * self.lr_decay);
+        let delta = grad
+            .div(sum.clone().sqrt().add_scalar(self.epsilon))
+            .mul_scalar(lr);
+
+        let state = AdaGradState::new(state_weight_decay, time, sum);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.weight_decay = state.weight_decay.map(|state| state.to_device(device));
+        state.sum = state.sum.to_device(device);
+        state
+    }
+}
+
+impl AdaGradConfig {
+    /// Initialize AdaGrad optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<AdaGrad<B::InnerBackend>, M, B> {
+        let optim = AdaGrad {
+            lr_decay: self.lr_decay,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_adagrad_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_adagrad();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(optimizer.to_record(), "/tmp/test_optim_adagrad".into())
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_loaded = recorder.load("/tmp/test_optim_adagrad".into()).unwrap();
+        let optimizer = create_adagrad();
+        let optimizer = optimizer.load_record(state_optim_loaded);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adagrad_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = AdaGradConfig::new()
+            .with_lr_decay(0.5)
+            .with_epsilon(1e-8)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3350, 0.1230, 0.3899, 0.3056, 0.0715, 0.0527],
+                [0.0661, -0.0301, -0.3783, 0.2434, 0.1839, -0.3038],
+                [-0.0335, 0.0201, -0.3107, 0.2339, -0.2925, 0.2985],
+                [-0.3126, -0.2360, -0.3861, -0.3127, -0.0907, 0.1480],
+                [0.3159, -0.2323, 0.3576, -0.1870, 0.3655, -0.0445],
+                [-0.0303, -0.0264, 0.1114, 0.1777, 0.0149, 0.3689],
+            ]),
+            Data::from([-0.4052, 0.0737, -0.1117, 0.1029, 0.1219, -0.0017]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 3);
+        weight_updated.assert_approx_eq(&weight_expected, 3);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_adagrad(
+    ) -> OptimizerAdaptor<AdaGrad<TestBackend>, nn::Linear<TestADBackend>, TestADBackend> {
+        let config = AdaGradConfig::new();
+        AdaGrad {
+            lr_decay: config.lr_decay,
+            epsilon: config.epsilon,
+            weight_decay: config.weight_decay.as_ref().map(WeightDecay::new),
+        }
+        .into()
+    }
+}
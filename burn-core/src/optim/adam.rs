@@ -5,7 +5,8 @@ use crate::{
 
 use super::{
     decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
-    Optimizer, SimpleOptimizer,
+    group::ParamGroupSettings,
+    SimpleOptimizer,
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
@@ -47,11 +48,22 @@ impl<B: Backend> SimpleOptimizer<B> for Adam<B> {
     type State<const D: usize> = AdamState<B, D>;
 
     fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
         &self,
         lr: LearningRate,
         tensor: Tensor<B, D>,
         mut grad: Tensor<B, D>,
         state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
         let mut state_weight_decay = None;
         let mut state_momemtum = None;
@@ -61,7 +73,8 @@ impl<B: Backend> SimpleOptimizer<B> for Adam<B> {
             state_momemtum = Some(state.momentum);
         }
 
-        if let Some(weight_decay) = &self.weight_decay {
+        let group_weight_decay = group.override_weight_decay();
+        if let Some(weight_decay) = group_weight_decay.as_ref().or(self.weight_decay.as_ref()) {
             let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
             state_weight_decay = Some(state);
             grad = grad_out;
@@ -91,7 +104,9 @@ impl AdamConfig {
     /// # Returns
     ///
     /// Returns an optimizer that can be used to optimize a module.
-    pub fn init<B: ADBackend, M: ADModule<B>>(&self) -> impl Optimizer<M, B> {
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adam<B::InnerBackend>, M, B> {
         let optim = Adam {
             momentum: AdaptiveMomentum {
                 beta_1: self.beta_1,
@@ -117,10 +132,10 @@ pub struct AdaptiveMomentumState<B: Backend, const D: usize> {
     moment_2: Tensor<B, D>,
 }
 
-struct AdaptiveMomentum {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentum {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentum {
@@ -2,6 +2,7 @@ use super::GradientsParams;
 use crate::module::ADModule;
 use crate::record::Record;
 use crate::tensor::backend::ADBackend;
+use crate::tensor::Tensor;
 use crate::LearningRate;
 
 /// General trait to optimize [module](ADModule).
@@ -22,4 +23,15 @@ where
 
     /// Load the state of the optimizer as a [record](Record).
     fn load_record(self, record: Self::Record) -> Self;
+
+    /// Get the global L2 norm of the gradients used by the last step, computed before they were
+    /// clipped, if the optimizer clips them.
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        None
+    }
+
+    /// Whether the optimizer clips the gradients before using them.
+    fn has_grad_clipping(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,256 @@
+use super::{GradientsParams, Optimizer};
+use crate::module::{ADModule, ModuleVisitor, ParamId};
+use crate::tensor::{backend::ADBackend, Tensor};
+use crate::LearningRate;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// Gradient centralization as described in the paper
+/// [Gradient Centralization: A New Optimization Technique for Deep Neural Networks](https://arxiv.org/abs/2004.01461).
+///
+/// The gradients of the weights of linear and convolution layers are centralized, by subtracting
+/// their mean over the weights contributing to the same output feature, before the step of the
+/// inner optimizer.
+///
+/// Gradients of tensors with a single dimension, such as biases and normalization parameters, are
+/// left unchanged. For tensors with two dimensions, such as linear weights of shape
+/// `[d_input, d_output]`, the mean is computed over the first dimension. For tensors with more
+/// dimensions, such as convolution weights of shape `[channels_out, channels_in, kernel_size...]`,
+/// the mean is computed over all the dimensions except the first one.
+///
+/// Every parameter with more than one dimension is centralized by default. Since the shape of a
+/// gradient doesn't tell which layer it belongs to, parameters that aren't linear or convolution
+/// weights, such as embedding tables, should be excluded with a
+/// [filter](GradientCentralization::with_param_filter).
+///
+/// Any [optimizer](Optimizer) can be wrapped, including other wrappers.
+pub struct GradientCentralization<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    optim: O,
+    filter: Option<ParamFilter>,
+    phantom: PhantomData<(M, B)>,
+}
+
+/// Function used to select the parameters whose gradients are
+/// [centralized](GradientCentralization) based on their module path and [id](ParamId).
+pub type ParamFilter = Box<dyn Fn(&str, &ParamId) -> bool + Send + Sync>;
+
+impl<O, M, B> GradientCentralization<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    /// Wrap the given optimizer with gradient centralization.
+    pub fn new(optim: O) -> Self {
+        Self {
+            optim,
+            filter: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Only centralize the gradients of the parameters for which the filter returns true.
+    ///
+    /// The filter receives the module path of the parameter, which is the dot separated list of
+    /// field names leading to it (e.g. `encoder.linear.weight`), and its [id](ParamId).
+    pub fn with_param_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str, &ParamId) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// The inner optimizer.
+    pub fn inner(&self) -> &O {
+        &self.optim
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for GradientCentralization<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    type Record = O::Record;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let mut visitor = GradientsCentralizer::<B>::new(grads, self.filter.as_ref());
+        module.visit(&mut visitor);
+
+        self.optim.step(lr, module, visitor.grads)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.optim.to_record()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        self.optim.grad_norm()
+    }
+
+    fn has_grad_clipping(&self) -> bool {
+        self.optim.has_grad_clipping()
+    }
+}
+
+/// Centralize a gradient, see [gradient centralization](GradientCentralization).
+pub(crate) fn centralize_gradient<B: Backend, const D: usize>(grad: Tensor<B, D>) -> Tensor<B, D> {
+    match D {
+        0 | 1 => grad,
+        2 => grad.clone().sub(grad.mean_dim(0)),
+        _ => {
+            let shape = grad.shape();
+            let num_features = shape.dims[0];
+            let grad: Tensor<B, 2> =
+                grad.reshape([num_features, shape.num_elements() / num_features]);
+
+            grad.clone().sub(grad.mean_dim(1)).reshape(shape)
+        }
+    }
+}
+
+struct GradientsCentralizer<'a, B: ADBackend> {
+    grads: GradientsParams,
+    filter: Option<&'a ParamFilter>,
+    path: Vec<String>,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: ADBackend> GradientsCentralizer<'a, B> {
+    fn new(grads: GradientsParams, filter: Option<&'a ParamFilter>) -> Self {
+        Self {
+            grads,
+            filter,
+            path: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, B: ADBackend> ModuleVisitor<B> for GradientsCentralizer<'a, B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(filter) = self.filter {
+            if !filter(&self.path.join("."), id) {
+                return;
+            }
+        }
+
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), centralize_gradient(grad));
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::optim::{LookaheadConfig, SgdConfig};
+    use crate::tensor::Distribution;
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn test_centralize_linear_gradients() {
+        let linear = LinearConfig::new(4, 3).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let bias_grad: Tensor<TestBackend, 1> =
+            grads.get(&linear.bias.as_ref().unwrap().id).unwrap();
+        let mut optim = GradientCentralization::new(SgdConfig::new().init());
+
+        let linear_updated = optim.step(1.0, linear.clone(), grads);
+
+        let weight_delta = linear.weight.val().sub(linear_updated.weight.val()).inner();
+        let bias_delta = linear
+            .bias
+            .unwrap()
+            .val()
+            .sub(linear_updated.bias.unwrap().val());
+        weight_delta
+            .mean_dim(0)
+            .into_data()
+            .assert_approx_eq(&Tensor::<TestBackend, 2>::zeros([1, 3]).into_data(), 5);
+        bias_delta
+            .inner()
+            .into_data()
+            .assert_approx_eq(&bias_grad.into_data(), 5);
+    }
+
+    #[test]
+    fn test_only_centralize_filtered_params() {
+        let linear = LinearConfig::new(4, 3).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let weight_grad: Tensor<TestBackend, 2> = grads.get(&linear.weight.id).unwrap();
+        let mut optim = GradientCentralization::new(SgdConfig::new().init())
+            .with_param_filter(|path, _| !path.ends_with("weight"));
+
+        let linear_updated = optim.step(1.0, linear.clone(), grads);
+
+        linear
+            .weight
+            .val()
+            .sub(linear_updated.weight.val())
+            .inner()
+            .into_data()
+            .assert_approx_eq(&weight_grad.into_data(), 5);
+    }
+
+    #[test]
+    fn test_centralize_conv_gradients() {
+        let grad = Tensor::<TestBackend, 4>::random([2, 3, 2, 2], Distribution::Standard);
+
+        let grad = centralize_gradient(grad);
+
+        grad.reshape([2, 12])
+            .mean_dim(1)
+            .into_data()
+            .assert_approx_eq(&Tensor::<TestBackend, 2>::zeros([2, 1]).into_data(), 5);
+    }
+
+    #[test]
+    fn test_nest_with_lookahead() {
+        let linear = LinearConfig::new(4, 3).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let mut optim =
+            LookaheadConfig::new().init(GradientCentralization::new(SgdConfig::new().init()));
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear_updated = optim.step(1.0, linear.clone(), grads);
+
+        linear
+            .weight
+            .val()
+            .sub(linear_updated.weight.val())
+            .inner()
+            .mean_dim(0)
+            .into_data()
+            .assert_approx_eq(&Tensor::<TestBackend, 2>::zeros([1, 3]).into_data(), 5);
+    }
+}
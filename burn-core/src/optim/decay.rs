@@ -2,6 +2,7 @@ use burn_tensor::backend::Backend;
 
 use crate as burn;
 use crate::record::Record;
+use crate::LearningRate;
 
 use crate::config::Config;
 use crate::tensor::{ElementConversion, Tensor};
@@ -59,6 +60,48 @@ impl<B: Backend> WeightDecay<B> {
     }
 }
 
+/// Decoupled weight decay implementation that transforms parameters.
+///
+/// As described in [Decoupled Weight Decay Regularization](https://arxiv.org/pdf/1711.05101.pdf),
+/// the parameters are shrunk by `lr * penalty` at each step instead of adding the penalty to the
+/// gradient, so no state is required.
+pub struct DecoupledWeightDecay {
+    penalty: f64,
+}
+
+impl DecoupledWeightDecay {
+    /// Creates a new [DecoupledWeightDecay](DecoupledWeightDecay) from a [WeightDecayConfig](WeightDecayConfig).
+    pub fn new(config: &WeightDecayConfig) -> Self {
+        Self {
+            penalty: config.penalty,
+        }
+    }
+
+    /// Transforms a parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - Learning rate of the current step.
+    /// * `tensor` - Parameter to transform.
+    ///
+    /// # Returns
+    ///
+    /// The decayed parameter.
+    pub fn transform<B: Backend, const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let decay_rate = lr * self.penalty;
+
+        if decay_rate == 0.0 {
+            return tensor;
+        }
+
+        tensor.mul_scalar(1.0 - decay_rate)
+    }
+}
+
 impl<B: Backend, const D: usize> WeightDecayState<B, D> {
     /// Moves the state to a device.
     ///
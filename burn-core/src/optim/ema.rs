@@ -0,0 +1,298 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::Tensor;
+use burn_tensor::{backend::Backend, container::TensorContainer};
+use core::marker::PhantomData;
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create a [model EMA](ModelEma).
+#[derive(Config)]
+pub struct ModelEmaConfig {
+    /// The decay applied to the averaged weights at each update.
+    #[config(default = 0.9999)]
+    decay: f64,
+    /// Whether to ramp up the decay during the first updates, using
+    /// `min(decay, (1 + n) / (10 + n))` where `n` is the number of updates.
+    #[config(default = false)]
+    warmup: bool,
+    /// The number of calls to [update](ModelEma::update) between two updates of the weights.
+    #[config(default = 1)]
+    update_interval: usize,
+}
+
+/// Exponential moving average of the weights of a model.
+///
+/// The averaged model is a shadow copy of the model, updated after each optimizer step with
+/// `ema = decay * ema + (1 - decay) * weights`. It doesn't track gradients and is usually used
+/// for validation and inference, as its weights tend to generalize better.
+#[derive(Debug)]
+pub struct ModelEma<B: Backend, M: Module<B>> {
+    model: M,
+    decay: f64,
+    warmup: bool,
+    update_interval: usize,
+    step: usize,
+    num_updates: usize,
+    phantom: PhantomData<B>,
+}
+
+/// [Model EMA](ModelEma) state.
+pub struct ModelEmaRecord<B: Backend, M: Module<B>> {
+    model: M::Record,
+    step: usize,
+    num_updates: usize,
+    phantom: PhantomData<B>,
+}
+
+/// [Model EMA](ModelEma) record item.
+#[derive(Serialize, Deserialize)]
+pub struct ModelEmaRecordItem<T> {
+    model: T,
+    step: usize,
+    num_updates: usize,
+}
+
+impl<B: Backend, M: Module<B>> Record for ModelEmaRecord<B, M> {
+    type Item<S: PrecisionSettings> = ModelEmaRecordItem<<M::Record as Record>::Item<S>>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ModelEmaRecordItem {
+            model: self.model.into_item(),
+            step: self.step,
+            num_updates: self.num_updates,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            model: Record::from_item(item.model),
+            step: item.step,
+            num_updates: item.num_updates,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl ModelEmaConfig {
+    /// Initialize a new [model EMA](ModelEma) starting from the weights of the given model.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decay is not in the range `[0, 1]` or if the update interval is zero.
+    pub fn init<B: Backend, M: Module<B>>(&self, model: &M) -> ModelEma<B, M> {
+        assert!(
+            (0.0..=1.0).contains(&self.decay),
+            "The decay should be in the range [0, 1]."
+        );
+        assert!(
+            self.update_interval > 0,
+            "The update interval should be greater than zero."
+        );
+
+        ModelEma {
+            model: model.clone().no_grad(),
+            decay: self.decay,
+            warmup: self.warmup,
+            update_interval: self.update_interval,
+            step: 0,
+            num_updates: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend, M: Module<B>> ModelEma<B, M> {
+    /// Update the averaged weights with the weights of the given model.
+    ///
+    /// The weights are only updated every `update_interval` calls.
+    pub fn update(&mut self, model: &M) {
+        self.step += 1;
+
+        if self.step < self.update_interval {
+            return;
+        }
+        self.step = 0;
+
+        let mut collector = ParamsCollector::<B>::new(TensorContainer::new());
+        model.visit(&mut collector);
+
+        let mut mapper = EmaMapper::<B>::new(collector.params, self.current_decay());
+        self.model = self.model.clone().map(&mut mapper);
+        self.num_updates += 1;
+    }
+
+    /// The decay used for the next update of the weights.
+    pub fn current_decay(&self) -> f64 {
+        match self.warmup {
+            true => {
+                let num_updates = self.num_updates as f64;
+                f64::min(self.decay, (1.0 + num_updates) / (10.0 + num_updates))
+            }
+            false => self.decay,
+        }
+    }
+
+    /// Move the averaged model to the given device.
+    pub fn to_device(mut self, device: &B::Device) -> Self {
+        self.model = self.model.to_device(device);
+        self
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the EMA and return the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Get the current state of the EMA as a [record](Record).
+    pub fn to_record(&self) -> ModelEmaRecord<B, M> {
+        ModelEmaRecord {
+            model: self.model.clone().into_record(),
+            step: self.step,
+            num_updates: self.num_updates,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Load the state of the EMA from a [record](Record).
+    pub fn load_record(mut self, record: ModelEmaRecord<B, M>) -> Self {
+        self.model = self.model.load_record(record.model);
+        self.step = record.step;
+        self.num_updates = record.num_updates;
+        self
+    }
+}
+
+#[derive(new)]
+struct ParamsCollector<B: Backend> {
+    params: TensorContainer<ParamId>,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamsCollector<B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone().detach());
+    }
+}
+
+#[derive(new)]
+struct EmaMapper<B: Backend> {
+    params: TensorContainer<ParamId>,
+    decay: f64,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for EmaMapper<B> {
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B, D>(id) {
+            Some(param) => {
+                let param = param.to_device(&tensor.device());
+                tensor
+                    .mul_scalar(self.decay)
+                    .add(param.mul_scalar(1.0 - self.decay))
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn test_update_weights() {
+        let model = LinearConfig::new(4, 4).init();
+        let mut ema = ModelEmaConfig::new().with_decay(0.9).init(&model);
+
+        ema.update(&shifted(&model, 1.0));
+
+        let expected = model.weight.val().add_scalar(0.1);
+        ema.model()
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_update_interval_and_warmup() {
+        let model = LinearConfig::new(4, 4).init();
+        let mut ema = ModelEmaConfig::new()
+            .with_warmup(true)
+            .with_update_interval(2)
+            .init(&model);
+
+        ema.update(&shifted(&model, 1.0));
+        ema.model()
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.weight.to_data(), 5);
+        assert_eq!(ema.current_decay(), 0.1);
+
+        ema.update(&shifted(&model, 1.0));
+        let expected = model.weight.val().add_scalar(0.9);
+        ema.model()
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+        assert_eq!(ema.current_decay(), 2.0 / 11.0);
+    }
+
+    #[test]
+    fn test_not_track_gradients() {
+        let model: Linear<TestADBackend> = LinearConfig::new(4, 4).init();
+        let mut ema = ModelEmaConfig::new().init(&model);
+
+        ema.update(&model);
+
+        assert!(!ema.model().weight.is_require_grad());
+    }
+
+    #[test]
+    fn test_resume_from_recorded_state() {
+        let model = LinearConfig::new(4, 4).init();
+        let config = ModelEmaConfig::new().with_warmup(true);
+        let mut ema = config.init(&model);
+        ema.update(&shifted(&model, 1.0));
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(ema.to_record(), ()).unwrap();
+
+        let record = recorder.load(bytes).unwrap();
+        let ema_loaded = config.init(&model).load_record(record);
+
+        assert_eq!(ema_loaded.current_decay(), ema.current_decay());
+        ema_loaded
+            .model()
+            .weight
+            .to_data()
+            .assert_approx_eq(&ema.model().weight.to_data(), 5);
+    }
+
+    /// Returns a copy of the model, with the same parameter ids, where each weight is shifted by
+    /// the given value.
+    fn shifted(model: &Linear<TestBackend>, value: f32) -> Linear<TestBackend> {
+        struct Shift(f32);
+
+        impl ModuleMapper<TestBackend> for Shift {
+            fn map<const D: usize>(
+                &mut self,
+                _id: &ParamId,
+                tensor: Tensor<TestBackend, D>,
+            ) -> Tensor<TestBackend, D> {
+                tensor.add_scalar(self.0)
+            }
+        }
+
+        model.clone().map(&mut Shift(value))
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    adam::{AdaptiveMomentum, AdaptiveMomentumState},
+    group::ParamGroupSettings,
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// LAMB configuration.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for LAMB.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for LAMB.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay factor, added to the adaptive update before computing the trust
+    /// ratio.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// Whether biases and normalization parameters, i.e. parameters with a single dimension,
+    /// are excluded from weight decay and layer-wise adaptation.
+    #[config(default = true)]
+    exclude_biases_and_norms: bool,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LAMB optimizer as described in the paper
+/// [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The [Adam](super::Adam) update of each parameter is scaled by a layer-wise trust ratio, which
+/// is the ratio of the parameter norm to the update norm.
+pub struct Lamb<B: Backend> {
+    momentum: AdaptiveMomentum,
+    weight_decay: f32,
+    exclude_biases_and_norms: bool,
+    phantom: PhantomData<B>,
+}
+
+/// LAMB state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb<B> {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let state_momentum = state.map(|state| state.momentum);
+        let (mut update, state_momentum) = self.momentum.transform(grad, state_momentum);
+        let weight_decay = group
+            .weight_decay()
+            .map(|penalty| penalty as f32)
+            .unwrap_or(self.weight_decay);
+
+        if D > 1 || !self.exclude_biases_and_norms {
+            update = update.add(tensor.clone().mul_scalar(weight_decay));
+            update = update
+                .clone()
+                .mul(trust_ratio(l2_norm(tensor.clone()), l2_norm(update)));
+        }
+
+        let delta = update.mul_scalar(lr);
+
+        (tensor - delta, Some(LambState::new(state_momentum)))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl LambConfig {
+    /// Initialize LAMB optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lamb<B::InnerBackend>, M, B> {
+        let optim = Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+            exclude_biases_and_norms: self.exclude_biases_and_norms,
+            phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+/// Compute the L2 norm of a tensor.
+pub(crate) fn l2_norm<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powf(2.0).sum().sqrt()
+}
+
+/// Compute the ratio of the parameter norm to the update norm, falling back to one when either
+/// norm is zero.
+///
+/// The ratio is kept on the device and reshaped so that it can be broadcast to the parameter.
+pub(crate) fn trust_ratio<B: Backend, const D: usize>(
+    param_norm: Tensor<B, 1>,
+    update_norm: Tensor<B, 1>,
+) -> Tensor<B, D> {
+    param_norm
+        .clone()
+        .div(update_norm.clone())
+        .mask_fill(update_norm.equal_elem(0.0), 1.0)
+        .mask_fill(param_norm.equal_elem(0.0), 1.0)
+        .reshape([1; D])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_lamb();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), "/tmp/test_optim_lamb".into())
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lamb();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = LambConfig::new()
+            .with_epsilon(1e-6)
+            .with_beta_1(0.9)
+            .with_beta_2(0.999)
+            .with_weight_decay(0.01)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3255, 0.1325, 0.3994, 0.3151, 0.0810, 0.0622],
+                [0.0732, -0.0230, -0.3712, 0.2505, 0.1910, -0.2967],
+                [-0.0239, 0.0297, -0.3011, 0.2435, -0.2829, 0.3081],
+                [-0.3029, -0.2263, -0.3764, -0.3030, -0.0810, 0.1577],
+                [0.3251, -0.2231, 0.3668, -0.1778, 0.3747, -0.0353],
+                [-0.0208, -0.0169, 0.1209, 0.1872, 0.0244, 0.3784],
+            ]),
+            Data::from([-0.4105, 0.0684, -0.1170, 0.0976, 0.1166, -0.0070]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 3);
+        weight_updated.assert_approx_eq(&weight_expected, 3);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_lamb() -> OptimizerAdaptor<Lamb<TestBackend>, nn::Linear<TestADBackend>, TestADBackend>
+    {
+        let config = LambConfig::new();
+        Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: config.beta_1,
+                beta_2: config.beta_2,
+                epsilon: config.epsilon,
+            },
+            weight_decay: config.weight_decay,
+            exclude_biases_and_norms: config.exclude_biases_and_norms,
+            phantom: PhantomData,
+        }
+        .into()
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    group::ParamGroupSettings,
+    lamb::l2_norm,
+    momentum::{MomemtumState, Momentum, MomentumConfig},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// LARS configuration.
+#[derive(Config)]
+pub struct LarsConfig {
+    /// Trust coefficient, scaling the layer-wise learning rate.
+    #[config(default = 1e-3)]
+    trust_coefficient: f32,
+    /// Weight decay factor, added to the gradient before applying the layer-wise learning rate.
+    #[config(default = 1e-4)]
+    weight_decay: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// Whether biases and normalization parameters, i.e. parameters with a single dimension,
+    /// are excluded from weight decay and layer-wise adaptation.
+    #[config(default = true)]
+    exclude_biases_and_norms: bool,
+    /// [Momentum](MomentumConfig) config.
+    momentum: Option<MomentumConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LARS optimizer as described in the paper
+/// [Large Batch Training of Convolutional Networks](https://arxiv.org/abs/1708.03888).
+///
+/// The gradient of each parameter is scaled by a layer-wise learning rate, computed as
+/// `trust_coefficient * ||w|| / (||g|| + weight_decay * ||w||)`, before the momentum is applied.
+pub struct Lars<B: Backend> {
+    momentum: Option<Momentum<B>>,
+    trust_coefficient: f32,
+    weight_decay: f32,
+    epsilon: f32,
+    exclude_biases_and_norms: bool,
+}
+
+/// LARS state.
+#[derive(Record, Clone, new)]
+pub struct LarsState<B: Backend, const D: usize> {
+    momentum: Option<MomemtumState<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lars<B> {
+    type State<const D: usize> = LarsState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_momemtum = state.and_then(|state| state.momentum);
+        let weight_decay = group
+            .weight_decay()
+            .map(|penalty| penalty as f32)
+            .unwrap_or(self.weight_decay);
+
+        if D > 1 || !self.exclude_biases_and_norms {
+            let local_lr =
+                self.local_lr(l2_norm(tensor.clone()), l2_norm(grad.clone()), weight_decay);
+            grad = grad
+                .add(tensor.clone().mul_scalar(weight_decay))
+                .mul(local_lr);
+        }
+
+        if let Some(momentum) = &self.momentum {
+            let (grad_out, state) = momentum.transform(grad, state_momemtum);
+            state_momemtum = Some(state);
+            grad = grad_out;
+        }
+
+        let delta = grad.mul_scalar(lr);
+
+        (tensor - delta, Some(LarsState::new(state_momemtum)))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.map(|state| state.to_device(device));
+        state
+    }
+}
+
+impl<B: Backend> Lars<B> {
+    /// Compute the layer-wise learning rate, falling back to one when either norm is zero.
+    fn local_lr<const D: usize>(
+        &self,
+        param_norm: Tensor<B, 1>,
+        grad_norm: Tensor<B, 1>,
+        weight_decay: f32,
+    ) -> Tensor<B, D> {
+        let denominator = grad_norm
+            .clone()
+            .add(param_norm.clone().mul_scalar(weight_decay))
+            .add_scalar(self.epsilon);
+
+        param_norm
+            .clone()
+            .mul_scalar(self.trust_coefficient)
+            .div(denominator)
+            .mask_fill(grad_norm.equal_elem(0.0), 1.0)
+            .mask_fill(param_norm.equal_elem(0.0), 1.0)
+            .reshape([1; D])
+    }
+}
+
+impl LarsConfig {
+    /// Initialize LARS optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lars<B::InnerBackend>, M, B> {
+        let optim = Lars {
+            momentum: self.momentum.as_ref().map(Momentum::new),
+            trust_coefficient: self.trust_coefficient,
+            weight_decay: self.weight_decay,
+            epsilon: self.epsilon,
+            exclude_biases_and_norms: self.exclude_biases_and_norms,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_lars_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_lars();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), "/tmp/test_optim_lars".into())
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lars();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lars_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = LarsConfig::new()
+            .with_trust_coefficient(0.1)
+            .with_weight_decay(0.01)
+            .with_momentum(Some(MomentumConfig::new()))
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3275, 0.1305, 0.3973, 0.3130, 0.0790, 0.0602],
+                [0.0736, -0.0226, -0.3708, 0.2509, 0.1914, -0.2963],
+                [-0.0274, 0.0262, -0.3046, 0.2400, -0.2864, 0.3046],
+                [-0.3057, -0.2291, -0.3792, -0.3058, -0.0838, 0.1548],
+                [0.3244, -0.2237, 0.3661, -0.1784, 0.3740, -0.0360],
+                [-0.0227, -0.0188, 0.1190, 0.1853, 0.0225, 0.3765],
+            ]),
+            Data::from([-0.9505, -0.4716, -0.6570, -0.4424, -0.4234, -0.5470]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 3);
+        weight_updated.assert_approx_eq(&weight_expected, 3);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_lars() -> OptimizerAdaptor<Lars<TestBackend>, nn::Linear<TestADBackend>, TestADBackend>
+    {
+        LarsConfig::new()
+            .with_momentum(Some(MomentumConfig::new()))
+            .init()
+    }
+}
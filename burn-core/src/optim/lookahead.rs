@@ -0,0 +1,410 @@
+use crate as burn;
+
+use super::{GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::module::{ADModule, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::{backend::ADBackend, Tensor};
+use crate::LearningRate;
+use burn_tensor::backend::Backend;
+use core::any::Any;
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create the [Lookahead](Lookahead) optimizer.
+#[derive(Config)]
+pub struct LookaheadConfig {
+    /// The number of steps of the inner optimizer between two synchronizations.
+    #[config(default = 5)]
+    k: usize,
+    /// The interpolation factor applied when moving the slow weights toward the fast weights.
+    #[config(default = 0.5)]
+    alpha: f64,
+}
+
+/// Lookahead optimizer as described in the paper
+/// [Lookahead Optimizer: k steps forward, 1 step back](https://arxiv.org/abs/1907.08610).
+///
+/// The inner optimizer updates the fast weights, while a copy of the slow weights is kept for each
+/// parameter. Every `k` steps, the slow weights are moved toward the fast weights by a factor `alpha`, and the
+/// fast weights are reset to the slow weights.
+///
+/// Any [optimizer](Optimizer) can be wrapped, including other wrappers.
+pub struct Lookahead<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    optim: O,
+    k: usize,
+    alpha: f64,
+    step: usize,
+    slow: HashMap<ParamId, SlowWeights<B::InnerBackend>>,
+    phantom: PhantomData<M>,
+}
+
+impl LookaheadConfig {
+    /// Wrap the given optimizer with [Lookahead](Lookahead).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero or if `alpha` is not in the range `[0, 1]`.
+    pub fn init<B, M, O>(&self, optim: O) -> Lookahead<O, M, B>
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+        O: Optimizer<M, B>,
+    {
+        assert!(
+            self.k > 0,
+            "The number of steps k should be greater than zero."
+        );
+        assert!(
+            (0.0..=1.0).contains(&self.alpha),
+            "The interpolation factor alpha should be in the range [0, 1]."
+        );
+
+        Lookahead {
+            optim,
+            k: self.k,
+            alpha: self.alpha,
+            step: 0,
+            slow: HashMap::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<O, M, B> Lookahead<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    /// The inner optimizer.
+    pub fn inner(&self) -> &O {
+        &self.optim
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for Lookahead<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    type Record = (
+        O::Record,
+        HashMap<ParamId, SlowWeights<B::InnerBackend>>,
+        usize,
+    );
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        // The slow weights start from the weights of the parameters seen for the first time.
+        let mut initializer = SlowWeightsInitializer::<B>::new(&mut self.slow);
+        module.visit(&mut initializer);
+
+        let module = self.optim.step(lr, module, grads);
+        self.step += 1;
+
+        if self.step < self.k {
+            return module;
+        }
+        self.step = 0;
+
+        let mut mapper = SlowWeightsMapper::<B>::new(&mut self.slow, self.alpha);
+        module.map(&mut mapper)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.optim.to_record(), self.slow.clone(), self.step)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let (optim, slow, step) = record;
+
+        self.optim = self.optim.load_record(optim);
+        self.slow = slow;
+        self.step = step;
+        self
+    }
+
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        self.optim.grad_norm()
+    }
+
+    fn has_grad_clipping(&self) -> bool {
+        self.optim.has_grad_clipping()
+    }
+}
+
+/// The slow weights of a parameter kept by the [Lookahead](Lookahead) optimizer.
+#[derive(Clone)]
+pub enum SlowWeights<B: Backend> {
+    /// Rank 1.
+    Rank1(Tensor<B, 1>),
+
+    /// Rank 2.
+    Rank2(Tensor<B, 2>),
+
+    /// Rank 3.
+    Rank3(Tensor<B, 3>),
+
+    /// Rank 4.
+    Rank4(Tensor<B, 4>),
+
+    /// Rank 5.
+    Rank5(Tensor<B, 5>),
+
+    /// Rank 6.
+    Rank6(Tensor<B, 6>),
+
+    /// Rank 7.
+    Rank7(Tensor<B, 7>),
+
+    /// Rank 8.
+    Rank8(Tensor<B, 8>),
+}
+
+/// [Slow weights](SlowWeights) record item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum SlowWeightsItem<B: Backend, S: PrecisionSettings> {
+    /// Rank 1.
+    Rank1(<Tensor<B, 1> as Record>::Item<S>),
+
+    /// Rank 2.
+    Rank2(<Tensor<B, 2> as Record>::Item<S>),
+
+    /// Rank 3.
+    Rank3(<Tensor<B, 3> as Record>::Item<S>),
+
+    /// Rank 4.
+    Rank4(<Tensor<B, 4> as Record>::Item<S>),
+
+    /// Rank 5.
+    Rank5(<Tensor<B, 5> as Record>::Item<S>),
+
+    /// Rank 6.
+    Rank6(<Tensor<B, 6> as Record>::Item<S>),
+
+    /// Rank 7.
+    Rank7(<Tensor<B, 7> as Record>::Item<S>),
+
+    /// Rank 8.
+    Rank8(<Tensor<B, 8> as Record>::Item<S>),
+}
+
+impl<B: Backend> SlowWeights<B> {
+    /// Convert the slow weights into a tensor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of the tensor doesn't match the one of the slow weights.
+    pub fn into_tensor<const D: usize>(self) -> Tensor<B, D> {
+        let boxed: Box<dyn Any> = match self {
+            SlowWeights::Rank1(tensor) => Box::new(tensor),
+            SlowWeights::Rank2(tensor) => Box::new(tensor),
+            SlowWeights::Rank3(tensor) => Box::new(tensor),
+            SlowWeights::Rank4(tensor) => Box::new(tensor),
+            SlowWeights::Rank5(tensor) => Box::new(tensor),
+            SlowWeights::Rank6(tensor) => Box::new(tensor),
+            SlowWeights::Rank7(tensor) => Box::new(tensor),
+            SlowWeights::Rank8(tensor) => Box::new(tensor),
+        };
+        *boxed
+            .downcast::<Tensor<B, D>>()
+            .expect("The slow weights should have the dimension of the parameter.")
+    }
+
+    /// Create the slow weights from a tensor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tensor has more than 8 dimensions.
+    pub fn from_tensor<const D: usize>(tensor: Tensor<B, D>) -> Self {
+        let tensor: Box<dyn Any> = Box::new(tensor);
+
+        match D {
+            1 => SlowWeights::Rank1(*tensor.downcast().unwrap()),
+            2 => SlowWeights::Rank2(*tensor.downcast().unwrap()),
+            3 => SlowWeights::Rank3(*tensor.downcast().unwrap()),
+            4 => SlowWeights::Rank4(*tensor.downcast().unwrap()),
+            5 => SlowWeights::Rank5(*tensor.downcast().unwrap()),
+            6 => SlowWeights::Rank6(*tensor.downcast().unwrap()),
+            7 => SlowWeights::Rank7(*tensor.downcast().unwrap()),
+            8 => SlowWeights::Rank8(*tensor.downcast().unwrap()),
+            _ => panic!("Unsupported tensor dimension, dimension up to 8 are supported."),
+        }
+    }
+}
+
+impl<B: Backend> Record for SlowWeights<B> {
+    type Item<S: PrecisionSettings> = SlowWeightsItem<B, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        match self {
+            SlowWeights::Rank1(tensor) => SlowWeightsItem::Rank1(tensor.into_item()),
+            SlowWeights::Rank2(tensor) => SlowWeightsItem::Rank2(tensor.into_item()),
+            SlowWeights::Rank3(tensor) => SlowWeightsItem::Rank3(tensor.into_item()),
+            SlowWeights::Rank4(tensor) => SlowWeightsItem::Rank4(tensor.into_item()),
+            SlowWeights::Rank5(tensor) => SlowWeightsItem::Rank5(tensor.into_item()),
+            SlowWeights::Rank6(tensor) => SlowWeightsItem::Rank6(tensor.into_item()),
+            SlowWeights::Rank7(tensor) => SlowWeightsItem::Rank7(tensor.into_item()),
+            SlowWeights::Rank8(tensor) => SlowWeightsItem::Rank8(tensor.into_item()),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        match item {
+            SlowWeightsItem::Rank1(item) => SlowWeights::Rank1(Tensor::from_item(item)),
+            SlowWeightsItem::Rank2(item) => SlowWeights::Rank2(Tensor::from_item(item)),
+            SlowWeightsItem::Rank3(item) => SlowWeights::Rank3(Tensor::from_item(item)),
+            SlowWeightsItem::Rank4(item) => SlowWeights::Rank4(Tensor::from_item(item)),
+            SlowWeightsItem::Rank5(item) => SlowWeights::Rank5(Tensor::from_item(item)),
+            SlowWeightsItem::Rank6(item) => SlowWeights::Rank6(Tensor::from_item(item)),
+            SlowWeightsItem::Rank7(item) => SlowWeights::Rank7(Tensor::from_item(item)),
+            SlowWeightsItem::Rank8(item) => SlowWeights::Rank8(Tensor::from_item(item)),
+        }
+    }
+}
+
+#[derive(new)]
+struct SlowWeightsInitializer<'a, B: ADBackend> {
+    slow: &'a mut HashMap<ParamId, SlowWeights<B::InnerBackend>>,
+}
+
+impl<'a, B: ADBackend> ModuleVisitor<B> for SlowWeightsInitializer<'a, B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if !self.slow.contains_key(id) {
+            let slow = SlowWeights::from_tensor(tensor.clone().inner());
+            self.slow.insert(id.clone(), slow);
+        }
+    }
+}
+
+#[derive(new)]
+struct SlowWeightsMapper<'a, B: ADBackend> {
+    slow: &'a mut HashMap<ParamId, SlowWeights<B::InnerBackend>>,
+    alpha: f64,
+}
+
+impl<'a, B: ADBackend> ModuleMapper<B> for SlowWeightsMapper<'a, B> {
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let slow = match self.slow.remove(id) {
+            Some(slow) => slow.into_tensor::<D>(),
+            None => return tensor,
+        };
+
+        let is_require_grad = tensor.is_require_grad();
+        let fast = tensor.inner();
+        let slow = slow.to_device(&fast.device());
+        let slow = slow.clone().add(fast.sub(slow).mul_scalar(self.alpha));
+        self.slow
+            .insert(id.clone(), SlowWeights::from_tensor(slow.clone()));
+
+        let mut tensor = Tensor::from_inner(slow);
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::{adaptor::OptimizerAdaptor, Adam, AdamConfig, SgdConfig};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::{TestADBackend, TestBackend};
+
+    type TestLookahead = Lookahead<
+        OptimizerAdaptor<Adam<TestBackend>, Linear<TestADBackend>, TestADBackend>,
+        Linear<TestADBackend>,
+        TestADBackend,
+    >;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_interpolate_slow_weights_every_k_steps() {
+        let linear = LinearConfig::new(4, 4).init();
+        let mut optim = LookaheadConfig::new()
+            .with_k(2)
+            .with_alpha(0.5)
+            .init(SgdConfig::new().init());
+        let weight_init = weight(&linear);
+
+        let (linear, grad_1) = step(&mut optim, linear);
+        let expected = weight_init
+            .clone()
+            .sub(grad_1.clone().mul_scalar(LEARNING_RATE));
+        weight(&linear)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+
+        let (linear, grad_2) = step(&mut optim, linear);
+        let expected = weight_init.sub(grad_1.add(grad_2).mul_scalar(LEARNING_RATE * 0.5));
+        weight(&linear)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+        assert!(linear.weight.is_require_grad());
+    }
+
+    #[test]
+    fn test_resume_from_recorded_state() {
+        let linear = LinearConfig::new(4, 4).init();
+        let config = LookaheadConfig::new().with_k(3);
+        let mut optim: TestLookahead = config.init(AdamConfig::new().init());
+        let (linear, _) = step(&mut optim, linear);
+        let id = linear.weight.id.clone();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(optim.to_record(), ()).unwrap();
+
+        let record = recorder.load(bytes).unwrap();
+        let optim_loaded: TestLookahead = config.init(AdamConfig::new().init());
+        let mut optim_loaded = optim_loaded.load_record(record);
+        let (linear_loaded, _) = step(&mut optim_loaded, linear.clone());
+        let (_, _) = step(&mut optim_loaded, linear_loaded);
+        let (linear, _) = step(&mut optim, linear);
+        let (_, _) = step(&mut optim, linear);
+
+        let (inner, slow, step) = optim_loaded.to_record();
+        let (_, slow_expected, _) = optim.to_record();
+        assert_eq!(step, 0);
+        assert_eq!(inner.len(), 2);
+        assert_eq!(slow.len(), 2);
+        slow.get(&id)
+            .unwrap()
+            .clone()
+            .into_tensor::<2>()
+            .into_data()
+            .assert_approx_eq(
+                &slow_expected
+                    .get(&id)
+                    .unwrap()
+                    .clone()
+                    .into_tensor::<2>()
+                    .into_data(),
+                5,
+            );
+    }
+
+    fn step<O: Optimizer<Linear<TestADBackend>, TestADBackend>>(
+        optim: &mut O,
+        linear: Linear<TestADBackend>,
+    ) -> (Linear<TestADBackend>, Tensor<TestBackend, 2>) {
+        let x = Tensor::<TestADBackend, 2>::ones([2, 4]);
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let grad = grads.get(&linear.weight.id).unwrap();
+
+        (optim.step(LEARNING_RATE, linear, grads), grad)
+    }
+
+    fn weight(linear: &Linear<TestADBackend>) -> Tensor<TestBackend, 2> {
+        linear.weight.val().inner()
+    }
+}
@@ -4,17 +4,35 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adadelta;
+mod adagrad;
 mod adam;
+mod adamw;
 mod base;
+mod centralization;
+mod ema;
 mod grad_accum;
 mod grads;
+mod lamb;
+mod lars;
+mod lookahead;
+mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+pub use adadelta::*;
+pub use adagrad::*;
 pub use adam::*;
+pub use adamw::*;
 pub use base::*;
+pub use centralization::*;
+pub use ema::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lamb::*;
+pub use lars::*;
+pub use lookahead::*;
+pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
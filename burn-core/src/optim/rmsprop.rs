@@ -0,0 +1,346 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
+    group::ParamGroupSettings,
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// RMSProp configuration.
+#[derive(Config)]
+pub struct RmsPropConfig {
+    /// Smoothing constant of the moving average of the squared gradients.
+    #[config(default = 0.99)]
+    alpha: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-5)]
+    epsilon: f32,
+    /// If true, the gradient is normalized by an estimation of its variance instead of its
+    /// uncentered second moment.
+    #[config(default = false)]
+    centered: bool,
+    /// Momentum factor, no momentum buffer is kept when set to zero.
+    #[config(default = 0.0)]
+    momentum: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// RMSProp optimizer as described in the lecture notes [Neural Networks for Machine Learning](https://www.cs.toronto.edu/~tijmen/csc321/slides/lecture_slides_lec6.pdf).
+///
+/// The centered version is described in [Generating Sequences With Recurrent Neural Networks](https://arxiv.org/pdf/1308.0850.pdf).
+pub struct RmsProp<B: Backend> {
+    alpha: f32,
+    epsilon: f32,
+    centered: bool,
+    momentum: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// RMSProp state.
+#[derive(Record, Clone, new)]
+pub struct RmsPropState<B: Backend, const D: usize> {
+    weight_decay: Option<WeightDecayState<B, D>>,
+    square_avg: Tensor<B, D>,
+    grad_avg: Option<Tensor<B, D>>,
+    momentum_buffer: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for RmsProp<B> {
+    type State<const D: usize> = RmsPropState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_weight_decay = None;
+        let mut square_avg = None;
+        let mut grad_avg = None;
+        let mut momentum_buffer = None;
+
+        if let Some(state) = state {
+            state_weight_decay = state.weight_decay;
+            square_avg = Some(state.square_avg);
+            grad_avg = state.grad_avg;
+            momentum_buffer = state.momentum_buffer;
+        }
+
+        let group_weight_decay = group.override_weight_decay();
+        if let Some(weight_decay) = group_weight_decay.as_ref().or(self.weight_decay.as_ref()) {
+            let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
+            state_weight_decay = Some(state);
+            grad = grad_out;
+        }
+
+        let factor = 1.0 - self.alpha;
+        let grad_squared = grad.clone().powf(2.0).mul_scalar(factor);
+        let square_avg = match square_avg {
+            Some(square_avg) => square_avg.mul_scalar(self.alpha).add(grad_squared),
+            None => grad_squared,
+        };
+
+        let mut avg = square_avg.clone();
+
+        let grad_avg = match self.centered {
+            true => {
+                let grad_scaled = grad.clone().mul_scalar(factor);
+                let grad_avg = match grad_avg {
+                    Some(grad_avg) => grad_avg.mul_scalar(self.alpha).add(grad_scaled),
+                    None => grad_scaled,
+                };
+                avg = avg.sub(grad_avg.clone().powf(2.0));
+                Some(grad_avg)
+            }
+            false => None,
+        };
+
+        let avg = avg.sqrt().add_scalar(self.epsilon);
+        let mut delta = grad.div(avg);
+
+        let momentum_buffer = match self.momentum > 0.0 {
+            true => {
+                let buffer = match momentum_buffer {
+                    Some(buffer) => buffer.mul_scalar(self.momentum).add(delta),
+                    None => delta,
+                };
+                delta = buffer.clone();
+                Some(buffer)
+            }
+            false => None,
+        };
+
+        let state = RmsPropState::new(state_weight_decay, square_avg, grad_avg, momentum_buffer);
+        let delta = delta.mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.weight_decay = state.weight_decay.map(|state| state.to_device(device));
+        state.square_avg = state.square_avg.to_device(device);
+        state.grad_avg = state.grad_avg.map(|tensor| tensor.to_device(device));
+        state.momentum_buffer = state.momentum_buffer.map(|tensor| tensor.to_device(device));
+        state
+    }
+}
+
+impl RmsPropConfig {
+    /// Initialize RMSProp optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<RmsProp<B::InnerBackend>, M, B> {
+        let optim = RmsProp {
+            alpha: self.alpha,
+            epsilon: self.epsilon,
+            centered: self.centered,
+            momentum: self.momentum,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_rmsprop_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Standard);
+        let mut optimizer = create_rmsprop();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(optimizer.to_record(), "/tmp/test_optim_rmsprop".into())
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_loaded = recorder.load("/tmp/test_optim_rmsprop".into()).unwrap();
+        let optimizer = create_rmsprop();
+        let optimizer = optimizer.load_record(state_optim_loaded);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_rmsprop_optimizer_with_numbers() {
+        let (linear, x_1, x_2) = given_linear_layer_and_inputs();
+        let mut optimizer = RmsPropConfig::new()
+            .with_alpha(0.99)
+            .with_epsilon(1e-8)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        assert_linear_record_approx_eq(
+            linear.into_record(),
+            given_linear_record(
+                Data::from([
+                    [-0.4866, -0.0286, 0.2383, 0.1540, -0.0801, -0.0989],
+                    [-0.0458, -0.1420, -0.4902, 0.1315, 0.0720, -0.4157],
+                    [-0.1861, -0.1325, -0.4633, 0.0813, -0.4451, 0.1459],
+                    [-0.4677, -0.3911, -0.5412, -0.4678, -0.2458, -0.0071],
+                    [0.1683, -0.3799, 0.2100, -0.3346, 0.2179, -0.1921],
+                    [-0.1822, -0.1783, -0.0405, 0.0258, -0.1370, 0.2170],
+                ]),
+                Data::from([-0.5614, -0.0825, -0.2679, -0.0533, -0.0343, -0.1579]),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_rmsprop_centered_with_momentum_optimizer_with_numbers() {
+        let (linear, x_1, x_2) = given_linear_layer_and_inputs();
+        let mut optimizer = RmsPropConfig::new()
+            .with_alpha(0.99)
+            .with_epsilon(1e-8)
+            .with_centered(true)
+            .with_momentum(0.9)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        assert_linear_record_approx_eq(
+            linear.into_record(),
+            given_linear_record(
+                Data::from([
+                    [-0.5782, -0.1202, 0.1467, 0.0624, -0.1717, -0.1905],
+                    [-0.1369, -0.2331, -0.5813, 0.0404, -0.0191, -0.5068],
+                    [-0.2778, -0.2242, -0.5550, -0.0104, -0.5368, 0.0542],
+                    [-0.5594, -0.4828, -0.6329, -0.5595, -0.3375, -0.0988],
+                    [0.0767, -0.4715, 0.1184, -0.4262, 0.1263, -0.2837],
+                    [-0.2738, -0.2699, -0.1321, -0.0658, -0.2286, 0.1254],
+                ]),
+                Data::from([-0.6531, -0.1742, -0.3596, -0.1450, -0.1260, -0.2496]),
+            ),
+        );
+    }
+
+    fn given_linear_layer_and_inputs() -> (
+        nn::Linear<TestADBackend>,
+        Tensor<TestADBackend, 2>,
+        Tensor<TestADBackend, 2>,
+    ) {
+        let linear = nn::LinearConfig::new(6, 6)
+            .init()
+            .load_record(given_linear_record(
+                Data::from([
+                    [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                    [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                    [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                    [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                    [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                    [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+                ]),
+                Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+            ));
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        (linear, x_1, x_2)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn assert_linear_record_approx_eq(
+        updated: nn::LinearRecord<TestADBackend>,
+        expected: nn::LinearRecord<TestADBackend>,
+    ) {
+        updated
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected.bias.unwrap().to_data(), 3);
+        updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 3);
+    }
+
+    fn create_rmsprop(
+    ) -> OptimizerAdaptor<RmsProp<TestBackend>, nn::Linear<TestADBackend>, TestADBackend> {
+        let config = RmsPropConfig::new().with_centered(true).with_momentum(0.9);
+        RmsProp {
+            alpha: config.alpha,
+            epsilon: config.epsilon,
+            centered: config.centered,
+            momentum: config.momentum,
+            weight_decay: config.weight_decay.as_ref().map(WeightDecay::new),
+        }
+        .into()
+    }
+}
@@ -4,7 +4,7 @@ use crate::{self as burn, LearningRate};
 
 use super::decay::{WeightDecay, WeightDecayConfig, WeightDecayState};
 use super::momentum::{MomemtumState, Momentum, MomentumConfig};
-use super::SimpleOptimizer;
+use super::{group::ParamGroupSettings, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::record::Record;
@@ -60,11 +60,22 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
     type State<const D: usize> = SgdState<B, D>;
 
     fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_with_group(lr, tensor, grad, state, &ParamGroupSettings::default())
+    }
+
+    fn step_with_group<const D: usize>(
         &self,
         lr: LearningRate,
         tensor: Tensor<B, D>,
         mut grad: Tensor<B, D>,
         state: Option<Self::State<D>>,
+        group: &ParamGroupSettings,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
         let mut state_weight_decay = None;
         let mut state_momemtum = None;
@@ -74,7 +85,8 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
             state_momemtum = state.momentum;
         }
 
-        if let Some(weight_decay) = &self.weight_decay {
+        let group_weight_decay = group.override_weight_decay();
+        if let Some(weight_decay) = group_weight_decay.as_ref().or(self.weight_decay.as_ref()) {
             let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
             state_weight_decay = Some(state);
             grad = grad_out;
@@ -136,7 +148,24 @@ mod tests {
     #[test]
     fn can_attach_gradient_clipping() {
         let optim = sgd_with_all().with_grad_clipping(GradientClipping::Value(0.5));
-        assert!(optim.has_gradient_clipping());
+        assert!(optim.has_grad_clipping());
+    }
+
+    #[test]
+    fn should_report_the_gradient_norm_when_clipping() {
+        let layer = layer();
+        let mut optim = sgd_with_all();
+        let grads = GradientsParams::from_grads(layer.forward(random_tensor()).backward(), &layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+        assert!(optim.grad_norm().is_none());
+
+        let mut optim = optim.with_grad_clipping(GradientClipping::GlobalNorm(0.5));
+        let grads = GradientsParams::from_grads(layer.forward(random_tensor()).backward(), &layer);
+        let norm_expected = GradientClipping::global_norm(&layer, &grads).into_scalar();
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        let norm = optim.grad_norm().unwrap().into_scalar();
+        assert!((norm - norm_expected).abs() < 1e-4);
     }
 
     #[test]
@@ -1,10 +1,19 @@
-use super::{record::AdaptorRecord, SimpleOptimizer};
+use super::{
+    group::{assign_param_groups, ParamGroup, ParamGroupSettings},
+    record::AdaptorRecord,
+    SimpleOptimizer,
+};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{ADModule, ModuleMapper, ParamId},
+    module::{ADModule, Module, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
     LearningRate,
 };
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
 use burn_tensor::{backend::ADBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -21,6 +30,10 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    groups: Vec<ParamGroup>,
+    assignments: Option<HashMap<ParamId, usize>>,
+    restored_groups: HashMap<ParamId, Option<String>>,
+    grad_norm: Option<Tensor<B::InnerBackend, 1>>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -35,6 +48,10 @@ where
             records: HashMap::new(),
             module: PhantomData::default(),
             grad_clipping: None,
+            groups: Vec::new(),
+            assignments: None,
+            restored_groups: HashMap::new(),
+            grad_norm: None,
         }
     }
 }
@@ -59,9 +76,23 @@ where
         self
     }
 
-    #[cfg(test)]
-    pub(crate) fn has_gradient_clipping(&self) -> bool {
-        self.grad_clipping.is_some()
+    /// Adds a [parameter group](ParamGroup) with its own learning rate multiplier and weight
+    /// decay.
+    ///
+    /// Groups are evaluated in the order they are added, a parameter belongs to the first group
+    /// that contains it.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_param_group(mut self, group: ParamGroup) -> Self {
+        self.groups.push(group);
+        self.assignments = None;
+        self
     }
 }
 
@@ -73,13 +104,28 @@ where
 {
     type Record = HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>;
 
-    fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let mut grads = match &self.grad_clipping {
+            Some(grad_clipping) => {
+                let (grads, norm) = grad_clipping.clip_gradients_with_norm(&module, grads);
+                self.grad_norm = Some(norm);
+                grads
+            }
+            None => grads,
+        };
+        // The groups are assigned once, the module structure doesn't change between steps.
+        let groups = &self.groups;
+        let restored_groups = &mut self.restored_groups;
+        let assignments = self.assignments.get_or_insert_with(|| {
+            init_param_groups(&module, groups, core::mem::take(restored_groups))
+        });
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
-            self.grad_clipping.as_ref(),
+            &self.groups,
+            assignments,
         );
         module.map(&mut mapper)
     }
@@ -89,9 +135,55 @@ where
     }
 
     fn load_record(mut self, record: Self::Record) -> Self {
+        // Only the second version of the record knows the group of each parameter.
+        self.restored_groups = record
+            .iter()
+            .filter_map(|(id, record)| match record {
+                AdaptorRecord::V1(_) => None,
+                AdaptorRecord::V2(record) => Some((id.clone(), record.group.clone())),
+            })
+            .collect();
+        self.assignments = None;
         self.records = record;
         self
     }
+
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        self.grad_norm.clone()
+    }
+
+    fn has_grad_clipping(&self) -> bool {
+        self.grad_clipping.is_some()
+    }
+}
+
+/// Assigns each parameter to the configured group it matches.
+///
+/// The groups saved in a loaded record never override the configured ones. A warning is logged
+/// for the groups of the record that aren't registered anymore, e.g. after being renamed or
+/// removed, since their parameters fall back to the configured groups.
+fn init_param_groups<B: ADBackend, M: Module<B>>(
+    module: &M,
+    groups: &[ParamGroup],
+    restored_groups: HashMap<ParamId, Option<String>>,
+) -> HashMap<ParamId, usize> {
+    let missing_groups = restored_groups
+        .into_values()
+        .flatten()
+        .filter(|name| !groups.iter().any(|group| group.name() == name))
+        .collect::<BTreeSet<_>>();
+
+    for name in missing_groups {
+        log::warn!(
+            "The parameter group '{name}' of the loaded record isn't registered on the \
+             optimizer, its parameters are assigned to the configured groups."
+        );
+    }
+
+    match groups.is_empty() {
+        true => HashMap::new(),
+        false => assign_param_groups(module, groups),
+    }
 }
 
 #[derive(new)]
@@ -106,7 +198,8 @@ where
     grads: &'a mut GradientsParams,
     lr: LearningRate,
     phantom: PhantomData<M>,
-    grad_clipping: Option<&'a GradientClipping>,
+    groups: &'a [ParamGroup],
+    assignments: &'a HashMap<ParamId, usize>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -123,24 +216,32 @@ where
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
 
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
+            let group = self.assignments.get(id).map(|index| &self.groups[*index]);
+            let (lr, settings) = match group {
+                Some(group) => (self.lr * group.lr_multiplier(), group.settings()),
+                None => (self.lr, ParamGroupSettings::default()),
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (tensor, state) = self.optimizer.step_with_group(
+                lr,
                 tensor.inner(),
-                clipped_grad,
-                record.map(|record| O::to_device(record.into_state(), &device)),
+                grad,
+                record
+                    .and_then(|record| record.try_into_state())
+                    .map(|state| O::to_device(state, &device)),
+                &settings,
             );
 
-            if let Some(state) = state {
-                self.records.insert(
-                    key.unwrap_or_else(|| id.clone()),
-                    AdaptorRecord::from_state(state),
-                );
+            // The group is kept even without any state, e.g. with stateless optimizers.
+            let group = group.map(|group| group.name().to_string());
+            let record = match (state, group) {
+                (Some(state), group) => Some(AdaptorRecord::from_state(state).with_group(group)),
+                (None, Some(group)) => Some(AdaptorRecord::from_group(Some(group))),
+                (None, None) => None,
+            };
+            if let Some(record) = record {
+                self.records
+                    .insert(key.unwrap_or_else(|| id.clone()), record);
             }
 
             let mut tensor = Tensor::from_inner(tensor);
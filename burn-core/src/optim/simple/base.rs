@@ -1,3 +1,4 @@
+use super::group::ParamGroupSettings;
 use crate::{record::Record, LearningRate};
 use burn_tensor::{backend::Backend, Tensor};
 
@@ -25,6 +26,25 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// The optimizer step of a tensor belonging to a [parameter group](super::group::ParamGroup).
+    ///
+    /// The settings of the group take precedence over the ones of the optimizer, e.g. its weight
+    /// decay replaces the weight decay of the optimizer. The learning rate is already adjusted
+    /// for the group.
+    ///
+    /// The default implementation ignores the settings of the group and calls
+    /// [step](SimpleOptimizer::step), optimizers with a weight decay should override it.
+    fn step_with_group<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+        _group: &ParamGroupSettings,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step(lr, tensor, grad, state)
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordindly to have the state on the same device as the
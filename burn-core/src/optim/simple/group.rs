@@ -0,0 +1,472 @@
+use crate::{
+    module::{Module, ModuleVisitor, ParamId},
+    optim::decay::{WeightDecay, WeightDecayConfig},
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+
+/// Function used to select the parameters of a [group](ParamGroup) based on their module path
+/// and [id](ParamId).
+pub type ParamGroupPredicate = Box<dyn Fn(&str, &ParamId) -> bool + Send + Sync>;
+
+enum ParamGroupSelector {
+    Path(String),
+    Predicate(ParamGroupPredicate),
+}
+
+/// A group of parameters optimized with their own settings.
+///
+/// Parameters are selected either by their module path, which is the dot separated list of field
+/// names leading to the parameter (e.g. `backbone.layers.0.weight`), or by a predicate.
+///
+/// Groups are registered on an [optimizer adaptor](super::adaptor::OptimizerAdaptor) and are
+/// evaluated in order, the first matching group is used. Parameters that don't match any group
+/// are optimized with the default settings.
+pub struct ParamGroup {
+    name: String,
+    selector: ParamGroupSelector,
+    lr_multiplier: f64,
+    weight_decay: Option<f64>,
+}
+
+impl ParamGroup {
+    /// Creates a group containing every parameter under the given module path.
+    ///
+    /// The path is matched on whole components, so `encoder` matches `encoder.linear.weight`
+    /// but not `encoder_2.linear.weight`.
+    pub fn from_path<N: Into<String>, P: Into<String>>(name: N, path: P) -> Self {
+        Self::new(name.into(), ParamGroupSelector::Path(path.into()))
+    }
+
+    /// Creates a group containing every parameter for which the predicate returns true.
+    ///
+    /// The predicate receives the module path of the parameter and its [id](ParamId).
+    pub fn from_predicate<N, F>(name: N, predicate: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn(&str, &ParamId) -> bool + Send + Sync + 'static,
+    {
+        Self::new(
+            name.into(),
+            ParamGroupSelector::Predicate(Box::new(predicate)),
+        )
+    }
+
+    fn new(name: String, selector: ParamGroupSelector) -> Self {
+        Self {
+            name,
+            selector,
+            lr_multiplier: 1.0,
+            weight_decay: None,
+        }
+    }
+
+    /// Sets the factor applied to the learning rate for the parameters of the group.
+    pub fn with_lr_multiplier(mut self, lr_multiplier: f64) -> Self {
+        self.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Sets the weight decay of the group.
+    ///
+    /// The penalty replaces the weight decay penalty configured on the optimizer for the
+    /// parameters of the group, and is applied the way the optimizer applies its own decay. A
+    /// penalty of zero disables the weight decay, e.g. for biases and normalization parameters.
+    pub fn with_weight_decay(mut self, config: &WeightDecayConfig) -> Self {
+        self.weight_decay = Some(config.penalty);
+        self
+    }
+
+    /// The name of the group.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The learning rate multiplier of the group.
+    pub fn lr_multiplier(&self) -> f64 {
+        self.lr_multiplier
+    }
+
+    /// Returns true if the parameter belongs to the group.
+    pub fn contains(&self, path: &str, id: &ParamId) -> bool {
+        match &self.selector {
+            ParamGroupSelector::Path(prefix) => {
+                path == prefix
+                    || (path.starts_with(prefix.as_str()) && path[prefix.len()..].starts_with('.'))
+            }
+            ParamGroupSelector::Predicate(predicate) => predicate(path, id),
+        }
+    }
+
+    /// The settings passed to the [optimizer step](super::SimpleOptimizer::step) of the
+    /// parameters of the group.
+    pub fn settings(&self) -> ParamGroupSettings {
+        ParamGroupSettings {
+            weight_decay: self.weight_decay,
+        }
+    }
+}
+
+/// Settings of the [parameter group](ParamGroup) of the parameter being optimized, which
+/// override the ones of the optimizer.
+///
+/// Parameters that don't belong to any group use the default settings, which don't override
+/// anything.
+#[derive(Debug, Clone, Default)]
+pub struct ParamGroupSettings {
+    weight_decay: Option<f64>,
+}
+
+impl ParamGroupSettings {
+    /// The weight decay penalty of the group, if it overrides the one of the optimizer.
+    pub fn weight_decay(&self) -> Option<f64> {
+        self.weight_decay
+    }
+
+    /// Returns the [weight decay](WeightDecay) of the group if it overrides the one of the
+    /// optimizer, and `None` otherwise.
+    ///
+    /// Optimizers should use the returned decay when there is one, and their own otherwise.
+    pub fn override_weight_decay<B: Backend>(&self) -> Option<WeightDecay<B>> {
+        self.weight_decay
+            .map(|penalty| WeightDecay::new(&WeightDecayConfig::new(penalty)))
+    }
+}
+
+/// Assigns each parameter of a module to the index of the first matching group.
+pub(crate) fn assign_param_groups<B: Backend, M: Module<B>>(
+    module: &M,
+    groups: &[ParamGroup],
+) -> HashMap<ParamId, usize> {
+    let mut visitor = ParamGroupVisitor {
+        groups,
+        path: Vec::new(),
+        assignments: HashMap::new(),
+        phantom: PhantomData::<B>,
+    };
+    module.visit(&mut visitor);
+
+    visitor.assignments
+}
+
+struct ParamGroupVisitor<'a, B> {
+    groups: &'a [ParamGroup],
+    path: Vec<String>,
+    assignments: HashMap<ParamId, usize>,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: Backend> ModuleVisitor<B> for ParamGroupVisitor<'a, B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let path = self.path.join(".");
+
+        if let Some(index) = self
+            .groups
+            .iter()
+            .position(|group| group.contains(&path, id))
+        {
+            self.assignments.insert(id.clone(), index);
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as burn,
+        module::{list_param_ids, ConstantRecord, Module},
+        nn::{Linear, LinearConfig},
+        optim::{
+            adaptor::OptimizerAdaptor, AdamWConfig, GradientsParams, Optimizer, SgdConfig,
+            SimpleOptimizer,
+        },
+        tensor::{Distribution, Tensor},
+        TestADBackend,
+    };
+
+    use crate::LearningRate;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[derive(Module, Debug)]
+    struct TwoLayers<B: Backend> {
+        backbone: Linear<B>,
+        head: Vec<Linear<B>>,
+    }
+
+    impl<B: Backend> TwoLayers<B> {
+        fn new() -> Self {
+            Self {
+                backbone: LinearConfig::new(4, 4).init(),
+                head: vec![LinearConfig::new(4, 2).init()],
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.backbone.forward(input);
+            self.head[0].forward(x)
+        }
+    }
+
+    #[test]
+    fn should_assign_groups_by_path_and_predicate() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let groups = [
+            ParamGroup::from_predicate("no_decay", |path, _| path.ends_with("bias")),
+            ParamGroup::from_path("head", "head.0"),
+            ParamGroup::from_path("back", "back"),
+        ];
+
+        let assignments = assign_param_groups(&module, &groups);
+
+        let bias = module.backbone.bias.as_ref().unwrap().id.clone();
+        let weight_backbone = module.backbone.weight.id.clone();
+        let weight_head = module.head[0].weight.id.clone();
+        assert_eq!(assignments.get(&bias), Some(&0));
+        assert_eq!(assignments.get(&weight_head), Some(&1));
+        assert_eq!(assignments.get(&weight_backbone), None);
+    }
+
+    #[test]
+    fn should_not_update_params_of_frozen_group() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new().init().with_param_group(
+                ParamGroup::from_path("backbone", "backbone").with_lr_multiplier(0.0),
+            );
+
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let module_updated = optim.step(LEARNING_RATE, module.clone(), grads);
+
+        module_updated
+            .backbone
+            .weight
+            .to_data()
+            .assert_approx_eq(&module.backbone.weight.to_data(), 5);
+        assert_ne!(
+            module_updated.head[0].weight.to_data(),
+            module.head[0].weight.to_data()
+        );
+    }
+
+    #[test]
+    fn should_override_optimizer_weight_decay_with_group_weight_decay() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            AdamWConfig::new()
+                .with_weight_decay(0.0)
+                .init()
+                .with_param_group(
+                    ParamGroup::from_path("backbone", "backbone")
+                        .with_lr_multiplier(0.5)
+                        .with_weight_decay(&WeightDecayConfig::new(0.5)),
+                );
+        let mut optim_no_decay: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            AdamWConfig::new()
+                .with_weight_decay(0.0)
+                .init()
+                .with_param_group(
+                    ParamGroup::from_path("backbone", "backbone").with_lr_multiplier(0.5),
+                );
+
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x.clone()).backward(), &module);
+        let module_updated = optim.step(LEARNING_RATE, module.clone(), grads);
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let module_no_decay = optim_no_decay.step(LEARNING_RATE, module.clone(), grads);
+
+        // The decay is decoupled: the parameters are shrunk before the adaptive update.
+        let lr = LEARNING_RATE * 0.5;
+        let weight = module.backbone.weight.val().inner();
+        let update = module_no_decay.backbone.weight.val().inner() - weight.clone();
+        let weight_expected = weight.mul_scalar(1.0 - lr * 0.5) + update;
+        module_updated
+            .backbone
+            .weight
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq(&weight_expected.into_data(), 5);
+        module_updated.head[0]
+            .weight
+            .to_data()
+            .assert_approx_eq(&module_no_decay.head[0].weight.to_data(), 5);
+    }
+
+    #[test]
+    fn should_disable_weight_decay_of_group_with_zero_decay() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            AdamWConfig::new()
+                .with_weight_decay(0.5)
+                .init()
+                .with_param_group(
+                    ParamGroup::from_predicate("no_decay", |path, _| path.ends_with("bias"))
+                        .with_weight_decay(&WeightDecayConfig::new(0.0)),
+                );
+        let mut optim_no_decay: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            AdamWConfig::new().with_weight_decay(0.0).init();
+
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x.clone()).backward(), &module);
+        let module_updated = optim.step(LEARNING_RATE, module.clone(), grads);
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let module_no_decay = optim_no_decay.step(LEARNING_RATE, module.clone(), grads);
+
+        let bias =
+            |module: &TwoLayers<TestADBackend>| module.backbone.bias.as_ref().unwrap().to_data();
+        bias(&module_updated).assert_approx_eq(&bias(&module_no_decay), 5);
+        assert_ne!(
+            module_updated.backbone.weight.to_data(),
+            module_no_decay.backbone.weight.to_data()
+        );
+    }
+
+    #[test]
+    fn should_record_param_groups() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new()
+                .init()
+                .with_param_group(ParamGroup::from_path("head", "head"));
+
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let _module = optim.step(LEARNING_RATE, module.clone(), grads);
+
+        let record = optim.to_record();
+        assert_eq!(record.len(), list_param_ids(&module).len());
+        assert_eq!(
+            record.get(&module.head[0].weight.id).unwrap().group(),
+            Some("head")
+        );
+        assert_eq!(
+            record.get(&module.backbone.weight.id).unwrap().group(),
+            None
+        );
+    }
+    #[test]
+    fn should_assign_configured_groups_over_record_groups() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new()
+                .init()
+                .with_param_group(ParamGroup::from_path("frozen", "head").with_lr_multiplier(0.0));
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x.clone()).backward(), &module);
+        let module = optim.step(LEARNING_RATE, module, grads);
+
+        // The selector changed, the configured group is used instead of the one of the record.
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new()
+                .init()
+                .with_param_group(
+                    ParamGroup::from_path("frozen", "backbone").with_lr_multiplier(0.0),
+                )
+                .load_record(optim.to_record());
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let module_updated = optim.step(LEARNING_RATE, module.clone(), grads);
+
+        module_updated
+            .backbone
+            .weight
+            .to_data()
+            .assert_approx_eq(&module.backbone.weight.to_data(), 5);
+        assert_ne!(
+            module_updated.head[0].weight.to_data(),
+            module.head[0].weight.to_data()
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_configured_groups_when_record_group_is_not_registered() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new()
+                .init()
+                .with_param_group(ParamGroup::from_path("frozen", "head").with_lr_multiplier(0.0));
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x.clone()).backward(), &module);
+        let module = optim.step(LEARNING_RATE, module, grads);
+
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            SgdConfig::new().init().load_record(optim.to_record());
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let module_updated = optim.step(LEARNING_RATE, module.clone(), grads);
+
+        assert_ne!(
+            module_updated.head[0].weight.to_data(),
+            module.head[0].weight.to_data()
+        );
+        assert_eq!(
+            optim
+                .to_record()
+                .get(&module.head[0].weight.id)
+                .unwrap()
+                .group(),
+            None
+        );
+    }
+
+    #[test]
+    fn should_record_param_groups_of_stateless_optimizer() {
+        let module = TwoLayers::<TestADBackend>::new();
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            OptimizerAdaptor::from(GradientDescent)
+                .with_param_group(ParamGroup::from_path("head", "head"));
+        let x = Tensor::<TestADBackend, 2>::random([2, 4], Distribution::Standard);
+        let grads = GradientsParams::from_grads(module.forward(x.clone()).backward(), &module);
+        let module = optim.step(LEARNING_RATE, module, grads);
+
+        let record = optim.to_record();
+        assert_eq!(
+            record.get(&module.head[0].weight.id).unwrap().group(),
+            Some("head")
+        );
+        assert!(record.get(&module.backbone.weight.id).is_none());
+
+        let mut optim: OptimizerAdaptor<_, TwoLayers<TestADBackend>, TestADBackend> =
+            OptimizerAdaptor::from(GradientDescent)
+                .with_param_group(ParamGroup::from_path("head", "head"))
+                .load_record(record);
+        let grads = GradientsParams::from_grads(module.forward(x).backward(), &module);
+        let _module = optim.step(LEARNING_RATE, module, grads);
+    }
+
+    /// Plain gradient descent, which doesn't have any state.
+    struct GradientDescent;
+
+    impl<B: Backend> SimpleOptimizer<B> for GradientDescent {
+        type State<const D: usize> = ConstantRecord;
+
+        fn step<const D: usize>(
+            &self,
+            lr: LearningRate,
+            tensor: Tensor<B, D>,
+            grad: Tensor<B, D>,
+            _state: Option<Self::State<D>>,
+        ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+            (tensor - grad.mul_scalar(lr), None)
+        }
+
+        fn to_device<const D: usize>(state: Self::State<D>, _device: &B::Device) -> Self::State<D> {
+            state
+        }
+    }
+}
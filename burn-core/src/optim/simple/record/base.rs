@@ -1,8 +1,9 @@
-use super::{AdaptorRecordItemV1, AdaptorRecordV1};
+use super::{AdaptorRecordItemV1, AdaptorRecordItemV2, AdaptorRecordV1, AdaptorRecordV2};
 use crate::{
     optim::SimpleOptimizer,
     record::{PrecisionSettings, Record},
 };
+use alloc::string::String;
 use burn_tensor::backend::Backend;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,9 @@ use serde::{Deserialize, Serialize};
 pub enum AdaptorRecord<O: SimpleOptimizer<B>, B: Backend> {
     /// Version 1.
     V1(AdaptorRecordV1<O, B>),
+
+    /// Version 2.
+    V2(AdaptorRecordV2<O, B>),
 }
 
 /// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record item.
@@ -20,6 +24,9 @@ pub enum AdaptorRecord<O: SimpleOptimizer<B>, B: Backend> {
 pub enum AdaptorRecordItem<O: SimpleOptimizer<B>, B: Backend, S: PrecisionSettings> {
     /// Version 1.
     V1(AdaptorRecordItemV1<O, B, S>),
+
+    /// Version 2.
+    V2(AdaptorRecordItemV2<O, B, S>),
 }
 
 impl<O, B> Record for AdaptorRecord<O, B>
@@ -32,12 +39,14 @@ where
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
         match self {
             AdaptorRecord::V1(record) => AdaptorRecordItem::V1(record.into_item()),
+            AdaptorRecord::V2(record) => AdaptorRecordItem::V2(record.into_item()),
         }
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
         match item {
             AdaptorRecordItem::V1(item) => Self::V1(AdaptorRecordV1::from_item(item)),
+            AdaptorRecordItem::V2(item) => Self::V2(AdaptorRecordV2::from_item(item)),
         }
     }
 }
@@ -50,6 +59,7 @@ where
     fn clone(&self) -> Self {
         match self {
             AdaptorRecord::V1(record) => Self::V1(record.clone()),
+            AdaptorRecord::V2(record) => Self::V2(record.clone()),
         }
     }
 }
@@ -64,9 +74,25 @@ where
     /// # Returns
     ///
     /// The optimizer state.
+    ///
+    /// # Panics
+    ///
+    /// If the record doesn't have any state, see [try_into_state](Self::try_into_state).
     pub fn into_state<const D: usize>(self) -> O::State<D> {
+        self.try_into_state()
+            .expect("The record should have an optimizer state.")
+    }
+
+    /// Converts the record into the optimizer state, if it has one.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer state, `None` if the record only holds the parameter group of a stateless
+    /// optimizer.
+    pub fn try_into_state<const D: usize>(self) -> Option<O::State<D>> {
         match self {
-            AdaptorRecord::V1(record) => record.into_state(),
+            AdaptorRecord::V1(record) => Some(record.into_state()),
+            AdaptorRecord::V2(record) => record.into_state(),
         }
     }
 
@@ -80,6 +106,57 @@ where
     ///
     /// The record.
     pub fn from_state<const D: usize>(state: O::State<D>) -> Self {
-        Self::V1(AdaptorRecordV1::from_state(state))
+        Self::V2(AdaptorRecordV2::from_state(state, None))
+    }
+
+    /// Creates a record without any optimizer state, which only keeps the
+    /// [parameter group](crate::optim::group::ParamGroup) of the parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `group`: The name of the parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The record.
+    pub fn from_group(group: Option<String>) -> Self {
+        Self::V2(AdaptorRecordV2::from_group(group))
+    }
+
+    /// Sets the name of the [parameter group](crate::optim::group::ParamGroup) the state was
+    /// computed with.
+    ///
+    /// # Arguments
+    ///
+    /// * `group`: The name of the parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The record.
+    pub fn with_group(self, group: Option<String>) -> Self {
+        match self {
+            AdaptorRecord::V1(state) => Self::V2(AdaptorRecordV2 {
+                state: Some(state),
+                group,
+            }),
+            AdaptorRecord::V2(record) => Self::V2(AdaptorRecordV2 {
+                state: record.state,
+                group,
+            }),
+        }
+    }
+
+    /// Gets the name of the [parameter group](crate::optim::group::ParamGroup) the state was
+    /// computed with.
+    ///
+    /// # Returns
+    ///
+    /// The name of the group, `None` if the parameter wasn't part of any group or if the record
+    /// was saved before groups were recorded.
+    pub fn group(&self) -> Option<&str> {
+        match self {
+            AdaptorRecord::V1(_) => None,
+            AdaptorRecord::V2(record) => record.group.as_deref(),
+        }
     }
 }
@@ -1,5 +1,7 @@
 mod base;
 mod v1;
+mod v2;
 
 pub use base::*;
 pub use v1::*;
+pub use v2::*;
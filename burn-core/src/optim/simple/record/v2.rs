@@ -0,0 +1,107 @@
+use super::{AdaptorRecordItemV1, AdaptorRecordV1};
+use crate::{
+    optim::SimpleOptimizer,
+    record::{PrecisionSettings, Record},
+};
+use alloc::string::String;
+use burn_tensor::backend::Backend;
+use serde::{Deserialize, Serialize};
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record item.
+///
+/// Compared to [version 1](AdaptorRecordV1), the [parameter group](crate::optim::group::ParamGroup)
+/// the state was computed with is also saved, even for stateless optimizers.
+pub struct AdaptorRecordV2<O: SimpleOptimizer<B>, B: Backend> {
+    /// The optimizer state, if the optimizer has one.
+    pub state: Option<AdaptorRecordV1<O, B>>,
+
+    /// The name of the parameter group, if any.
+    pub group: Option<String>,
+}
+
+impl<O: SimpleOptimizer<B>, B: Backend> Clone for AdaptorRecordV2<O, B> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            group: self.group.clone(),
+        }
+    }
+}
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct AdaptorRecordItemV2<O: SimpleOptimizer<B>, B: Backend, S: PrecisionSettings> {
+    /// The optimizer state, if the optimizer has one.
+    pub state: Option<AdaptorRecordItemV1<O, B, S>>,
+
+    /// The name of the parameter group, if any.
+    pub group: Option<String>,
+}
+
+impl<O, B> AdaptorRecordV2<O, B>
+where
+    O: SimpleOptimizer<B>,
+    B: Backend,
+{
+    /// Convert the record into the state.
+    ///
+    /// # Returns
+    ///
+    /// The state, `None` if the optimizer doesn't have any.
+    pub fn into_state<const D: usize>(self) -> Option<O::State<D>> {
+        self.state.map(|state| state.into_state())
+    }
+
+    /// Convert the state into the record.
+    ///
+    /// # Arguments
+    ///
+    /// * `state`: The state.
+    /// * `group`: The name of the parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The record.
+    pub fn from_state<const D: usize>(state: O::State<D>, group: Option<String>) -> Self {
+        Self {
+            state: Some(AdaptorRecordV1::from_state(state)),
+            group,
+        }
+    }
+
+    /// Create a record without any state, for optimizers that don't have one.
+    ///
+    /// # Arguments
+    ///
+    /// * `group`: The name of the parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The record.
+    pub fn from_group(group: Option<String>) -> Self {
+        Self { state: None, group }
+    }
+}
+
+impl<O, B> Record for AdaptorRecordV2<O, B>
+where
+    O: SimpleOptimizer<B>,
+    B: Backend,
+{
+    type Item<S: PrecisionSettings> = AdaptorRecordItemV2<O, B, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        AdaptorRecordItemV2 {
+            state: self.state.map(|state| state.into_item()),
+            group: self.group,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            state: item.state.map(AdaptorRecordV1::from_item),
+            group: item.group,
+        }
+    }
+}
@@ -41,6 +41,26 @@ impl<T: Record> Record for Option<T> {
     }
 }
 
+// Tuples are recorded element by element.
+macro_rules! tuple {
+    ($($name:ident: $index:tt),+) => {
+        impl<$($name: Record),+> Record for ($($name,)+) {
+            type Item<S: PrecisionSettings> = ($($name::Item<S>,)+);
+
+            fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+                ($(self.$index.into_item(),)+)
+            }
+
+            fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+                ($($name::from_item(item.$index),)+)
+            }
+        }
+    };
+}
+
+tuple!(A: 0, B: 1);
+tuple!(A: 0, B: 1, C: 2);
+
 impl<const N: usize, T: Record + core::fmt::Debug> Record for [T; N] {
     type Item<S: PrecisionSettings> = Vec<T::Item<S>>;
 
@@ -67,7 +67,9 @@ impl FnGenerator {
     pub fn gen_visit_fn(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
             quote! {
+                burn::module::ModuleVisitor::<B>::enter_module(visitor, stringify!(#name));
                 burn::module::Module::visit(&self.#name, visitor);
+                burn::module::ModuleVisitor::<B>::exit_module(visitor, stringify!(#name));
             }
         });
 
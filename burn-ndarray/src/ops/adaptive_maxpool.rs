@@ -0,0 +1,105 @@
+use crate::{
+    element::FloatNdArrayElement,
+    iter_par,
+    ops::adaptive_avgpool::{end_index, start_index},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_tensor::ElementConversion;
+use ndarray::Array4;
+
+pub(crate) fn adaptive_max_pool2d_with_indexes<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+) -> (NdArrayTensor<E, 4>, NdArrayTensor<i64, 4>) {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let inf = (-f32::INFINITY).elem::<E>();
+
+    let x = x.array;
+    let mut output = Array4::from_elem((batch_size, channels, output_size[0], output_size[1]), inf);
+    let mut indexes = Array4::<i64>::zeros((batch_size, channels, output_size[0], output_size[1]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let unsafe_shared_indexes = UnsafeSharedRef::new(&mut indexes);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let indexes = unsafe_shared_indexes.get();
+
+            for h in 0..output_size[0] {
+                for w in 0..output_size[1] {
+                    let ih_start = start_index(h, output_size[0], input_height);
+                    let ih_end = end_index(h, output_size[0], input_height);
+                    let iw_start = start_index(w, output_size[1], input_width);
+                    let iw_end = end_index(w, output_size[1], input_width);
+
+                    let mut max_val = inf;
+                    let mut index = 0;
+
+                    for ih in ih_start..ih_end {
+                        for iw in iw_start..iw_end {
+                            let val = x[[b, c, ih, iw]];
+
+                            if val > max_val {
+                                max_val = val;
+                                index = (ih * input_width + iw) as i64;
+                            }
+                        }
+                    }
+
+                    output[[b, c, h, w]] = max_val;
+                    indexes[[b, c, h, w]] = index;
+                }
+            }
+        })
+    });
+
+    let output = NdArrayTensor::new(output.into_dyn().into_shared());
+    let indexes = NdArrayTensor::new(indexes.into_dyn().into_shared());
+
+    (output, indexes)
+}
+
+pub(crate) fn adaptive_max_pool2d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_grad: NdArrayTensor<E, 4>,
+    indexes: NdArrayTensor<i64, 4>,
+) -> NdArrayTensor<E, 4> {
+    let [_batch_size, _channels, height, width] = output_grad.shape().dims;
+    let [batch_size, channels, height_x, width_x] = x.shape().dims;
+
+    let output_grad = output_grad.array;
+    let indexes = indexes.array;
+
+    let mut output = Array4::zeros((batch_size, channels, height_x, width_x));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for h in 0..height {
+                for w in 0..width {
+                    let index = indexes[[b, c, h, w]];
+                    let grad = output_grad[[b, c, h, w]];
+
+                    let index_h = index as usize / width_x;
+                    let index_w = index as usize % width_x;
+
+                    output[[b, c, index_h, index_w]] += grad;
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
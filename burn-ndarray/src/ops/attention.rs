@@ -0,0 +1,234 @@
+use crate::{
+    element::FloatNdArrayElement, iter_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use alloc::vec;
+use burn_tensor::{ops::AttentionOptions, ElementConversion};
+use ndarray::{ArcArray, Array4, IxDyn};
+
+/// Scaled dot-product attention computed one query row at a time with an online softmax, so the
+/// `[seq_length_1, seq_length_2]` attention matrix is never materialized.
+pub(crate) fn scaled_dot_product_attention<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    options: AttentionOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = query.shape().dims;
+    let [_, _, seq_length_2, d_v] = value.shape().dims;
+
+    let mut output = Array4::from_elem((batch_size, n_heads, seq_length_1, d_v), 0.elem());
+
+    // Without any key to attend to, the weighted sum of the values is empty.
+    if seq_length_2 == 0 {
+        return NdArrayTensor::new(output.into_dyn().into_shared());
+    }
+
+    let scores = Scores::new(query, key, mask, &options);
+    let value = value.array;
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            let output = unsafe_shared_out.get();
+            let mut acc = vec![0.elem::<E>(); d_v];
+
+            for i in 0..seq_length_1 {
+                let mut max = scores.get(b, h, i, 0);
+                let mut sum: E = 1.elem();
+                for (d, acc) in acc.iter_mut().enumerate() {
+                    *acc = value[[b, h, 0, d]];
+                }
+
+                for j in 1..seq_length_2 {
+                    let score = scores.get(b, h, i, j);
+
+                    if score > max {
+                        // Rescale what was accumulated so far relative to the new maximum.
+                        let correction = (max - score).exp_elem();
+                        sum = sum * correction + 1.elem();
+                        for (d, acc) in acc.iter_mut().enumerate() {
+                            *acc = *acc * correction + value[[b, h, j, d]];
+                        }
+                        max = score;
+                    } else {
+                        let weight = (score - max).exp_elem();
+                        sum += weight;
+                        for (d, acc) in acc.iter_mut().enumerate() {
+                            *acc += weight * value[[b, h, j, d]];
+                        }
+                    }
+                }
+
+                for (d, acc) in acc.iter().enumerate() {
+                    output[[b, h, i, d]] = *acc / sum;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn scaled_dot_product_attention_backward<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    output_grad: NdArrayTensor<E, 4>,
+    options: AttentionOptions,
+) -> (
+    NdArrayTensor<E, 4>,
+    NdArrayTensor<E, 4>,
+    NdArrayTensor<E, 4>,
+) {
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape().dims;
+    let [_, _, seq_length_2, d_v] = value.shape().dims;
+    let scale: E = options.scale.elem();
+
+    let mut query_grad = Array4::from_elem((batch_size, n_heads, seq_length_1, d_k), 0.elem());
+    let mut key_grad = Array4::from_elem((batch_size, n_heads, seq_length_2, d_k), 0.elem());
+    let mut value_grad = Array4::from_elem((batch_size, n_heads, seq_length_2, d_v), 0.elem());
+
+    // The output doesn't depend on the query when there isn't any key to attend to.
+    if seq_length_2 == 0 {
+        return (
+            NdArrayTensor::new(query_grad.into_dyn().into_shared()),
+            NdArrayTensor::new(key_grad.into_dyn().into_shared()),
+            NdArrayTensor::new(value_grad.into_dyn().into_shared()),
+        );
+    }
+
+    let scores = Scores::new(query, key, mask, &options);
+    let value = value.array;
+    let output_grad = output_grad.array;
+    let unsafe_shared_query_grad = UnsafeSharedRef::new(&mut query_grad);
+    let unsafe_shared_key_grad = UnsafeSharedRef::new(&mut key_grad);
+    let unsafe_shared_value_grad = UnsafeSharedRef::new(&mut value_grad);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            let query_grad = unsafe_shared_query_grad.get();
+            let key_grad = unsafe_shared_key_grad.get();
+            let value_grad = unsafe_shared_value_grad.get();
+
+            let weights_grad = |i: usize, j: usize| {
+                let mut grad: E = 0.elem();
+                for d in 0..d_v {
+                    grad += output_grad[[b, h, i, d]] * value[[b, h, j, d]];
+                }
+                grad
+            };
+
+            for i in 0..seq_length_1 {
+                let mut max = scores.get(b, h, i, 0);
+                let mut sum: E = 1.elem();
+                for j in 1..seq_length_2 {
+                    let score = scores.get(b, h, i, j);
+
+                    if score > max {
+                        sum = sum * (max - score).exp_elem() + 1.elem();
+                        max = score;
+                    } else {
+                        sum += (score - max).exp_elem();
+                    }
+                }
+                let weight = |j: usize| (scores.get(b, h, i, j) - max).exp_elem() / sum;
+
+                // Backward of the softmax: dS = P * (dP - sum(dP * P)).
+                let mut delta: E = 0.elem();
+                for j in 0..seq_length_2 {
+                    delta += weight(j) * weights_grad(i, j);
+                }
+
+                for j in 0..seq_length_2 {
+                    let weight = weight(j);
+
+                    for d in 0..d_v {
+                        value_grad[[b, h, j, d]] += weight * output_grad[[b, h, i, d]];
+                    }
+
+                    // The masked scores are replaced by a constant and don't receive any gradient.
+                    if scores.is_masked(b, h, i, j) {
+                        continue;
+                    }
+
+                    let score_grad = weight * (weights_grad(i, j) - delta) * scale;
+
+                    for d in 0..d_k {
+                        query_grad[[b, h, i, d]] += score_grad * scores.key[[b, h, j, d]];
+                        key_grad[[b, h, j, d]] += score_grad * scores.query[[b, h, i, d]];
+                    }
+                }
+            }
+        })
+    });
+
+    (
+        NdArrayTensor::new(query_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(key_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(value_grad.into_dyn().into_shared()),
+    )
+}
+
+/// Computes the masked and scaled attention scores on demand.
+struct Scores<E> {
+    query: ArcArray<E, IxDyn>,
+    key: ArcArray<E, IxDyn>,
+    mask: Option<ArcArray<bool, IxDyn>>,
+    scale: E,
+    mask_value: E,
+}
+
+impl<E: FloatNdArrayElement> Scores<E> {
+    fn new(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        options: &AttentionOptions,
+    ) -> Self {
+        Self {
+            query: query.array,
+            key: key.array,
+            mask: mask.map(|mask| mask.array),
+            scale: options.scale.elem(),
+            mask_value: options.mask_value.elem(),
+        }
+    }
+
+    fn is_masked(&self, b: usize, h: usize, i: usize, j: usize) -> bool {
+        match &self.mask {
+            Some(mask) => {
+                let shape = mask.shape();
+                // Dimensions of size one are broadcasted.
+                let b = if shape[0] == 1 { 0 } else { b };
+                let h = if shape[1] == 1 { 0 } else { h };
+                let i = if shape[2] == 1 { 0 } else { i };
+
+                mask[[b, h, i, j]]
+            }
+            None => false,
+        }
+    }
+
+    fn get(&self, b: usize, h: usize, i: usize, j: usize) -> E {
+        if self.is_masked(b, h, i, j) {
+            return self.mask_value;
+        }
+
+        let d_k = self.query.shape()[3];
+        let mut score: E = 0.elem();
+        for d in 0..d_k {
+            score += self.query[[b, h, i, d]] * self.key[[b, h, j, d]];
+        }
+
+        score * self.scale
+    }
+}
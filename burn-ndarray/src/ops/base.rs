@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use burn_tensor::ops::compare_elements;
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
 use core::cmp::Ordering;
@@ -11,6 +12,7 @@ use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
@@ -171,6 +173,34 @@ where
         NdArrayTensor { array }
     }
 
+    /// Applies the function element wise on both tensors, broadcasting their dimensions of
+    /// size one.
+    pub fn elementwise_op<const D: usize, F>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        func: F,
+    ) -> NdArrayTensor<E, D>
+    where
+        F: Fn(E, E) -> E,
+    {
+        let shape: Vec<usize> = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(lhs, rhs)| usize::max(*lhs, *rhs))
+            .collect();
+        let lhs = lhs.array.broadcast(shape.clone()).unwrap();
+        let rhs = rhs.array.broadcast(shape).unwrap();
+
+        let array = Zip::from(&lhs)
+            .and(&rhs)
+            .map_collect(|lhs, rhs| func(*lhs, *rhs))
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
         let data = Data::from([tensor.array.mean().unwrap()]);
         NdArrayTensor::from_data(data)
@@ -208,6 +238,39 @@ where
         }
     }
 
+    pub fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        let data = Data::from([tensor.array.product()]);
+        NdArrayTensor::from_data(data)
+    }
+
+    pub fn prod_dim<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .fold_axis(Axis(dim), 1.elem(), |acc, value| *acc * *value)
+            .insert_axis(Axis(dim));
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |previous, current| *current += *previous);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |previous, current| {
+            *current = *current * *previous
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn gather<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
@@ -408,6 +471,49 @@ where
 
         tensor
     }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut values = tensor.array.into_owned();
+        let mut indices = values.map(|_| 0_i64);
+
+        Zip::from(values.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|mut values, mut indices| {
+                let mut sorted: Vec<(usize, E)> = values.iter().copied().enumerate().collect();
+                // The sort is stable, equal elements keep their relative order and NaN is
+                // sorted as the largest value.
+                sorted.sort_by(|(_, a), (_, b)| {
+                    let ordering = compare_elements(a, b);
+
+                    match descending {
+                        true => ordering.reverse(),
+                        false => ordering,
+                    }
+                });
+
+                for (position, (index, value)) in sorted.into_iter().enumerate() {
+                    values[position] = value;
+                    indices[position] = index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(values.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        Self::sort_with_indices(tensor, dim, descending).1
+    }
 }
 
 fn arg<E: NdArrayElement, F, const D: usize>(
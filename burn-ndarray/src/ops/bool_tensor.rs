@@ -11,6 +11,7 @@ use crate::{tensor::NdArrayTensor, NdArrayBackend};
 
 // Workspace crates
 use burn_tensor::{backend::Backend, Data, Shape};
+use ndarray::Axis;
 
 use super::NdArrayOps;
 
@@ -105,7 +106,7 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         rhs: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
         let mut array = lhs.array;
-        array.zip_mut_with(&rhs.array, |a, b| *a = *a && *b);
+        array.zip_mut_with(&rhs.array, |a, b| *a = *a == *b);
 
         NdArrayTensor { array }
     }
@@ -117,4 +118,80 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         let array = lhs.array.mapv(|a| a == rhs).into_shared();
         NdArrayTensor { array }
     }
+
+    fn bool_and<const D: usize>(
+        lhs: NdArrayTensor<bool, D>,
+        rhs: NdArrayTensor<bool, D>,
+    ) -> NdArrayTensor<bool, D> {
+        let array = (&lhs.array & &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: NdArrayTensor<bool, D>,
+        rhs: NdArrayTensor<bool, D>,
+    ) -> NdArrayTensor<bool, D> {
+        let array = (&lhs.array | &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: NdArrayTensor<bool, D>,
+        rhs: NdArrayTensor<bool, D>,
+    ) -> NdArrayTensor<bool, D> {
+        let array = (&lhs.array ^ &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_not<const D: usize>(tensor: NdArrayTensor<bool, D>) -> NdArrayTensor<bool, D> {
+        let array = tensor.array.mapv(|a| !a).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_any<const D: usize>(tensor: NdArrayTensor<bool, D>) -> NdArrayTensor<bool, 1> {
+        let data = Data::from([tensor.array.iter().any(|a| *a)]);
+        NdArrayTensor::from_data(data)
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: NdArrayTensor<bool, D>,
+        dim: usize,
+    ) -> NdArrayTensor<bool, D> {
+        let array = tensor
+            .array
+            .fold_axis(Axis(dim), false, |acc, a| *acc || *a)
+            .insert_axis(Axis(dim))
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    fn bool_all<const D: usize>(tensor: NdArrayTensor<bool, D>) -> NdArrayTensor<bool, 1> {
+        let data = Data::from([tensor.array.iter().all(|a| *a)]);
+        NdArrayTensor::from_data(data)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: NdArrayTensor<bool, D>,
+        dim: usize,
+    ) -> NdArrayTensor<bool, D> {
+        let array = tensor
+            .array
+            .fold_axis(Axis(dim), true, |acc, a| *acc && *a)
+            .insert_axis(Axis(dim))
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    fn bool_argwhere<const D: usize>(tensor: NdArrayTensor<bool, D>) -> NdArrayTensor<i64, 2> {
+        let mut indexes = Vec::new();
+
+        for (index, _) in tensor.array.indexed_iter().filter(|(_, a)| **a) {
+            indexes.extend((0..D).map(|dim| index[dim] as i64));
+        }
+
+        let num_elements = indexes.len() / D;
+        NdArrayTensor::from_data(Data::new(indexes, Shape::new([num_elements, D])))
+    }
 }
@@ -2,11 +2,15 @@ use burn_tensor::{
     ops::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions},
     ElementConversion,
 };
-use ndarray::{Array4, Dim};
+use ndarray::{Array4, Array5, Dim};
 
 use crate::{
-    element::FloatNdArrayElement, iter_par, ops::padding::apply_padding_4d, run_par,
-    sharing::UnsafeSharedRef, tensor::NdArrayTensor,
+    element::FloatNdArrayElement,
+    iter_par,
+    ops::padding::{apply_padding_4d, apply_padding_5d},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
 };
 
 pub(crate) fn conv2d<E: FloatNdArrayElement>(
@@ -46,7 +50,7 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
         iter_par!(0, batch_size * out_channels).for_each(|k| unsafe {
             let b = k / out_channels;
             let oc = k % out_channels;
-            let g = k % options.groups;
+            let g = oc / (out_channels / options.groups);
 
             let output = unsafe_shared_out.get();
 
@@ -116,12 +120,12 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
     run_par!(|| {
         iter_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
             let b = k / (out_channels * options.groups);
-            let oc = k % out_channels;
-            let g = k % options.groups;
+            let oc_out = k % (out_channels * options.groups);
+            let g = oc_out / out_channels;
+            let oc = oc_out % out_channels;
 
             let output = unsafe_shared_out.get();
 
-            let oc_out = oc + (out_channels * g);
             let ic_start = g * (in_channels / options.groups);
             let ic_end = ic_start + in_channels / options.groups;
 
@@ -164,3 +168,198 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+pub(crate) fn conv3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [out_channels, in_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+
+    let out_depth = calculate_conv_output_size(
+        kernel_depth,
+        stride_depth,
+        padding_depth,
+        dilation_depth,
+        in_depth,
+    );
+    let out_height = calculate_conv_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    let x = apply_padding_5d(x, options.padding, 0i32.elem()).array;
+
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * out_channels).for_each(|k| unsafe {
+            let b = k / out_channels;
+            let oc = k % out_channels;
+            let g = oc / (out_channels / options.groups);
+
+            let output = unsafe_shared_out.get();
+
+            for ic in (in_channels * g)..(in_channels * (g + 1)) {
+                let weight_ic = ic - (g * in_channels);
+
+                for kd in 0..kernel_depth {
+                    for kh in 0..kernel_height {
+                        for kw in 0..kernel_width {
+                            let w = weight.array[[oc, weight_ic, kd, kh, kw]];
+
+                            for od in 0..out_depth {
+                                for oh in 0..out_height {
+                                    for ow in 0..out_width {
+                                        let id = od * stride_depth + kd * dilation_depth;
+                                        let ih = oh * stride_height + kh * dilation_height;
+                                        let iw = ow * stride_width + kw * dilation_width;
+
+                                        output[[b, oc, od, oh, ow]] += x[[b, ic, id, ih, iw]] * w;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(bias) = &bias {
+                for od in 0..out_depth {
+                    for oh in 0..out_height {
+                        for ow in 0..out_width {
+                            output[[b, oc, od, oh, ow]] += bias.array[oc];
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn conv_transpose3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [out_padding_depth, out_padding_height, out_padding_width] = options.padding_out;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [in_channels, out_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+
+    let out_depth =
+        (in_depth - 1) * stride_depth + dilation_depth * (kernel_depth - 1) + out_padding_depth
+            - 2 * padding_depth
+            + 1;
+    let out_height = (in_height - 1) * stride_height
+        + dilation_height * (kernel_height - 1)
+        + out_padding_height
+        - 2 * padding_height
+        + 1;
+    let out_width =
+        (in_width - 1) * stride_width + dilation_width * (kernel_width - 1) + out_padding_width
+            - 2 * padding_width
+            + 1;
+
+    let x = x.array;
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels * options.groups,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
+            let b = k / (out_channels * options.groups);
+            let oc_out = k % (out_channels * options.groups);
+            let g = oc_out / out_channels;
+            let oc = oc_out % out_channels;
+
+            let output = unsafe_shared_out.get();
+
+            let ic_start = g * (in_channels / options.groups);
+            let ic_end = ic_start + in_channels / options.groups;
+
+            for ic in ic_start..ic_end {
+                for id in 0..in_depth {
+                    for ih in 0..in_height {
+                        for iw in 0..in_width {
+                            for kd in 0..kernel_depth {
+                                for kh in 0..kernel_height {
+                                    for kw in 0..kernel_width {
+                                        let od = id * stride_depth + kd * dilation_depth;
+                                        let oh = ih * stride_height + kh * dilation_height;
+                                        let ow = iw * stride_width + kw * dilation_width;
+
+                                        if od >= out_depth + padding_depth
+                                            || oh >= out_height + padding_height
+                                            || ow >= out_width + padding_width
+                                            || od < padding_depth
+                                            || oh < padding_height
+                                            || ow < padding_width
+                                        {
+                                            continue;
+                                        }
+
+                                        let od = od - padding_depth;
+                                        let oh = oh - padding_height;
+                                        let ow = ow - padding_width;
+
+                                        output[[b, oc_out, od, oh, ow]] += x[[b, ic, id, ih, iw]]
+                                            * weight.array[[ic, oc, kd, kh, kw]];
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(bias) = &bias {
+                for od in 0..out_depth {
+                    for oh in 0..out_height {
+                        for ow in 0..out_width {
+                            output[[b, oc_out, od, oh, ow]] += bias.array[oc_out];
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
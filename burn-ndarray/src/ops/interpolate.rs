@@ -0,0 +1,112 @@
+use crate::{
+    element::FloatNdArrayElement, iter_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use alloc::vec::Vec;
+use burn_tensor::ops::interpolate::interpolate_axis_weights;
+use burn_tensor::ops::InterpolateOptions;
+use burn_tensor::ElementConversion;
+use ndarray::Array4;
+
+pub(crate) fn interpolate<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let [output_height, output_width] = output_size;
+
+    let weights_height = axis_weights::<E>(input_height, output_height, &options);
+    let weights_width = axis_weights::<E>(input_width, output_width, &options);
+
+    let x = x.array;
+    let mut output = Array4::from_elem(
+        (batch_size, channels, output_height, output_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for oh in 0..output_height {
+                for ow in 0..output_width {
+                    let mut val: E = 0.elem();
+
+                    for (ih, weight_h) in weights_height[oh].iter() {
+                        for (iw, weight_w) in weights_width[ow].iter() {
+                            val += x[[b, c, *ih, *iw]] * *weight_h * *weight_w;
+                        }
+                    }
+
+                    output[[b, c, oh, ow]] = val;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn interpolate_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grad: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let [output_height, output_width] = output_size;
+
+    let weights_height = axis_weights::<E>(input_height, output_height, &options);
+    let weights_width = axis_weights::<E>(input_width, output_width, &options);
+
+    let grad = grad.array;
+    let mut output_grad =
+        Array4::from_elem((batch_size, channels, input_height, input_width), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_out.get();
+
+            for oh in 0..output_height {
+                for ow in 0..output_width {
+                    let grad = grad[[b, c, oh, ow]];
+
+                    for (ih, weight_h) in weights_height[oh].iter() {
+                        for (iw, weight_w) in weights_width[ow].iter() {
+                            output_grad[[b, c, *ih, *iw]] += grad * *weight_h * *weight_w;
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
+
+/// Computes, for each output index along one axis, the input indexes contributing to it with
+/// their respective weights.
+fn axis_weights<E: FloatNdArrayElement>(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Vec<Vec<(usize, E)>> {
+    interpolate_axis_weights(input_size, output_size, options)
+        .into_iter()
+        .map(|weights| {
+            weights
+                .into_iter()
+                .map(|(index, weight)| (index, weight.elem()))
+                .collect()
+        })
+        .collect()
+}
@@ -1,10 +1,14 @@
 use crate::{
-    element::FloatNdArrayElement, iter_par, ops::padding::apply_padding_4d, run_par,
-    sharing::UnsafeSharedRef, tensor::NdArrayTensor,
+    element::FloatNdArrayElement,
+    iter_par,
+    ops::padding::{apply_padding_4d, apply_padding_5d},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
 };
 
 use burn_tensor::ElementConversion;
-use ndarray::Array4;
+use ndarray::{Array4, Array5};
 
 pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -109,7 +113,7 @@ pub(crate) fn max_pool2d_with_indexes<E: FloatNdArrayElement>(
                                 let ih = ih as i64 - padding_height as i64;
                                 let iw = iw as i64 - padding_width as i64;
 
-                                index = ih * x_height as i64 + iw;
+                                index = ih * x_width as i64 + iw;
                             }
                         }
                     }
@@ -168,3 +172,138 @@ pub(crate) fn max_pool2d_backward<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+pub(crate) fn max_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> NdArrayTensor<E, 5> {
+    let (output, _indexes) = max_pool3d_with_indexes(x, kernel_size, stride, padding);
+
+    output
+}
+
+pub(crate) fn max_pool3d_with_indexes<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> (NdArrayTensor<E, 5>, NdArrayTensor<i64, 5>) {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims;
+    let inf = (-f32::INFINITY).elem::<E>();
+
+    let out_depth = ((x_depth + 2 * padding_depth - kernel_depth) / stride_depth) + 1;
+    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
+    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+
+    let x = apply_padding_5d(x, padding, inf).array;
+
+    let mut output = Array5::from_elem(
+        (batch_size, channels, out_depth, out_height, out_width),
+        inf,
+    );
+    let mut indexes =
+        Array5::<i64>::zeros((batch_size, channels, out_depth, out_height, out_width));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let unsafe_shared_indexes = UnsafeSharedRef::new(&mut indexes);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let indexes = unsafe_shared_indexes.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let mut max_val = inf;
+                        let mut index = 0;
+
+                        for kd in 0..kernel_depth {
+                            let id = od * stride_depth + kd;
+
+                            for kh in 0..kernel_height {
+                                let ih = oh * stride_height + kh;
+
+                                for kw in 0..kernel_width {
+                                    let iw = ow * stride_width + kw;
+                                    let val = x[[b, c, id, ih, iw]];
+
+                                    if val > max_val {
+                                        max_val = val;
+
+                                        let id = id as i64 - padding_depth as i64;
+                                        let ih = ih as i64 - padding_height as i64;
+                                        let iw = iw as i64 - padding_width as i64;
+
+                                        index = (id * x_height as i64 + ih) * x_width as i64 + iw;
+                                    }
+                                }
+                            }
+                        }
+
+                        output[[b, c, od, oh, ow]] = max_val;
+                        indexes[[b, c, od, oh, ow]] = index;
+                    }
+                }
+            }
+        })
+    });
+
+    let output = NdArrayTensor::new(output.into_dyn().into_shared());
+    let indexes = NdArrayTensor::new(indexes.into_dyn().into_shared());
+
+    (output, indexes)
+}
+
+pub(crate) fn max_pool3d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    _kernel_size: [usize; 3],
+    _stride: [usize; 3],
+    _padding: [usize; 3],
+    output_grad: NdArrayTensor<E, 5>,
+    indexes: NdArrayTensor<i64, 5>,
+) -> NdArrayTensor<E, 5> {
+    let [_batch_size, _channels, depth, height, width] = output_grad.shape().dims;
+    let [batch_size, channels, depth_x, height_x, width_x] = x.shape().dims;
+
+    let output_grad = output_grad.array;
+    let indexes = indexes.array;
+
+    let mut output = Array5::zeros((batch_size, channels, depth_x, height_x, width_x));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for d in 0..depth {
+                for h in 0..height {
+                    for w in 0..width {
+                        let index = indexes[[b, c, d, h, w]] as usize;
+                        let grad = output_grad[[b, c, d, h, w]];
+
+                        let index_d = index / (height_x * width_x);
+                        let index_h = (index / width_x) % height_x;
+                        let index_w = index % width_x;
+
+                        output[[b, c, index_d, index_h, index_w]] += grad;
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -5,8 +5,12 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod adaptive_avgpool;
+pub(crate) mod adaptive_maxpool;
+pub(crate) mod attention;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
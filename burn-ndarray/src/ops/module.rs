@@ -1,7 +1,14 @@
 use super::{
-    avgpool::{avg_pool2d, avg_pool2d_backward},
-    conv::{conv2d, conv_transpose2d},
-    maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indexes},
+    adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    adaptive_maxpool::{adaptive_max_pool2d_backward, adaptive_max_pool2d_with_indexes},
+    attention::{scaled_dot_product_attention, scaled_dot_product_attention_backward},
+    avgpool::{avg_pool2d, avg_pool2d_backward, avg_pool3d, avg_pool3d_backward},
+    conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
+    interpolate::{interpolate, interpolate_backward},
+    maxpool::{
+        max_pool2d, max_pool2d_backward, max_pool2d_with_indexes, max_pool3d, max_pool3d_backward,
+        max_pool3d_with_indexes,
+    },
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
 use burn_tensor::ops::*;
@@ -25,6 +32,24 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
         conv_transpose2d(x, weight, bias, options)
     }
 
+    fn conv3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv3d(x, weight, bias, options)
+    }
+
+    fn conv_transpose3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv_transpose3d(x, weight, bias, options)
+    }
+
     fn avg_pool2d(
         x: NdArrayTensor<E, 4>,
         kernel_size: [usize; 2],
@@ -81,4 +106,130 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
             indexes,
         ))
     }
+
+    fn adaptive_avg_pool2d(x: NdArrayTensor<E, 4>, output_size: [usize; 2]) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d(x, output_size)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+    ) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d_backward(x, grad)
+    }
+
+    fn adaptive_max_pool2d_with_indexes(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndexes<NdArrayBackend<E>> {
+        let (output, indexes) = adaptive_max_pool2d_with_indexes(x, output_size);
+
+        AdaptiveMaxPool2dWithIndexes::new(output, indexes)
+    }
+
+    fn adaptive_max_pool2d_with_indexes_backward(
+        x: NdArrayTensor<E, 4>,
+        output_grad: NdArrayTensor<E, 4>,
+        indexes: NdArrayTensor<i64, 4>,
+    ) -> AdaptiveMaxPool2dBackward<NdArrayBackend<E>> {
+        AdaptiveMaxPool2dBackward::new(adaptive_max_pool2d_backward(x, output_grad, indexes))
+    }
+
+    fn avg_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d(x, kernel_size, stride, padding)
+    }
+
+    fn avg_pool3d_backward(
+        x: NdArrayTensor<E, 5>,
+        grad: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d_backward(x, grad, kernel_size, stride, padding)
+    }
+
+    fn max_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        max_pool3d(x, kernel_size, stride, padding)
+    }
+
+    fn max_pool3d_with_indexes(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<NdArrayBackend<E>> {
+        let (output, indexes) = max_pool3d_with_indexes(x, kernel_size, stride, padding);
+
+        MaxPool3dWithIndexes::new(output, indexes)
+    }
+
+    fn max_pool3d_with_indexes_backward(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: NdArrayTensor<E, 5>,
+        indexes: NdArrayTensor<i64, 5>,
+    ) -> MaxPool3dBackward<NdArrayBackend<E>> {
+        MaxPool3dBackward::new(max_pool3d_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            output_grad,
+            indexes,
+        ))
+    }
+
+    fn interpolate(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate(x, output_size, options)
+    }
+
+    fn interpolate_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate_backward(x, grad, output_size, options)
+    }
+
+    fn scaled_dot_product_attention(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        options: AttentionOptions,
+    ) -> NdArrayTensor<E, 4> {
+        scaled_dot_product_attention(query, key, value, mask, options)
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        output_grad: NdArrayTensor<E, 4>,
+        options: AttentionOptions,
+    ) -> ScaledDotProductAttentionBackward<NdArrayBackend<E>> {
+        let (query_grad, key_grad, value_grad) =
+            scaled_dot_product_attention_backward(query, key, value, mask, output_grad, options);
+
+        ScaledDotProductAttentionBackward::new(query_grad, key_grad, value_grad)
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
 use burn_tensor::ops::TensorOps;
-use ndarray::Array4;
+use ndarray::{Array4, Array5};
 
 pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -31,3 +31,41 @@ pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
 
     x_new
 }
+
+pub(crate) fn apply_padding_5d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    padding: [usize; 3],
+    elem: E,
+) -> NdArrayTensor<E, 5> {
+    let [batch_size, input_channels, depth, height, width] = x.shape().dims;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let padded_depth = depth + 2 * padding_depth;
+    let padded_height = height + 2 * padding_height;
+    let padded_width = width + 2 * padding_width;
+
+    let x_new = Array5::from_elem(
+        (
+            batch_size,
+            input_channels,
+            padded_depth,
+            padded_height,
+            padded_width,
+        ),
+        elem,
+    );
+    let mut x_new = NdArrayTensor::new(x_new.into_shared().into_dyn());
+
+    x_new = NdArrayBackend::index_assign(
+        x_new,
+        [
+            0..batch_size,
+            0..input_channels,
+            padding_depth..depth + padding_depth,
+            padding_height..height + padding_height,
+            padding_width..width + padding_width,
+        ],
+        x,
+    );
+
+    x_new
+}
@@ -14,7 +14,7 @@ use burn_tensor::Distribution;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{atan2, ceil, cos, erf, floor, pow, rint, sin, tanh};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -318,6 +318,22 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        NdArrayMathOps::prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::prod_dim(tensor, dim)
+    }
+
+    fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -416,7 +432,145 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn abs<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| match a < 0.elem() {
+                true => 0.elem::<E>() - a,
+                false => a,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn sign<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| {
+                if a > 0.elem() {
+                    1.elem()
+                } else if a < 0.elem() {
+                    (-1).elem()
+                } else {
+                    a
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| floor(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| ceil(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        // Rounds halfway values to the nearest even integer.
+        let array = tensor
+            .array
+            .mapv_into(|a| rint(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn recip<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| 1.elem::<E>() / a).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp_min<const D: usize>(tensor: NdArrayTensor<E, D>, min: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| match a < min {
+                true => min,
+                false => a,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp_max<const D: usize>(tensor: NdArrayTensor<E, D>, max: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| match a > max {
+                true => max,
+                false => a,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp<const D: usize>(tensor: NdArrayTensor<E, D>, min: E, max: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| {
+                if a < min {
+                    min
+                } else if a > max {
+                    max
+                } else {
+                    a
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn atan2<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |lhs, rhs| {
+            atan2(lhs.to_f64().unwrap(), rhs.to_f64().unwrap()).elem()
+        })
+    }
+
+    fn pow<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |lhs, rhs| {
+            pow(lhs.to_f64().unwrap(), rhs.to_f64().unwrap()).elem()
+        })
+    }
+
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::argsort(tensor, dim, descending)
+    }
 }
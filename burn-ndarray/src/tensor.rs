@@ -59,12 +59,9 @@ macro_rules! reshape {
         array $array:expr
     ) => {{
         let dim = $crate::to_typed_dims!($n, $shape.dims, justdim);
-        let safe_into_shape =
-            $array.is_standard_layout() ||
-            (
-                $array.ndim() > 1 &&
-                $array.raw_view().reversed_axes().is_standard_layout()
-            );
+        // Only arrays in standard layout can change shape without relayout: `into_shape` reads
+        // column major arrays in column major order, which doesn't preserve the logical order.
+        let safe_into_shape = $array.is_standard_layout();
 
         let array: ndarray::ArcArray<$ty, Dim<[usize; $n]>> = match safe_into_shape {
             true => $array
@@ -305,6 +305,26 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn prod<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
+        let tensor = tensor.tensor.prod(E::KIND);
+        TchTensor::new(tensor)
+    }
+
+    pub fn prod_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(
+            tensor.tensor.prod_dim_int(dim as i64, true, E::KIND),
+            tensor.storage,
+        )
+    }
+
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumsum(dim as i64, E::KIND), tensor.storage)
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumprod(dim as i64, E::KIND), tensor.storage)
+    }
+
     pub fn argmax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<i64, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.argmax(dim as i64, true);
@@ -358,4 +378,37 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
 
         (tensor, indexes)
     }
+
+    pub fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let (tensor, _indices) = tensor.tensor.sort(dim as i64, descending);
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.sort(dim as i64, descending);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchTensor::new(tensor.tensor.argsort(dim as i64, descending))
+    }
 }
@@ -104,4 +104,79 @@ impl<E: TchElement> BoolTensorOps<TchBackend<E>> for TchBackend<E> {
         let tensor = tensor.tensor.to_kind(E::KIND);
         TchTensor::new(tensor)
     }
+
+    fn bool_and<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_and_(rhs),
+            |lhs, rhs| rhs.logical_and_(lhs),
+            |lhs, rhs| lhs.logical_and(rhs),
+        )
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_or_(rhs),
+            |lhs, rhs| rhs.logical_or_(lhs),
+            |lhs, rhs| lhs.logical_or(rhs),
+        )
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_xor_(rhs),
+            |lhs, rhs| rhs.logical_xor_(lhs),
+            |lhs, rhs| lhs.logical_xor(rhs),
+        )
+    }
+
+    fn bool_not<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<bool, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.logical_not_(),
+            |tensor| tensor.logical_not(),
+        )
+    }
+
+    fn bool_any<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<bool, 1> {
+        TchTensor::new(tensor.tensor.any().reshape([1]))
+    }
+
+    fn bool_any_dim<const D: usize>(tensor: TchTensor<bool, D>, dim: usize) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.any_dim(dim as i64, true))
+    }
+
+    fn bool_all<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<bool, 1> {
+        TchTensor::new(tensor.tensor.all().reshape([1]))
+    }
+
+    fn bool_all_dim<const D: usize>(tensor: TchTensor<bool, D>, dim: usize) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.all_dim(dim as i64, true))
+    }
+
+    fn bool_nonzero<const D: usize>(tensor: TchTensor<bool, D>) -> Vec<TchTensor<i64, 1>> {
+        tensor
+            .tensor
+            .nonzero_numpy()
+            .into_iter()
+            .map(TchTensor::new)
+            .collect()
+    }
+
+    fn bool_argwhere<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<i64, 2> {
+        TchTensor::new(tensor.tensor.nonzero())
+    }
 }
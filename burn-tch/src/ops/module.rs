@@ -1,6 +1,8 @@
 use crate::{element::TchElement, TchBackend, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndexes, ModuleOps,
+    AdaptiveMaxPool2dBackward, AdaptiveMaxPool2dWithIndexes, ConvOptions, ConvTransposeOptions,
+    InterpolateMode, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndexes,
+    MaxPool3dBackward, MaxPool3dWithIndexes, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
@@ -86,6 +88,45 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.dilation.map(|i| i as i64),
+            options.groups as i64,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn conv_transpose3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv_transpose3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.padding_out.map(|i| i as i64),
+            options.groups as i64,
+            options.dilation.map(|i| i as i64),
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose1d(
         x: TchTensor<E, 3>,
         weight: TchTensor<E, 3>,
@@ -220,4 +261,229 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
 
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
+
+    fn adaptive_avg_pool2d(x: TchTensor<E, 4>, output_size: [usize; 2]) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::adaptive_avg_pool2d(
+            &x.tensor,
+            [output_size[0] as i64, output_size[1] as i64],
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool2d_backward(x: TchTensor<E, 4>, grad: TchTensor<E, 4>) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool2d_backward(&grad.tensor, &x.tensor);
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_max_pool2d_with_indexes(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndexes<TchBackend<E>> {
+        let (tensor, indexes) = tch::Tensor::adaptive_max_pool2d(
+            &x.tensor,
+            [output_size[0] as i64, output_size[1] as i64],
+        );
+
+        AdaptiveMaxPool2dWithIndexes::new(TchTensor::new(tensor), TchTensor::new(indexes))
+    }
+
+    fn adaptive_max_pool2d_with_indexes_backward(
+        x: TchTensor<E, 4>,
+        output_grad: TchTensor<E, 4>,
+        indexes: TchTensor<i64, 4>,
+    ) -> AdaptiveMaxPool2dBackward<TchBackend<E>> {
+        let grad = tch::Tensor::adaptive_max_pool2d_backward(
+            &output_grad.tensor,
+            &x.tensor,
+            &indexes.tensor,
+        );
+
+        AdaptiveMaxPool2dBackward::new(TchTensor::new(grad))
+    }
+
+    fn max_pool1d(
+        x: TchTensor<E, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> TchTensor<E, 3> {
+        let tensor = tch::Tensor::max_pool1d(
+            &x.tensor,
+            [kernel_size as i64],
+            [stride as i64],
+            [padding as i64],
+            [1],
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            true,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor<E, 5>,
+        grad: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            true,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::max_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indexes(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<TchBackend<E>> {
+        let (tensor, indexes) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+        );
+
+        MaxPool3dWithIndexes::new(TchTensor::new(tensor), TchTensor::new(indexes))
+    }
+
+    fn max_pool3d_with_indexes_backward(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        output_grad: TchTensor<E, 5>,
+        indexes: TchTensor<i64, 5>,
+    ) -> MaxPool3dBackward<TchBackend<E>> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            [1, 1, 1],
+            false,
+            &indexes.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
+    fn interpolate(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> TchTensor<E, 4> {
+        let output_size = [output_size[0] as i64, output_size[1] as i64];
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => {
+                tch::Tensor::upsample_nearest2d(&x.tensor, output_size, None, None)
+            }
+            InterpolateMode::Bilinear => tch::Tensor::upsample_bilinear2d(
+                &x.tensor,
+                output_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d(
+                &x.tensor,
+                output_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn interpolate_backward(
+        x: TchTensor<E, 4>,
+        grad: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> TchTensor<E, 4> {
+        let input_size = x.tensor.size();
+        let output_size = [output_size[0] as i64, output_size[1] as i64];
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => tch::Tensor::upsample_nearest2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                None,
+                None,
+            ),
+            InterpolateMode::Bilinear => tch::Tensor::upsample_bilinear2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+        };
+
+        TchTensor::new(tensor)
+    }
 }
@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Bool, Data, Int, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
@@ -23,4 +27,128 @@ where
     pub fn into_int(self) -> Tensor<B, D, Int> {
         Tensor::new(B::bool_into_int(self.primitive))
     }
+
+    /// Applies element wise logical and.
+    ///
+    /// `y = x2 & x1`
+    pub fn bool_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("And", &self, &other));
+        Self::new(B::bool_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical or.
+    ///
+    /// `y = x2 | x1`
+    pub fn bool_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Or", &self, &other));
+        Self::new(B::bool_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical exclusive or.
+    ///
+    /// `y = x2 ^ x1`
+    pub fn bool_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Xor", &self, &other));
+        Self::new(B::bool_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical not.
+    ///
+    /// `y = !x`
+    pub fn bool_not(self) -> Self {
+        Self::new(B::bool_not(self.primitive))
+    }
+
+    /// Tests if any element of the tensor is true.
+    pub fn any(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_any(self.primitive))
+    }
+
+    /// Tests if any element along the given *dimension* or *axis* of the tensor is true.
+    pub fn any_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Any", dim));
+        Self::new(B::bool_any_dim(self.primitive, dim))
+    }
+
+    /// Tests if all the elements of the tensor are true.
+    pub fn all(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_all(self.primitive))
+    }
+
+    /// Tests if all the elements along the given *dimension* or *axis* of the tensor are true.
+    pub fn all_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("All", dim));
+        Self::new(B::bool_all_dim(self.primitive, dim))
+    }
+
+    /// Returns the indexes of the true elements, with one tensor per dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Data, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+    ///     let indexes = tensor.nonzero();
+    ///     println!("{} {}", indexes[0], indexes[1]);
+    ///     // [0, 1] [0, 1]
+    /// }
+    /// ```
+    pub fn nonzero(self) -> Vec<Tensor<B, 1, Int>> {
+        B::bool_nonzero(self.primitive)
+            .into_iter()
+            .map(Tensor::new)
+            .collect()
+    }
+
+    /// Returns the indexes of the true elements, one row of `D` indexes per element.
+    pub fn argwhere(self) -> Tensor<B, 2, Int> {
+        Tensor::new(B::bool_argwhere(self.primitive))
+    }
+}
+
+impl<B, const D: usize> core::ops::BitAnd<Self> for Tensor<B, D, Bool>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self::bool_and(self, rhs)
+    }
+}
+
+impl<B, const D: usize> core::ops::BitOr<Self> for Tensor<B, D, Bool>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::bool_or(self, rhs)
+    }
+}
+
+impl<B, const D: usize> core::ops::BitXor<Self> for Tensor<B, D, Bool>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Self::bool_xor(self, rhs)
+    }
+}
+
+impl<B, const D: usize> core::ops::Not for Tensor<B, D, Bool>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::bool_not(self)
+    }
 }
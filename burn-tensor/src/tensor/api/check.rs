@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, BasicOps, PadMode, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -80,6 +80,112 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn topk<const D: usize>(shape: &Shape<D>, k: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("TopK", dim);
+
+        if dim < D && k > shape.dims[dim] {
+            check = check.register(
+                "TopK",
+                TensorError::new(
+                    "The number of elements to select is higher than the dimension size.",
+                )
+                .details(format!(
+                    "Dimension size: '{}', given k: '{k}'.",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for dim in dims.iter().filter(|dim| **dim >= D) {
+            check = check.register(
+                "Flip",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                "Roll",
+                TensorError::new("The number of shifts should match the number of dimensions.")
+                    .details(format!(
+                        "Number of shifts: '{}', number of dimensions: '{}'.",
+                        shifts.len(),
+                        dims.len()
+                    )),
+            );
+        }
+
+        for dim in dims.iter().filter(|dim| **dim >= D) {
+            check = check.register(
+                "Roll",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        padding: &[(usize, usize); D],
+        mode: &PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (dim, (size, (before, after))) in shape.dims.iter().zip(padding).enumerate() {
+            let padding = usize::max(*before, *after);
+            let valid = match mode {
+                PadMode::Constant(_) => true,
+                PadMode::Reflect => padding < *size,
+                PadMode::Replicate => padding == 0 || *size > 0,
+                PadMode::Circular => padding <= *size,
+            };
+
+            if !valid {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(format!(
+                        "The padding is too large for the dimension size in {mode:?} mode."
+                    ))
+                    .details(format!(
+                        "Dimension: '{dim}', dimension size: '{size}', given padding: \
+                         '({before}, {after})'."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn triangular<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn reshape<const D1: usize, const D2: usize>(
         original: &Shape<D1>,
         target: &Shape<D2>,
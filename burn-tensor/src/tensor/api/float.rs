@@ -7,6 +7,7 @@ use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
+use crate::ElementConversion;
 use crate::Tensor;
 
 impl<const D: usize, B> Tensor<B, D>
@@ -96,6 +97,79 @@ where
         Self::new(B::tanh(self.primitive))
     }
 
+    /// Applies element wise absolute value operation.
+    ///
+    /// `y = |x|`
+    pub fn abs(self) -> Self {
+        Self::new(B::abs(self.primitive))
+    }
+
+    /// Applies element wise sign operation, returning `-1`, `0` or `1`.
+    pub fn sign(self) -> Self {
+        Self::new(B::sign(self.primitive))
+    }
+
+    /// Rounds the elements down to the nearest integer.
+    pub fn floor(self) -> Self {
+        Self::new(B::floor(self.primitive))
+    }
+
+    /// Rounds the elements up to the nearest integer.
+    pub fn ceil(self) -> Self {
+        Self::new(B::ceil(self.primitive))
+    }
+
+    /// Rounds the elements to the nearest integer, with halfway values rounded to the nearest
+    /// even integer.
+    pub fn round(self) -> Self {
+        Self::new(B::round(self.primitive))
+    }
+
+    /// Applies element wise reciprocal operation.
+    ///
+    /// `y = 1 / x`
+    pub fn recip(self) -> Self {
+        Self::new(B::recip(self.primitive))
+    }
+
+    /// Clamps the elements between `min` and `max`.
+    ///
+    /// `y = min(max(x, min), max)`
+    pub fn clamp<E: ElementConversion>(self, min: E, max: E) -> Self {
+        Self::new(B::clamp(self.primitive, min.elem(), max.elem()))
+    }
+
+    /// Clamps the elements to be greater or equal to `min`.
+    ///
+    /// `y = max(x, min)`
+    pub fn clamp_min<E: ElementConversion>(self, min: E) -> Self {
+        Self::new(B::clamp_min(self.primitive, min.elem()))
+    }
+
+    /// Clamps the elements to be lower or equal to `max`.
+    ///
+    /// `y = min(x, max)`
+    pub fn clamp_max<E: ElementConversion>(self, max: E) -> Self {
+        Self::new(B::clamp_max(self.primitive, max.elem()))
+    }
+
+    /// Applies element wise four-quadrant arctangent operation, with `self` as the `y`
+    /// coordinates and `other` as the `x` coordinates.
+    ///
+    /// `y = atan2(x2, x1)`
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(B::atan2(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise power operation with a tensor exponent.
+    ///
+    /// `y = x2^x1`
+    pub fn pow(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Pow", &self, &other));
+        Self::new(B::pow(self.primitive, other.primitive))
+    }
+
     /// Create a tensor from floats (f32).
     ///
     /// # Example
@@ -212,6 +286,20 @@ where
         (var, mean)
     }
 
+    /// Calculate the logarithm of the sum of the exponentials along the given dimension.
+    ///
+    /// `logsumexp(x) = max(x) + log(sum_j(exp(x_j - max(x))))`
+    ///
+    /// The maximum is subtracted before the exponentials to avoid overflows.
+    pub fn logsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Logsumexp", dim));
+
+        let max = self.clone().detach().max_dim(dim);
+        let tensor = self.sub(max.clone()).exp().sum_dim(dim).log();
+
+        tensor.add(max)
+    }
+
     /// Create a random tensor of the given shape where each element is sampled from the given
     /// distribution.
     pub fn random<S: Into<Shape<D>>>(shape: S, distribution: Distribution<B::FloatElem>) -> Self {
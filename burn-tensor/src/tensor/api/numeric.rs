@@ -1,6 +1,9 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Element, ElementConversion,
+    Float, Int, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -133,6 +136,43 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Aggregate all elements in the tensor with the product operation.
+    pub fn prod(self) -> Tensor<B, 1, K> {
+        Tensor::new(K::prod(self.primitive))
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the product operation.
+    pub fn prod_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Prod", dim));
+        Self::new(K::prod_dim(self.primitive, dim))
+    }
+
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.cumsum(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumprod", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -386,6 +426,363 @@ where
 
         (tensor, index)
     }
+
+    /// Sort the elements along the given dimension in ascending order.
+    pub fn sort(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        Tensor::new(K::sort(self.primitive, dim, false))
+    }
+
+    /// Sort the elements along the given dimension in descending order.
+    pub fn sort_descending(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        Tensor::new(K::sort(self.primitive, dim, true))
+    }
+
+    /// Sort the elements along the given dimension in ascending order.
+    ///
+    /// Also returns the indexes of the sorted elements in the original tensor.
+    pub fn sort_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (tensor, indices) = K::sort_with_indices(self.primitive, dim, false);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Sort the elements along the given dimension in descending order.
+    ///
+    /// Also returns the indexes of the sorted elements in the original tensor.
+    pub fn sort_descending_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (tensor, indices) = K::sort_with_indices(self.primitive, dim, true);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Returns the indexes that sort the elements along the given dimension in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+    ///     let tensor = tensor.argsort(1);
+    ///     println!("{}", tensor);
+    ///     // [[1, 2, 0], [0, 2, 1]]
+    /// }
+    /// ```
+    pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+
+        Tensor::new(K::argsort(self.primitive, dim, false))
+    }
+
+    /// Returns the indexes that sort the elements along the given dimension in descending order.
+    pub fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+
+        Tensor::new(K::argsort(self.primitive, dim, true))
+    }
+
+    /// Returns the `k` largest elements along the given dimension, in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+    ///     let tensor = tensor.topk(2, 1);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0], [5.0, 4.0]]
+    /// }
+    /// ```
+    pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::topk::<D>(&self.shape(), k, dim));
+
+        let tensor = self.sort_descending(dim);
+        let ranges = topk_ranges(&tensor.shape(), k, dim);
+
+        tensor.index(ranges)
+    }
+
+    /// Returns the `k` largest elements along the given dimension, in descending order.
+    ///
+    /// Also returns their indexes in the original tensor.
+    pub fn topk_with_indices(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(&self.shape(), k, dim));
+
+        let (tensor, indices) = self.sort_descending_with_indices(dim);
+        let ranges = topk_ranges(&tensor.shape(), k, dim);
+
+        (tensor.index(ranges.clone()), indices.index(ranges))
+    }
+
+    /// Reverses the order of the elements along each of the given dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.flip(&[1]);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]
+    /// }
+    /// ```
+    pub fn flip(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(dims));
+
+        let shape = self.shape();
+        let device = self.device();
+
+        dims.iter().fold(self, |tensor, dim| {
+            let size = shape.dims[*dim];
+            let indexes = index_tensor::<B, _>((0..size).rev(), &device);
+
+            tensor.index_select(*dim, indexes)
+        })
+    }
+
+    /// Shifts the elements along each of the given dimensions by the corresponding amount.
+    ///
+    /// Elements shifted beyond the last position are re-introduced at the first position, and
+    /// negative shifts move the elements toward the first position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.roll(&[1], &[1]);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+
+        let shape = self.shape();
+        let device = self.device();
+
+        shifts.iter().zip(dims).fold(self, |tensor, (shift, dim)| {
+            let size = shape.dims[*dim];
+            if size == 0 {
+                return tensor;
+            }
+
+            let shift = shift.rem_euclid(size as i64) as usize;
+            if shift == 0 {
+                return tensor;
+            }
+
+            let indexes =
+                index_tensor::<B, _>((0..size).map(|i| (i + size - shift) % size), &device);
+
+            tensor.index_select(*dim, indexes)
+        })
+    }
+
+    /// Pads the tensor with the given number of elements `(before, after)` on each dimension.
+    ///
+    /// See [PadMode] for how the new elements are computed.
+    ///
+    /// # Panics
+    ///
+    /// If the padding is too large for the dimension size with the given mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0, 1.0, 2.0, 3.0, 2.0], [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]]
+    /// }
+    /// ```
+    pub fn pad(self, padding: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad::<D>(&self.shape(), &padding, &mode));
+
+        let shape = self.shape();
+        let device = self.device();
+
+        if let PadMode::Constant(value) = mode {
+            let mut dims = shape.dims;
+            let mut ranges = shape.dims.map(|size| 0..size);
+
+            for (dim, (before, after)) in padding.iter().enumerate() {
+                dims[dim] += before + after;
+                ranges[dim] = *before..before + shape.dims[dim];
+            }
+
+            return Self::zeros_device(dims, &device)
+                .add_scalar(value)
+                .index_assign(ranges, self);
+        }
+
+        padding
+            .iter()
+            .enumerate()
+            .fold(self, |tensor, (dim, (before, after))| {
+                if before + after == 0 {
+                    return tensor;
+                }
+
+                let size = shape.dims[dim] as i64;
+                let positions = -(*before as i64)..size + *after as i64;
+                let indexes = index_tensor::<B, _>(
+                    positions.map(|position| pad_index(position, size, &mode)),
+                    &device,
+                );
+
+                tensor.index_select(dim, indexes)
+            })
+    }
+
+    /// Returns the lower triangular part of the matrices formed by the last two dimensions, the
+    /// other elements being set to zero.
+    ///
+    /// The `diagonal` offset selects which diagonal is the upper bound: `0` is the main diagonal,
+    /// a positive value includes diagonals above it and a negative value excludes diagonals below
+    /// it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.tril(0);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 0.0, 0.0], [4.0, 5.0, 0.0]]
+    /// }
+    /// ```
+    pub fn tril(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Tril"));
+
+        let mask = triangular_mask::<B, D, _>(&self.shape(), &self.device(), |offsets| {
+            offsets.greater_elem(diagonal)
+        });
+
+        self.mask_fill(mask, 0)
+    }
+
+    /// Returns the upper triangular part of the matrices formed by the last two dimensions, the
+    /// other elements being set to zero.
+    ///
+    /// The `diagonal` offset selects which diagonal is the lower bound: `0` is the main diagonal,
+    /// a positive value excludes diagonals above it and a negative value includes diagonals below
+    /// it.
+    pub fn triu(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Triu"));
+
+        let mask = triangular_mask::<B, D, _>(&self.shape(), &self.device(), |offsets| {
+            offsets.lower_elem(diagonal)
+        });
+
+        self.mask_fill(mask, 0)
+    }
+}
+
+fn topk_ranges<const D: usize>(shape: &Shape<D>, k: usize, dim: usize) -> [Range<usize>; D] {
+    let mut ranges = shape.dims.map(|size| 0..size);
+    ranges[dim] = 0..k;
+
+    ranges
+}
+
+/// How the new elements are computed when [padding](Tensor::pad) a tensor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PadMode {
+    /// Fills the new elements with the given value.
+    Constant(f64),
+    /// Mirrors the elements at the border, without repeating the border element.
+    ///
+    /// `[1, 2, 3]` padded with `(2, 2)` gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// Repeats the border element.
+    ///
+    /// `[1, 2, 3]` padded with `(2, 2)` gives `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+    /// Wraps around to the elements at the opposite border.
+    ///
+    /// `[1, 2, 3]` padded with `(2, 2)` gives `[2, 3, 1, 2, 3, 1, 2]`.
+    Circular,
+}
+
+/// Maps a position in the padded dimension to the index of the element it copies.
+fn pad_index(position: i64, size: i64, mode: &PadMode) -> usize {
+    let index = match mode {
+        PadMode::Reflect if position < 0 => -position,
+        PadMode::Reflect if position >= size => 2 * (size - 1) - position,
+        PadMode::Reflect => position,
+        PadMode::Replicate => position.clamp(0, size - 1),
+        PadMode::Circular => position.rem_euclid(size),
+        PadMode::Constant(_) => unreachable!("Constant padding doesn't copy elements"),
+    };
+
+    index as usize
+}
+
+fn index_tensor<B: Backend, I: Iterator<Item = usize>>(
+    indexes: I,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let indexes: Vec<B::IntElem> = indexes.map(|index| (index as i64).elem()).collect();
+    let size = indexes.len();
+
+    Tensor::from_data_device(Data::new(indexes, Shape::new([size])), device)
+}
+
+/// Creates a mask over the last two dimensions from the offset `column - row` of each element.
+fn triangular_mask<B, const D: usize, F>(
+    shape: &Shape<D>,
+    device: &B::Device,
+    masked: F,
+) -> Tensor<B, D, Bool>
+where
+    B: Backend,
+    F: FnOnce(Tensor<B, 2, Int>) -> Tensor<B, 2, Bool>,
+{
+    let rows = shape.dims[D - 2];
+    let columns = shape.dims[D - 1];
+
+    let row_indexes = Tensor::<B, 1, Int>::arange_device(0..rows, device)
+        .reshape([rows, 1])
+        .repeat(1, columns);
+    let column_indexes = Tensor::<B, 1, Int>::arange_device(0..columns, device)
+        .reshape([1, columns])
+        .repeat(0, rows);
+    let mask = masked(column_indexes.sub(row_indexes));
+
+    let mut dims = [1; D];
+    dims[D - 2] = rows;
+    dims[D - 1] = columns;
+
+    (0..D - 2).fold(mask.reshape(dims), |mask, dim| {
+        mask.repeat(dim, shape.dims[dim])
+    })
 }
 
 /// Trait that list all operations that can be applied on all numerical tensors.
@@ -680,6 +1077,89 @@ where
     /// which is more high-level and designed for public use.
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Computes the product of all the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// The product of all the elements of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the product of all the elements of a tensor, users should prefer the [Tensor::prod](Tensor::prod) function,
+    /// which is more high-level and designed for public use.
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+
+    /// Computes the product of all the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension along which to compute the product.
+    ///
+    /// # Returns
+    ///
+    /// The product of all the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the product of all the elements of a tensor along a dimension, users should prefer the [Tensor::prod_dim](Tensor::prod_dim) function,
+    /// which is more high-level and designed for public use.
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with the cumulative sum along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of a tensor along a dimension, users should prefer the [Tensor::cumsum](Tensor::cumsum) function,
+    /// which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with the cumulative product along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of a tensor along a dimension, users should prefer the [Tensor::cumprod](Tensor::cumprod) function,
+    /// which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -1296,6 +1776,86 @@ where
         tensor: Self::Primitive<D>,
         dim: usize,
     ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with its elements sorted along the
+    /// specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For sorting the elements of a tensor along an axis, users should prefer the
+    /// [Tensor::sort](Tensor::sort) function, which is more high-level and designed for public use.
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D>;
+
+    /// Sorts the elements of a tensor along an axis and returns their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted tensor and the indices of the sorted elements in the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For sorting the elements of a tensor along an axis, users should prefer the
+    /// [Tensor::sort_with_indices](Tensor::sort_with_indices) function, which is more high-level and designed for public use.
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
+    /// Gets the indices that sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the index in the
+    /// input tensor of the sorted element at the corresponding position along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the indices that sort a tensor along an axis, users should prefer the
+    /// [Tensor::argsort](Tensor::argsort) function, which is more high-level and designed for public use.
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -1362,6 +1922,18 @@ impl<B: Backend> Numeric<B> for Int {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::int_sum_dim(tensor, dim)
     }
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::int_prod(tensor)
+    }
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_prod_dim(tensor, dim)
+    }
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_mean(tensor)
     }
@@ -1517,6 +2089,30 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
         B::int_min_dim_with_indexes(tensor, dim)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -1583,6 +2179,18 @@ impl<B: Backend> Numeric<B> for Float {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::sum_dim(tensor, dim)
     }
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::prod(tensor)
+    }
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::prod_dim(tensor, dim)
+    }
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumsum(tensor, dim)
+    }
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumprod(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::mean(tensor)
     }
@@ -1739,6 +2347,30 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
         B::min_dim_with_indexes(tensor, dim)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::argsort(tensor, dim, descending)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
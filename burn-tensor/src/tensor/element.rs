@@ -11,6 +11,7 @@ pub trait Element:
     + ElementPrecision
     + core::fmt::Debug
     + core::fmt::Display
+    + PartialOrd
     + Default
     + Send
     + Sync
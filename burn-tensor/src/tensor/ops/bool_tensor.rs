@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
-use crate::{backend::Backend, tensor::Shape, Data};
+use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 
 /// Bool Tensor API for basic operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -230,4 +230,194 @@ pub trait BoolTensorOps<B: Backend> {
         lhs: B::BoolTensorPrimitive<D>,
         rhs: bool,
     ) -> B::BoolTensorPrimitive<D>;
+
+    /// Element-wise logical and.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor that is true where both `lhs` and `rhs` are true.
+    fn bool_and<const D: usize>(
+        lhs: B::BoolTensorPrimitive<D>,
+        rhs: B::BoolTensorPrimitive<D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        let tensor = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(tensor, 2.elem())
+    }
+
+    /// Element-wise logical or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor that is true where `lhs` or `rhs` is true.
+    fn bool_or<const D: usize>(
+        lhs: B::BoolTensorPrimitive<D>,
+        rhs: B::BoolTensorPrimitive<D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        let tensor = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(tensor, 0.elem())
+    }
+
+    /// Element-wise logical exclusive or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor that is true where exactly one of `lhs` and `rhs` is true.
+    fn bool_xor<const D: usize>(
+        lhs: B::BoolTensorPrimitive<D>,
+        rhs: B::BoolTensorPrimitive<D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        let tensor = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(tensor, 1.elem())
+    }
+
+    /// Element-wise logical not.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with all the values inverted.
+    fn bool_not<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        Self::bool_equal_elem(tensor, false)
+    }
+
+    /// Tests if any element of the tensor is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a single element, true if any element of `tensor` is true.
+    fn bool_any<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<1> {
+        let sum = B::int_sum(B::bool_into_int(tensor));
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Tests if any element of the tensor is true along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the size of `dim` reduced to one, true where any element along `dim` is true.
+    fn bool_any_dim<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D> {
+        let sum = B::int_sum_dim(B::bool_into_int(tensor), dim);
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Tests if all the elements of the tensor are true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a single element, true if all the elements of `tensor` are true.
+    fn bool_all<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<1> {
+        Self::bool_not(Self::bool_any(Self::bool_not(tensor)))
+    }
+
+    /// Tests if all the elements of the tensor are true along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the size of `dim` reduced to one, true where all the elements along `dim`
+    /// are true.
+    fn bool_all_dim<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D> {
+        Self::bool_not(Self::bool_any_dim(Self::bool_not(tensor), dim))
+    }
+
+    /// Gets the indexes of the true elements, one tensor per dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `D` tensors, where the tensor at position `i` contains the indexes along the
+    /// dimension `i` of the true elements, in row-major order.
+    fn bool_nonzero<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+    ) -> Vec<B::IntTensorPrimitive<1>> {
+        let indexes = Self::bool_argwhere(tensor);
+        let [num_elements, _] = B::int_shape(&indexes).dims;
+
+        (0..D)
+            .map(|dim| {
+                let indexes = B::int_index(indexes.clone(), [0..num_elements, dim..dim + 1]);
+                B::int_reshape(indexes, Shape::new([num_elements]))
+            })
+            .collect()
+    }
+
+    /// Gets the indexes of the true elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[num_true, D]`, where each row contains the indexes of a true element,
+    /// in row-major order.
+    fn bool_argwhere<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<2> {
+        let device = Self::bool_device(&tensor);
+        let data = Self::bool_into_data(tensor);
+        let dims = data.shape.dims;
+        let mut indexes = Vec::new();
+
+        for (position, _) in data.value.iter().enumerate().filter(|(_, value)| **value) {
+            let mut remainder = position;
+            let mut index = [0; D];
+
+            for dim in (0..D).rev() {
+                index[dim] = (remainder % dims[dim]) as i64;
+                remainder /= dims[dim];
+            }
+
+            indexes.extend(index);
+        }
+
+        let num_elements = indexes.len() / D;
+        let data = Data::new(indexes, Shape::new([num_elements, D]));
+
+        B::int_from_data(data.convert(), &device)
+    }
 }
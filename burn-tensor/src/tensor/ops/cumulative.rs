@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+
+use crate::{Data, Element, ElementConversion};
+
+/// Accumulates the data along the given dimension with the provided operation.
+///
+/// Each output element is the accumulation of all the elements that precede it along `dim`,
+/// including itself. The computation is done in `f64` before converting back to the element type.
+pub(crate) fn cumulative_data<E: Element, const D: usize, F>(
+    data: &Data<E, D>,
+    dim: usize,
+    op: F,
+) -> Data<E, D>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    let dims = data.shape.dims;
+    let dim_size = dims[dim];
+    let stride: usize = dims[dim + 1..].iter().product();
+    let mut output: Vec<f64> = data.value.iter().map(|value| value.elem()).collect();
+
+    if output.is_empty() {
+        return Data::new(Vec::new(), data.shape.clone());
+    }
+
+    for outer in 0..output.len() / (dim_size * stride) {
+        for inner in 0..stride {
+            let start = outer * dim_size * stride + inner;
+
+            for index in 1..dim_size {
+                let current = start + index * stride;
+                output[current] = op(output[current - stride], output[current]);
+            }
+        }
+    }
+
+    Data::new(
+        output.into_iter().map(|value| value.elem()).collect(),
+        data.shape.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Shape;
+    use alloc::vec;
+
+    #[test]
+    fn test_cumulative_data_inner_dim() {
+        let data = Data::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], Shape::new([2, 3]));
+
+        assert_eq!(
+            cumulative_data(&data, 1, |acc, value| acc + value),
+            Data::new(vec![1.0, 3.0, 6.0, 4.0, 9.0, 15.0], Shape::new([2, 3]))
+        );
+    }
+
+    #[test]
+    fn test_cumulative_data_outer_dim() {
+        let data = Data::new(vec![1, 2, 3, 4, 5, 6], Shape::new([2, 3]));
+
+        assert_eq!(
+            cumulative_data(&data, 0, |acc, value| acc * value),
+            Data::new(vec![1, 2, 3, 4, 10, 18], Shape::new([2, 3]))
+        );
+    }
+}
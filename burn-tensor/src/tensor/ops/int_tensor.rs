@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::{cumulative, sort};
 use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
@@ -650,6 +651,90 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Computes the product of all elements in the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// The product of all elements in the tensor.
+    fn int_prod<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<1> {
+        let num_elements = B::int_shape(&tensor).num_elements();
+        let tensor = B::int_reshape(tensor, Shape::new([num_elements]));
+
+        B::int_prod_dim(tensor, 0)
+    }
+
+    /// Computes the product of all elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension to compute the product along.
+    ///
+    /// # Returns
+    ///
+    /// The product of all elements in the tensor along the dimension.
+    fn int_prod_dim<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let mut ranges = B::int_shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = ranges[dim].end - 1..ranges[dim].end;
+
+        B::int_index(B::int_cumprod(tensor, dim), ranges)
+    }
+
+    /// Computes the cumulative sum of the elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension to compute the cumulative sum along.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input, where each element is the sum of all the
+    /// elements up to and including it along the dimension.
+    fn int_cumsum<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+        let data = B::int_into_data(tensor);
+
+        B::int_from_data(
+            cumulative::cumulative_data(&data, dim, |acc, value| acc + value),
+            &device,
+        )
+    }
+
+    /// Computes the cumulative product of the elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension to compute the cumulative product along.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input, where each element is the product of all the
+    /// elements up to and including it along the dimension.
+    fn int_cumprod<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+        let data = B::int_into_data(tensor);
+
+        B::int_from_data(
+            cumulative::cumulative_data(&data, dim, |acc, value| acc * value),
+            &device,
+        )
+    }
+
     /// Gets the indices of the maximum elements along a dimension.
     ///
     /// # Arguments
@@ -789,4 +874,70 @@ pub trait IntTensorOps<B: Backend> {
 
         (values, index)
     }
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with its elements sorted along `dim`.
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their indexes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted elements of `tensor` along `dim` and their original indexes.
+    fn int_sort_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let indices = B::int_argsort(tensor.clone(), dim, descending);
+        let values = B::int_gather(dim, tensor, indices.clone());
+
+        (values, indices)
+    }
+
+    /// Gets the indexes that sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the indexes of its sorted elements along `dim`.
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+        let data = B::int_into_data(tensor);
+
+        B::int_from_data(
+            sort::argsort_data(&data, dim, descending).convert(),
+            &device,
+        )
+    }
 }
@@ -1,11 +1,14 @@
 mod activation;
 mod bool_tensor;
+mod cumulative;
 mod int_tensor;
 mod modules;
+mod sort;
 mod tensor;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
+pub use sort::compare_elements;
 pub use tensor::*;
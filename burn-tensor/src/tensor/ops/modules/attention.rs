@@ -0,0 +1,63 @@
+use super::{AttentionOptions, ScaledDotProductAttentionBackward};
+use crate::{backend::Backend, ElementConversion};
+
+pub(crate) fn scaled_dot_product_attention_from_ops<B: Backend>(
+    query: B::TensorPrimitive<4>,
+    key: B::TensorPrimitive<4>,
+    value: B::TensorPrimitive<4>,
+    mask: Option<B::BoolTensorPrimitive<4>>,
+    options: AttentionOptions,
+) -> B::TensorPrimitive<4> {
+    let weights = attention_weights::<B>(query, key, mask, &options);
+
+    B::matmul(weights, value)
+}
+
+pub(crate) fn scaled_dot_product_attention_backward_from_ops<B: Backend>(
+    query: B::TensorPrimitive<4>,
+    key: B::TensorPrimitive<4>,
+    value: B::TensorPrimitive<4>,
+    mask: Option<B::BoolTensorPrimitive<4>>,
+    output_grad: B::TensorPrimitive<4>,
+    options: AttentionOptions,
+) -> ScaledDotProductAttentionBackward<B> {
+    let weights = attention_weights::<B>(query.clone(), key.clone(), mask.clone(), &options);
+
+    let value_grad = B::matmul(B::swap_dims(weights.clone(), 2, 3), output_grad.clone());
+    let weights_grad = B::matmul(output_grad, B::swap_dims(value, 2, 3));
+
+    // Backward of the softmax: dS = P * (dP - sum(dP * P)).
+    let sum = B::sum_dim(B::mul(weights_grad.clone(), weights.clone()), 3);
+    let scores_grad = B::mul(weights, B::sub(weights_grad, sum));
+
+    // The masked scores are replaced by a constant, so they don't receive any gradient.
+    let scores_grad = match mask {
+        Some(mask) => B::mask_fill(scores_grad, mask, 0.elem()),
+        None => scores_grad,
+    };
+    let scores_grad = B::mul_scalar(scores_grad, options.scale.elem());
+
+    let query_grad = B::matmul(scores_grad.clone(), key);
+    let key_grad = B::matmul(B::swap_dims(scores_grad, 2, 3), query);
+
+    ScaledDotProductAttentionBackward::new(query_grad, key_grad, value_grad)
+}
+
+fn attention_weights<B: Backend>(
+    query: B::TensorPrimitive<4>,
+    key: B::TensorPrimitive<4>,
+    mask: Option<B::BoolTensorPrimitive<4>>,
+    options: &AttentionOptions,
+) -> B::TensorPrimitive<4> {
+    let scores = B::matmul(query, B::swap_dims(key, 2, 3));
+    let scores = B::mul_scalar(scores, options.scale.elem());
+    let scores = match mask {
+        Some(mask) => B::mask_fill(scores, mask, options.mask_value.elem()),
+        None => scores,
+    };
+
+    let scores = B::sub(scores.clone(), B::max_dim(scores, 3));
+    let scores = B::exp(scores);
+
+    B::div(scores.clone(), B::sum_dim(scores, 3))
+}
@@ -1,4 +1,4 @@
-use super::{conv, pool};
+use super::{attention, conv, interpolate, pool};
 use crate::{backend::Backend, Shape};
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -31,6 +31,53 @@ pub struct MaxPool2dWithIndexes<B: Backend> {
     pub indexes: B::IntTensorPrimitive<4>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d).
+#[derive(new)]
+pub struct AdaptiveMaxPool2dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<4>,
+}
+
+/// Results from [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d_with_indexes).
+#[derive(new)]
+pub struct AdaptiveMaxPool2dWithIndexes<B: Backend> {
+    /// The output tensor.
+    pub output: B::TensorPrimitive<4>,
+
+    /// The indexes tensor.
+    pub indexes: B::IntTensorPrimitive<4>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv3d](ModuleOps::conv3d).
+#[derive(new)]
+pub struct Conv3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<5>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indexes).
+#[derive(new)]
+pub struct MaxPool3dWithIndexes<B: Backend> {
+    /// The output tensor.
+    pub output: B::TensorPrimitive<5>,
+
+    /// The indexes tensor.
+    pub indexes: B::IntTensorPrimitive<5>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [conv1d](ModuleOps::conv1d).
 #[derive(new)]
 pub struct Conv1dBackward<B: Backend> {
@@ -44,6 +91,58 @@ pub struct Conv1dBackward<B: Backend> {
     pub bias_grad: Option<B::TensorPrimitive<1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose2d](ModuleOps::conv_transpose2d).
+#[derive(new)]
+pub struct ConvTranspose2dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<4>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<4>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose3d](ModuleOps::conv_transpose3d).
+#[derive(new)]
+pub struct ConvTranspose3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<5>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [conv_transpose1d](ModuleOps::conv_transpose1d).
+#[derive(new)]
+pub struct ConvTranspose1dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<3>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<3>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [scaled_dot_product_attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new)]
+pub struct ScaledDotProductAttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: B::TensorPrimitive<4>,
+
+    /// Key gradient.
+    pub key_grad: B::TensorPrimitive<4>,
+
+    /// Value gradient.
+    pub value_grad: B::TensorPrimitive<4>,
+}
+
 /// Convolution options.
 #[derive(new, Debug, Clone)]
 pub struct ConvOptions<const N: usize> {
@@ -60,6 +159,40 @@ pub struct ConvOptions<const N: usize> {
     pub groups: usize,
 }
 
+/// Algorithm used to compute the values of an [interpolation](ModuleOps::interpolate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Uses the value of the nearest input element.
+    Nearest,
+
+    /// Linear interpolation between the 2x2 closest input elements.
+    Bilinear,
+
+    /// Cubic convolution over the 4x4 closest input elements.
+    Bicubic,
+}
+
+/// Interpolation options.
+#[derive(new, Debug, Clone)]
+pub struct InterpolateOptions {
+    /// Interpolation algorithm.
+    pub mode: InterpolateMode,
+
+    /// Whether the corner pixels of the input and output are aligned, preserving the values at
+    /// those pixels. Ignored by the [nearest](InterpolateMode::Nearest) mode.
+    pub align_corners: bool,
+}
+
+/// Attention options.
+#[derive(new, Debug, Clone)]
+pub struct AttentionOptions {
+    /// Factor applied to the attention scores before the softmax, usually `1 / sqrt(d_k)`.
+    pub scale: f64,
+
+    /// Value given to the masked attention scores before the softmax.
+    pub mask_value: f64,
+}
+
 /// Transposed convolution options.
 #[derive(new, Debug, Clone)]
 pub struct ConvTransposeOptions<const N: usize> {
@@ -168,6 +301,72 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Backward pass for the [conv transpose 2d](ModuleOps::conv_transpose2d) operation.
+    fn conv_transpose2d_backward(
+        x: B::TensorPrimitive<4>,
+        weight: B::TensorPrimitive<4>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<4>,
+        options: ConvTransposeOptions<2>,
+    ) -> ConvTranspose2dBackward<B> {
+        conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
+    }
+    /// Three dimensional convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    ///
+    /// The default implementation applies a [conv2d](ModuleOps::conv2d) for each depth index of
+    /// the kernel.
+    fn conv3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvOptions<3>,
+    ) -> B::TensorPrimitive<5> {
+        conv::conv3d_from_conv2d::<B>(x, weight, bias, options)
+    }
+    /// Three dimensional transposed convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    ///
+    /// The default implementation applies a [conv_transpose2d](ModuleOps::conv_transpose2d) for
+    /// each depth index of the kernel.
+    fn conv_transpose3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> B::TensorPrimitive<5> {
+        conv::conv_transpose3d_from_conv_transpose2d::<B>(x, weight, bias, options)
+    }
+    /// Backward pass for the [conv3d](ModuleOps::conv3d) operation.
+    fn conv3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv3d_backward(x, weight, bias, output_grad, options)
+    }
+    /// Backward pass for the [conv transpose 3d](ModuleOps::conv_transpose3d) operation.
+    fn conv_transpose3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvTransposeOptions<3>,
+    ) -> ConvTranspose3dBackward<B> {
+        conv::conv_transpose3d_backward(x, weight, bias, output_grad, options)
+    }
     /// One dimensional convolution.
     ///
     /// # Shapes
@@ -208,6 +407,16 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv1dBackward<B> {
         conv::conv1d_backward(x, weight, bias, output_grad, options)
     }
+    /// Backward pass for the [conv transpose 1d](ModuleOps::conv_transpose1d) operation.
+    fn conv_transpose1d_backward(
+        x: B::TensorPrimitive<3>,
+        weight: B::TensorPrimitive<3>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<3>,
+        options: ConvTransposeOptions<1>,
+    ) -> ConvTranspose1dBackward<B> {
+        conv::conv_transpose1d_backward(x, weight, bias, output_grad, options)
+    }
     /// One dimensional avg pooling.
     ///
     /// # Shapes
@@ -250,6 +459,120 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
     ) -> B::TensorPrimitive<4>;
+    /// One dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_avg_pool1d(x: B::TensorPrimitive<3>, output_size: usize) -> B::TensorPrimitive<3> {
+        pool::adaptive_avg_pool1d_from_adaptive_avg_pool2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 1d](ModuleOps::adaptive_avg_pool1d) operation.
+    fn adaptive_avg_pool1d_backward(
+        x: B::TensorPrimitive<3>,
+        grad: B::TensorPrimitive<3>,
+    ) -> B::TensorPrimitive<3> {
+        pool::adaptive_avg_pool1d_backward_from_adaptive_avg_pool2d::<B>(x, grad)
+    }
+    /// Two dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    ///
+    /// The default implementation averages the windows with index selections.
+    fn adaptive_avg_pool2d(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> B::TensorPrimitive<4> {
+        pool::adaptive_avg_pool2d_from_index_select::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 2d](ModuleOps::adaptive_avg_pool2d) operation.
+    fn adaptive_avg_pool2d_backward(
+        x: B::TensorPrimitive<4>,
+        grad: B::TensorPrimitive<4>,
+    ) -> B::TensorPrimitive<4> {
+        pool::adaptive_avg_pool2d_backward_from_index_select::<B>(x, grad)
+    }
+
+    /// Two dimensional interpolation, resizing the spatial dimensions to `output_size`.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    ///
+    /// The default implementation resamples the height, then the width, with index selections.
+    fn interpolate(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> B::TensorPrimitive<4> {
+        interpolate::interpolate_from_index_select::<B>(x, output_size, options)
+    }
+    /// Backward pass for the [interpolate](ModuleOps::interpolate) operation.
+    fn interpolate_backward(
+        x: B::TensorPrimitive<4>,
+        grad: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> B::TensorPrimitive<4> {
+        interpolate::interpolate_backward_from_index_select::<B>(x, grad, output_size, options)
+    }
+
+    /// Scaled dot-product attention, `softmax(scale * query @ key^T) @ value`, where the masked
+    /// scores are replaced by the [mask value](AttentionOptions::mask_value) before the softmax.
+    ///
+    /// The default implementation materializes the full attention matrix, backends may
+    /// override it with a kernel that computes the attention by blocks of keys.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key:   `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value: `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// mask:  `[batch_size, n_heads, seq_length_1, seq_length_2]`, where the first three
+    ///        dimensions can be of size one to be broadcasted.
+    fn scaled_dot_product_attention(
+        query: B::TensorPrimitive<4>,
+        key: B::TensorPrimitive<4>,
+        value: B::TensorPrimitive<4>,
+        mask: Option<B::BoolTensorPrimitive<4>>,
+        options: AttentionOptions,
+    ) -> B::TensorPrimitive<4> {
+        attention::scaled_dot_product_attention_from_ops::<B>(query, key, value, mask, options)
+    }
+    /// Backward pass for the [scaled dot-product attention](ModuleOps::scaled_dot_product_attention) operation.
+    fn scaled_dot_product_attention_backward(
+        query: B::TensorPrimitive<4>,
+        key: B::TensorPrimitive<4>,
+        value: B::TensorPrimitive<4>,
+        mask: Option<B::BoolTensorPrimitive<4>>,
+        output_grad: B::TensorPrimitive<4>,
+        options: AttentionOptions,
+    ) -> ScaledDotProductAttentionBackward<B> {
+        attention::scaled_dot_product_attention_backward_from_ops::<B>(
+            query,
+            key,
+            value,
+            mask,
+            output_grad,
+            options,
+        )
+    }
+
+    /// One dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn max_pool1d(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> B::TensorPrimitive<3> {
+        pool::max_pool1d_from_max_pool2d::<B>(x, kernel_size, stride, padding)
+    }
 
     /// Two dimensional max pooling.
     ///
@@ -283,4 +606,108 @@ pub trait ModuleOps<B: Backend> {
         output_grad: B::TensorPrimitive<4>,
         indexes: B::IntTensorPrimitive<4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> B::TensorPrimitive<4> {
+        Self::adaptive_max_pool2d_with_indexes(x, output_size).output
+    }
+
+    /// Two dimensional adaptive max pooling with indexes.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    ///
+    /// The default implementation takes the maximum of the windows along the width, then along
+    /// the height.
+    fn adaptive_max_pool2d_with_indexes(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndexes<B> {
+        pool::adaptive_max_pool2d_with_indexes_from_max_dim::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive max pooling 2d](ModuleOps::adaptive_max_pool2d_with_indexes) operation.
+    fn adaptive_max_pool2d_with_indexes_backward(
+        x: B::TensorPrimitive<4>,
+        output_grad: B::TensorPrimitive<4>,
+        indexes: B::IntTensorPrimitive<4>,
+    ) -> AdaptiveMaxPool2dBackward<B> {
+        pool::adaptive_max_pool2d_backward_from_scatter::<B>(x, output_grad, indexes)
+    }
+
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    ///
+    /// The default implementation applies an [avg pooling 2d](ModuleOps::avg_pool2d) for each
+    /// depth index, then along the depth.
+    fn avg_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5> {
+        pool::avg_pool3d_from_avg_pool2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: B::TensorPrimitive<5>,
+        grad: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5> {
+        pool::avg_pool3d_backward_from_avg_pool2d::<B>(x, grad, kernel_size, stride, padding)
+    }
+
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> B::TensorPrimitive<5> {
+        Self::max_pool3d_with_indexes(x, kernel_size, stride, padding).output
+    }
+
+    /// Three dimensional max pooling with indexes.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    ///
+    /// The default implementation applies a [max pooling 2d](ModuleOps::max_pool2d_with_indexes)
+    /// for each depth index, then takes the maximum along the depth.
+    fn max_pool3d_with_indexes(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> MaxPool3dWithIndexes<B> {
+        pool::max_pool3d_with_indexes_from_max_pool2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indexes) operation.
+    fn max_pool3d_with_indexes_backward(
+        x: B::TensorPrimitive<5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        output_grad: B::TensorPrimitive<5>,
+        indexes: B::IntTensorPrimitive<5>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_backward_from_scatter::<B>(x, output_grad, indexes)
+    }
 }
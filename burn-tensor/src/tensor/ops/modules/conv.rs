@@ -1,5 +1,9 @@
-use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
-use crate::{backend::Backend, Shape};
+use super::{
+    Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTranspose1dBackward,
+    ConvTranspose2dBackward, ConvTranspose3dBackward, ConvTransposeOptions,
+};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -162,6 +166,279 @@ pub(crate) fn conv2d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [3D convolution](crate::ops::ModuleOps::conv3d) backward pass using convolutions.
+pub(crate) fn conv3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+    let [channels_out, _, kernel_size_1, kernel_size_2, kernel_size_3] = weight_shape.dims;
+
+    let padding_1_out = calculate_padding_out(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+        depth_out,
+    );
+    let padding_2_out = calculate_padding_out(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        height_in,
+        height_out,
+    );
+    let padding_3_out = calculate_padding_out(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        width_in,
+        width_out,
+    );
+
+    let x_grad = B::conv_transpose3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            [padding_1_out, padding_2_out, padding_3_out],
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv3d_weight_grad_no_groups::<B>(x, output_grad.clone(), weight_shape, options),
+        false => conv3d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d) backward
+/// pass using the 2D transposed convolution backward pass.
+pub(crate) fn conv_transpose1d_backward<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    weight: B::TensorPrimitive<3>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<3>,
+    options: ConvTransposeOptions<1>,
+) -> ConvTranspose1dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let [channels_in, channels_out, kernel_size] = weight_shape.dims;
+    let [batch_size, _channels_in, length_in] = B::shape(&x).dims;
+    let [_, channels_out_total, length_out] = B::shape(&output_grad).dims;
+
+    let weight = B::reshape(
+        weight,
+        Shape::new([channels_in, channels_out, kernel_size, 1]),
+    );
+    let x = B::reshape(x, Shape::new([batch_size, channels_in, length_in, 1]));
+    let output_grad = B::reshape(
+        output_grad,
+        Shape::new([batch_size, channels_out_total, length_out, 1]),
+    );
+
+    let backward = B::conv_transpose2d_backward(
+        x,
+        weight,
+        bias,
+        output_grad,
+        ConvTransposeOptions::new(
+            [options.stride[0], 1],
+            [options.padding[0], 0],
+            [options.padding_out[0], 0],
+            [options.dilation[0], 1],
+            options.groups,
+        ),
+    );
+
+    ConvTranspose1dBackward::new(
+        B::reshape(
+            backward.x_grad,
+            Shape::new([batch_size, channels_in, length_in]),
+        ),
+        B::reshape(backward.weights_grad, weight_shape),
+        backward.bias_grad,
+    )
+}
+
+/// Calculate the [2D transposed convolution](crate::ops::ModuleOps::conv_transpose2d) backward
+/// pass using convolutions.
+pub(crate) fn conv_transpose2d_backward<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    weight: B::TensorPrimitive<4>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<4>,
+    options: ConvTransposeOptions<2>,
+) -> ConvTranspose2dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let x_shape = B::shape(&x);
+    let [batch_size, channels_in, height_in, width_in] = x_shape.dims;
+    let [_, channels_out, height_out, width_out] = B::shape(&output_grad).dims;
+
+    let mut x_grad = B::conv2d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    // The output padding can make the convolution produce a few more elements than the input.
+    if B::shape(&x_grad) != x_shape {
+        x_grad = B::index(
+            x_grad,
+            [0..batch_size, 0..channels_in, 0..height_in, 0..width_in],
+        );
+    }
+
+    let weight_grad = match options.groups == 1 {
+        true => conv_transpose2d_weight_grad_no_groups::<B>(
+            x,
+            output_grad.clone(),
+            weight_shape,
+            options,
+        ),
+        false => conv_transpose2d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    ConvTranspose2dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([channels_out, batch_size * height_out * width_out]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d) backward
+/// pass using convolutions.
+pub(crate) fn conv_transpose3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> ConvTranspose3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let x_shape = B::shape(&x);
+    let [batch_size, channels_in, depth_in, height_in, width_in] = x_shape.dims;
+    let [_, channels_out, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+
+    let mut x_grad = B::conv3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    // The output padding can make the convolution produce a few more elements than the input.
+    if B::shape(&x_grad) != x_shape {
+        x_grad = B::index(
+            x_grad,
+            [
+                0..batch_size,
+                0..channels_in,
+                0..depth_in,
+                0..height_in,
+                0..width_in,
+            ],
+        );
+    }
+
+    let weight_grad = match options.groups == 1 {
+        true => conv_transpose3d_weight_grad_no_groups::<B>(
+            x,
+            output_grad.clone(),
+            weight_shape,
+            options,
+        ),
+        false => conv_transpose3d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    ConvTranspose3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
 /// Execute a 1D convolution using a 2D convolution.
 pub(crate) fn conv1d_from_conv2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -225,6 +502,184 @@ pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     B::reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D convolution using 2D convolutions, one for each depth index of the kernel.
+pub fn conv3d_from_conv2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_out, channels_in_group, kernel_depth, kernel_height, kernel_width] =
+        B::shape(&weight).dims;
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [stride, padding, dilation] = [options.stride[0], options.padding[0], options.dilation[0]];
+    let depth_out = (depth_in + 2 * padding - dilation * (kernel_depth - 1) - 1) / stride + 1;
+    let device = B::device(&x);
+
+    let x = match padding {
+        0 => x,
+        _ => {
+            let zeros = || {
+                B::zeros(
+                    Shape::new([batch_size, channels_in, padding, height_in, width_in]),
+                    &device,
+                )
+            };
+            B::cat(vec![zeros(), x, zeros()], 2)
+        }
+    };
+    let options = ConvOptions::new(
+        [options.stride[1], options.stride[2]],
+        [options.padding[1], options.padding[2]],
+        [options.dilation[1], options.dilation[2]],
+        options.groups,
+    );
+
+    let mut output: Option<B::TensorPrimitive<4>> = None;
+
+    for k in 0..kernel_depth {
+        let indexes = (0..depth_out)
+            .map(|d| ((d * stride + k * dilation) as i64).elem())
+            .collect();
+        let indexes = B::int_from_data(Data::new(indexes, Shape::new([depth_out])), &device);
+
+        let x = B::index_select(x.clone(), 2, indexes);
+        let x = B::reshape(
+            B::swap_dims(x, 1, 2),
+            Shape::new([batch_size * depth_out, channels_in, height_in, width_in]),
+        );
+        let weight = B::reshape(
+            B::index(
+                weight.clone(),
+                [
+                    0..channels_out,
+                    0..channels_in_group,
+                    k..k + 1,
+                    0..kernel_height,
+                    0..kernel_width,
+                ],
+            ),
+            Shape::new([channels_out, channels_in_group, kernel_height, kernel_width]),
+        );
+
+        let tensor = B::conv2d(x, weight, None, options.clone());
+        output = Some(match output {
+            Some(output) => B::add(output, tensor),
+            None => tensor,
+        });
+    }
+
+    let output = output.expect("The kernel should have a depth of at least one.");
+    let [_, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels_out, height_out, width_out]),
+    );
+    let output = B::swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
+/// Execute a 3D transposed convolution using 2D transposed convolutions, one for each depth
+/// index of the kernel.
+pub fn conv_transpose3d_from_conv_transpose2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_in, channels_out_group, kernel_depth, kernel_height, kernel_width] =
+        B::shape(&weight).dims;
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [stride, padding, padding_out, dilation] = [
+        options.stride[0],
+        options.padding[0],
+        options.padding_out[0],
+        options.dilation[0],
+    ];
+    // The depth before removing the padding, every input index contributes to it.
+    let depth_full = (depth_in - 1) * stride + dilation * (kernel_depth - 1) + padding_out + 1;
+    let depth_out = depth_full - 2 * padding;
+    let device = B::device(&x);
+
+    let x = B::reshape(
+        B::swap_dims(x, 1, 2),
+        Shape::new([batch_size * depth_in, channels_in, height_in, width_in]),
+    );
+    let options = ConvTransposeOptions::new(
+        [options.stride[1], options.stride[2]],
+        [options.padding[1], options.padding[2]],
+        [options.padding_out[1], options.padding_out[2]],
+        [options.dilation[1], options.dilation[2]],
+        options.groups,
+    );
+
+    let mut output: Option<B::TensorPrimitive<5>> = None;
+
+    for k in 0..kernel_depth {
+        let weight = B::reshape(
+            B::index(
+                weight.clone(),
+                [
+                    0..channels_in,
+                    0..channels_out_group,
+                    k..k + 1,
+                    0..kernel_height,
+                    0..kernel_width,
+                ],
+            ),
+            Shape::new([channels_in, channels_out_group, kernel_height, kernel_width]),
+        );
+        let tensor = B::conv_transpose2d(x.clone(), weight, None, options.clone());
+        let [_, channels_out, height_out, width_out] = B::shape(&tensor).dims;
+        let tensor = B::reshape(
+            tensor,
+            Shape::new([batch_size, depth_in, channels_out, height_out, width_out]),
+        );
+
+        let indexes = (0..depth_in)
+            .map(|d| ((d * stride + k * dilation) as i64).elem())
+            .collect();
+        let indexes = B::int_from_data(Data::new(indexes, Shape::new([depth_in])), &device);
+        let output_full = output.unwrap_or_else(|| {
+            B::zeros(
+                Shape::new([batch_size, depth_full, channels_out, height_out, width_out]),
+                &device,
+            )
+        });
+
+        output = Some(B::index_select_assign(output_full, 1, indexes, tensor));
+    }
+
+    let output = output.expect("The kernel should have a depth of at least one.");
+    let [_, _, channels_out, height_out, width_out] = B::shape(&output).dims;
+    let output = B::index(
+        output,
+        [
+            0..batch_size,
+            padding..padding + depth_out,
+            0..channels_out,
+            0..height_out,
+            0..width_out,
+        ],
+    );
+    let output = B::swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
 fn conv1d_weight_grad_groups<B: Backend>(
     x: B::TensorPrimitive<3>,
     mut weight_grad: B::TensorPrimitive<3>,
@@ -304,6 +759,80 @@ fn conv2d_weight_grad_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_out, increment_ci, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_co = channels_out / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let [_, batch_size, depth_in, height_in, width_in] = B::shape(&x_swapped).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad_swapped).dims;
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::index(
+            x_swapped.clone(),
+            [
+                start_idx_ci..end_idx_ci,
+                0..batch_size,
+                0..depth_in,
+                0..height_in,
+                0..width_in,
+            ],
+        );
+        let grad = B::index(
+            output_grad_swapped.clone(),
+            [
+                start_idx_co..end_idx_co,
+                0..batch_size,
+                0..depth_out,
+                0..height_out,
+                0..width_out,
+            ],
+        );
+        let mut weight_grad_tmp = B::conv3d(
+            x,
+            grad,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                start_idx_co..end_idx_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
 fn conv1d_weight_grad_no_groups<B: Backend>(
     x: B::TensorPrimitive<3>,
     output_grad: B::TensorPrimitive<3>,
@@ -363,6 +892,241 @@ fn conv2d_weight_grad_no_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    weight_shape: Shape<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        x_swapped,
+        output_grad_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::shape(&weight_grad) != weight_shape {
+        weight_grad = B::index(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
+fn conv_transpose2d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    mut weight_grad: B::TensorPrimitive<4>,
+    output_grad: B::TensorPrimitive<4>,
+    options: ConvTransposeOptions<2>,
+) -> B::TensorPrimitive<4> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2] = B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let [_, batch_size, height_in, width_in] = B::shape(&x_swapped).dims;
+    let [_, _, height_out, width_out] = B::shape(&output_grad_swapped).dims;
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::index(
+            x_swapped.clone(),
+            [
+                start_idx_ci..end_idx_ci,
+                0..batch_size,
+                0..height_in,
+                0..width_in,
+            ],
+        );
+        let grad = B::index(
+            output_grad_swapped.clone(),
+            [
+                start_idx_co..end_idx_co,
+                0..batch_size,
+                0..height_out,
+                0..width_out,
+            ],
+        );
+        let mut weight_grad_tmp = B::conv2d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                start_idx_ci..end_idx_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+fn conv_transpose2d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_grad: B::TensorPrimitive<4>,
+    weight_shape: Shape<4>,
+    options: ConvTransposeOptions<2>,
+) -> B::TensorPrimitive<4> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv2d(
+        output_grad_swapped,
+        x_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::shape(&weight_grad) != weight_shape {
+        weight_grad = B::index(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+            ],
+        );
+    }
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let [_, batch_size, depth_in, height_in, width_in] = B::shape(&x_swapped).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad_swapped).dims;
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::index(
+            x_swapped.clone(),
+            [
+                start_idx_ci..end_idx_ci,
+                0..batch_size,
+                0..depth_in,
+                0..height_in,
+                0..width_in,
+            ],
+        );
+        let grad = B::index(
+            output_grad_swapped.clone(),
+            [
+                start_idx_co..end_idx_co,
+                0..batch_size,
+                0..depth_out,
+                0..height_out,
+                0..width_out,
+            ],
+        );
+        let mut weight_grad_tmp = B::conv3d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        weight_grad_tmp = B::index(
+            weight_grad_tmp,
+            [
+                0..increment_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        weight_grad = B::index_assign(
+            weight_grad,
+            [
+                start_idx_ci..end_idx_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    weight_shape: Shape<5>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        output_grad_swapped,
+        x_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::shape(&weight_grad) != weight_shape {
+        weight_grad = B::index(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
 fn calculate_padding_out(
     kernel_size: usize,
     stride: usize,
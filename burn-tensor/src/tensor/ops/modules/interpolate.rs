@@ -0,0 +1,117 @@
+use super::pool::{resample_axis, resample_axis_backward};
+use super::{InterpolateMode, InterpolateOptions};
+use crate::backend::Backend;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Coefficient of the cubic convolution kernel, same as PyTorch and OpenCV.
+const CUBIC_A: f64 = -0.75;
+
+/// Computes, for each output index along one axis of an [interpolation](super::ModuleOps::interpolate),
+/// the input indexes contributing to it with their respective weights.
+pub fn interpolate_axis_weights(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Vec<Vec<(usize, f64)>> {
+    let scale = input_size as f64 / output_size as f64;
+    let last = input_size as i64 - 1;
+    let clamp = |index: i64| index.clamp(0, last) as usize;
+
+    let source_index = |index: usize| {
+        if options.align_corners && output_size > 1 {
+            index as f64 * (input_size as f64 - 1.0) / (output_size as f64 - 1.0)
+        } else if options.align_corners {
+            0.0
+        } else {
+            (index as f64 + 0.5) * scale - 0.5
+        }
+    };
+
+    (0..output_size)
+        .map(|index| match options.mode {
+            InterpolateMode::Nearest => {
+                let source = libm::floor(index as f64 * scale) as i64;
+
+                vec![(clamp(source), 1.0)]
+            }
+            InterpolateMode::Bilinear => {
+                let source = f64::max(source_index(index), 0.0);
+                let index_0 = libm::floor(source) as i64;
+                let lambda = source - index_0 as f64;
+
+                vec![(clamp(index_0), 1.0 - lambda), (clamp(index_0 + 1), lambda)]
+            }
+            InterpolateMode::Bicubic => {
+                let source = source_index(index);
+                let index_0 = libm::floor(source) as i64;
+                let t = source - index_0 as f64;
+
+                vec![
+                    (clamp(index_0 - 1), cubic_convolution_2(t + 1.0)),
+                    (clamp(index_0), cubic_convolution_1(t)),
+                    (clamp(index_0 + 1), cubic_convolution_1(1.0 - t)),
+                    (clamp(index_0 + 2), cubic_convolution_2(2.0 - t)),
+                ]
+            }
+        })
+        .collect()
+}
+
+/// Execute an interpolation by resampling the height, then the width, with index selections.
+pub fn interpolate_from_index_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let [_, _, height_in, width_in] = B::shape(&x).dims;
+    let [height_out, width_out] = output_size;
+
+    let x = resample_axis::<B, 4>(
+        x,
+        2,
+        &interpolate_axis_weights(height_in, height_out, &options),
+    );
+
+    resample_axis::<B, 4>(
+        x,
+        3,
+        &interpolate_axis_weights(width_in, width_out, &options),
+    )
+}
+
+/// Execute the backward pass of an interpolation by accumulating the gradient of the width,
+/// then of the height, with index assignments.
+pub fn interpolate_backward_from_index_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grad: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let [_, _, height_in, width_in] = B::shape(&x).dims;
+    let [height_out, width_out] = output_size;
+
+    let grad = resample_axis_backward::<B, 4>(
+        grad,
+        3,
+        width_in,
+        &interpolate_axis_weights(width_in, width_out, &options),
+    );
+
+    resample_axis_backward::<B, 4>(
+        grad,
+        2,
+        height_in,
+        &interpolate_axis_weights(height_in, height_out, &options),
+    )
+}
+
+/// Cubic convolution kernel for `|x| <= 1`.
+fn cubic_convolution_1(x: f64) -> f64 {
+    ((CUBIC_A + 2.0) * x - (CUBIC_A + 3.0)) * x * x + 1.0
+}
+
+/// Cubic convolution kernel for `1 < |x| < 2`.
+fn cubic_convolution_2(x: f64) -> f64 {
+    ((CUBIC_A * x - 5.0 * CUBIC_A) * x + 8.0 * CUBIC_A) * x - 4.0 * CUBIC_A
+}
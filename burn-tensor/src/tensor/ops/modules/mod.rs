@@ -1,6 +1,12 @@
+/// Module with attention operations.
+pub mod attention;
+
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with interpolation operations.
+pub mod interpolate;
+
 /// Module with pooling operations.
 pub mod pool;
 
@@ -1,4 +1,10 @@
-use crate::{backend::Backend, Shape};
+use super::{
+    AdaptiveMaxPool2dBackward, AdaptiveMaxPool2dWithIndexes, MaxPool3dBackward,
+    MaxPool3dWithIndexes,
+};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec::Vec;
+use core::ops::Range;
 
 pub(crate) fn avg_pool1d_from_avg_pool2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -33,3 +39,425 @@ pub(crate) fn avg_pool1d_backward_from_avg_pool2d<B: Backend>(
 
     B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
 }
+
+pub(crate) fn adaptive_avg_pool1d_from_adaptive_avg_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    output_size: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::adaptive_avg_pool2d(x, [output_size, 1]);
+
+    let [batch_size, channels, length, _] = B::shape(&x).dims;
+
+    B::reshape(x, Shape::from([batch_size, channels, length]))
+}
+
+pub(crate) fn adaptive_avg_pool1d_backward_from_adaptive_avg_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    grad: B::TensorPrimitive<3>,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length_in] = B::shape(&x).dims;
+    let [_, _, length_out] = B::shape(&grad).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length_in, 1]));
+    let grad_x = B::reshape(grad, Shape::from([batch_size, channels, length_out, 1]));
+
+    let grad_x = B::adaptive_avg_pool2d_backward(x, grad_x);
+
+    B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
+}
+
+pub(crate) fn max_pool1d_from_max_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::max_pool2d(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&x).dims;
+
+    B::reshape(x, Shape::from([batch_size, channels, length]))
+}
+
+/// Execute a 2D adaptive avg pooling by averaging the height, then the width, with index
+/// selections.
+pub fn adaptive_avg_pool2d_from_index_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+) -> B::TensorPrimitive<4> {
+    let [_, _, height_in, width_in] = B::shape(&x).dims;
+    let [height_out, width_out] = output_size;
+
+    let x = resample_axis::<B, 4>(x, 2, &adaptive_avg_weights(height_in, height_out));
+
+    resample_axis::<B, 4>(x, 3, &adaptive_avg_weights(width_in, width_out))
+}
+
+/// Execute the backward pass of a 2D adaptive avg pooling with index assignments.
+pub fn adaptive_avg_pool2d_backward_from_index_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grad: B::TensorPrimitive<4>,
+) -> B::TensorPrimitive<4> {
+    let [_, _, height_in, width_in] = B::shape(&x).dims;
+    let [_, _, height_out, width_out] = B::shape(&grad).dims;
+
+    let grad = resample_axis_backward::<B, 4>(
+        grad,
+        3,
+        width_in,
+        &adaptive_avg_weights(width_in, width_out),
+    );
+
+    resample_axis_backward::<B, 4>(
+        grad,
+        2,
+        height_in,
+        &adaptive_avg_weights(height_in, height_out),
+    )
+}
+
+/// Execute a 2D adaptive max pooling by taking the maximum of each window along the width, then
+/// along the height.
+///
+/// The indexes point to the first maximum of each window in the flattened spatial dimensions, as
+/// when iterating over the rows of the window.
+pub fn adaptive_max_pool2d_with_indexes_from_max_dim<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+) -> AdaptiveMaxPool2dWithIndexes<B> {
+    let [batch_size, channels, height_in, width_in] = B::shape(&x).dims;
+    let [height_out, width_out] = output_size;
+
+    let (values, columns): (Vec<_>, Vec<_>) = adaptive_windows(width_in, width_out)
+        .map(|window| {
+            let start = window.start;
+            let x = B::index(
+                x.clone(),
+                [0..batch_size, 0..channels, 0..height_in, window],
+            );
+            let indexes = B::argmax(x.clone(), 3);
+            let values = B::gather(3, x, indexes.clone());
+
+            (values, B::int_add_scalar(indexes, (start as i64).elem()))
+        })
+        .unzip();
+    let values = B::cat(values, 3);
+    let columns = B::int_cat(columns, 3);
+
+    let (output, indexes): (Vec<_>, Vec<_>) = adaptive_windows(height_in, height_out)
+        .map(|window| {
+            let start = window.start;
+            let ranges = [0..batch_size, 0..channels, window, 0..width_out];
+            let values = B::index(values.clone(), ranges.clone());
+            let columns = B::int_index(columns.clone(), ranges);
+            let rows = B::argmax(values.clone(), 2);
+            let values = B::gather(2, values, rows.clone());
+            let columns = B::int_gather(2, columns, rows.clone());
+            let rows = B::int_add_scalar(rows, (start as i64).elem());
+
+            let indexes = B::int_add(B::int_mul_scalar(rows, (width_in as i64).elem()), columns);
+
+            (values, indexes)
+        })
+        .unzip();
+
+    AdaptiveMaxPool2dWithIndexes::new(B::cat(output, 2), B::int_cat(indexes, 2))
+}
+
+/// Execute the backward pass of a 2D adaptive max pooling by scattering the gradient to the
+/// indexes of the maximums.
+pub fn adaptive_max_pool2d_backward_from_scatter<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_grad: B::TensorPrimitive<4>,
+    indexes: B::IntTensorPrimitive<4>,
+) -> AdaptiveMaxPool2dBackward<B> {
+    AdaptiveMaxPool2dBackward::new(max_pool_backward_from_scatter::<B, 4>(
+        x,
+        output_grad,
+        indexes,
+    ))
+}
+
+/// Execute a 3D avg pooling using a 2D avg pooling over each depth index, followed by a 2D avg
+/// pooling along the depth.
+///
+/// As for the other avg poolings, the padding is counted in the mean.
+pub fn avg_pool3d_from_avg_pool2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+    );
+    let [_, _, height_out, width_out] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size, channels, depth_in, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(x, [kernel_size[0], 1], [stride[0], 1], [padding[0], 0]);
+    let [_, _, depth_out, _] = B::shape(&x).dims;
+
+    B::reshape(
+        x,
+        Shape::new([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+/// Execute the backward pass of a 3D avg pooling using the backward pass of a 2D avg pooling.
+pub fn avg_pool3d_backward_from_avg_pool2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    grad: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&grad).dims;
+
+    // The backward pass of an avg pooling only depends on the shape of its input.
+    let x_depth = B::zeros(
+        Shape::new([batch_size, channels, depth_in, height_out * width_out]),
+        &B::device(&x),
+    );
+    let grad = B::reshape(
+        grad,
+        Shape::new([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+    );
+
+    let grad = B::reshape(
+        grad,
+        Shape::new([batch_size, channels * depth_in, height_out, width_out]),
+    );
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+    );
+
+    B::reshape(
+        grad,
+        Shape::new([batch_size, channels, depth_in, height_in, width_in]),
+    )
+}
+
+/// Execute a 3D max pooling using a 2D max pooling over each depth index, followed by a maximum
+/// along the depth.
+///
+/// The indexes point to the first maximum of each window in the flattened spatial dimensions, as
+/// when iterating over the depth, then the rows of the window.
+pub fn max_pool3d_with_indexes_from_max_pool2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+) -> MaxPool3dWithIndexes<B> {
+    let [batch_size, channels, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let depth_out = (depth_in + 2 * padding[0] - kernel_size[0]) / stride[0] + 1;
+
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size, channels * depth_in, height_in, width_in]),
+    );
+    let pooled = B::max_pool2d_with_indexes(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+    );
+    let [_, _, height_out, width_out] = B::shape(&pooled.output).dims;
+    let shape = Shape::new([batch_size, channels, depth_in, height_out, width_out]);
+    let values = B::reshape(pooled.output, shape.clone());
+    let plane_indexes = B::int_reshape(pooled.indexes, shape);
+
+    let (output, indexes): (Vec<_>, Vec<_>) = (0..depth_out)
+        .map(|d| {
+            // The padded depth indexes can't be the maximum, only the others are compared.
+            let start = usize::saturating_sub(d * stride[0], padding[0]);
+            let end = usize::min(
+                (d * stride[0] + kernel_size[0]).saturating_sub(padding[0]),
+                depth_in,
+            );
+            let ranges = [
+                0..batch_size,
+                0..channels,
+                start..end,
+                0..height_out,
+                0..width_out,
+            ];
+
+            let values = B::index(values.clone(), ranges.clone());
+            let depths = B::argmax(values.clone(), 2);
+            let values = B::gather(2, values, depths.clone());
+            let plane_indexes = B::int_gather(
+                2,
+                B::int_index(plane_indexes.clone(), ranges),
+                depths.clone(),
+            );
+            let depths = B::int_add_scalar(depths, (start as i64).elem());
+
+            let indexes = B::int_add(
+                B::int_mul_scalar(depths, ((height_in * width_in) as i64).elem()),
+                plane_indexes,
+            );
+
+            (values, indexes)
+        })
+        .unzip();
+
+    MaxPool3dWithIndexes::new(B::cat(output, 2), B::int_cat(indexes, 2))
+}
+
+/// Execute the backward pass of a 3D max pooling by scattering the gradient to the indexes of the
+/// maximums.
+pub fn max_pool3d_backward_from_scatter<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    indexes: B::IntTensorPrimitive<5>,
+) -> MaxPool3dBackward<B> {
+    MaxPool3dBackward::new(max_pool_backward_from_scatter::<B, 5>(
+        x,
+        output_grad,
+        indexes,
+    ))
+}
+
+/// Scatter the gradient of a max pooling to the indexes of the maximums in the flattened spatial
+/// dimensions of the input.
+fn max_pool_backward_from_scatter<B: Backend, const D: usize>(
+    x: B::TensorPrimitive<D>,
+    output_grad: B::TensorPrimitive<D>,
+    indexes: B::IntTensorPrimitive<D>,
+) -> B::TensorPrimitive<D> {
+    let shape_x = B::shape(&x);
+    let shape_output = B::shape(&output_grad);
+    let [batch_size, channels] = [shape_x.dims[0], shape_x.dims[1]];
+    let size_in = shape_x.num_elements() / (batch_size * channels);
+    let size_out = shape_output.num_elements() / (batch_size * channels);
+
+    let grad = B::zeros(Shape::new([batch_size, channels, size_in]), &B::device(&x));
+    let indexes = B::int_reshape(indexes, Shape::new([batch_size, channels, size_out]));
+    let output_grad = B::reshape(output_grad, Shape::new([batch_size, channels, size_out]));
+
+    B::reshape(B::scatter(2, grad, indexes, output_grad), shape_x)
+}
+
+/// The input windows of an adaptive pooling along one axis.
+fn adaptive_windows(input_size: usize, output_size: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..output_size).map(move |index| {
+        let start = (index * input_size) / output_size;
+        let end = ((index + 1) * input_size).div_ceil(output_size);
+
+        start..usize::min(end, input_size)
+    })
+}
+
+/// The weights of an adaptive avg pooling along one axis.
+fn adaptive_avg_weights(input_size: usize, output_size: usize) -> Vec<Vec<(usize, f64)>> {
+    adaptive_windows(input_size, output_size)
+        .map(|window| {
+            let weight = 1.0 / window.len() as f64;
+            window.map(|index| (index, weight)).collect()
+        })
+        .collect()
+}
+
+/// Resample the tensor along the given dimension, each output index being the weighted sum of the
+/// input indexes given by `weights`.
+pub(crate) fn resample_axis<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+    weights: &[Vec<(usize, f64)>],
+) -> B::TensorPrimitive<D> {
+    let device = B::device(&tensor);
+
+    resample_taps::<B, D>(weights, dim, &device)
+        .into_iter()
+        .map(|(indexes, weights)| B::mul(B::index_select(tensor.clone(), dim, indexes), weights))
+        .reduce(B::add)
+        .expect("Each output index should have at least one input index.")
+}
+
+/// Backward pass of [resample_axis](resample_axis), accumulating the gradient of each output
+/// index into the input indexes it was computed from.
+pub(crate) fn resample_axis_backward<B: Backend, const D: usize>(
+    grad: B::TensorPrimitive<D>,
+    dim: usize,
+    input_size: usize,
+    weights: &[Vec<(usize, f64)>],
+) -> B::TensorPrimitive<D> {
+    let device = B::device(&grad);
+    let mut shape = B::shape(&grad);
+    shape.dims[dim] = input_size;
+
+    resample_taps::<B, D>(weights, dim, &device)
+        .into_iter()
+        .fold(B::zeros(shape, &device), |output, (indexes, weights)| {
+            B::index_select_assign(output, dim, indexes, B::mul(grad.clone(), weights))
+        })
+}
+
+/// Split the weights of each output index into taps, the k-th tap holding the k-th input index
+/// of every output index with its weight, shaped to be broadcasted along `dim`.
+///
+/// Output indexes with fewer input indexes than the others are completed with null weights.
+fn resample_taps<B: Backend, const D: usize>(
+    weights: &[Vec<(usize, f64)>],
+    dim: usize,
+    device: &B::Device,
+) -> Vec<(B::IntTensorPrimitive<1>, B::TensorPrimitive<D>)> {
+    let output_size = weights.len();
+    let num_taps = weights.iter().map(Vec::len).max().unwrap_or(0);
+    let mut shape = [1; D];
+    shape[dim] = output_size;
+
+    (0..num_taps)
+        .map(|tap| {
+            let (indexes, weights): (Vec<B::IntElem>, Vec<B::FloatElem>) = weights
+                .iter()
+                .map(|weights| {
+                    let (index, weight) = weights.get(tap).copied().unwrap_or((0, 0.0));
+                    (
+                        (index as i64).elem::<B::IntElem>(),
+                        weight.elem::<B::FloatElem>(),
+                    )
+                })
+                .unzip();
+
+            (
+                B::int_from_data(Data::new(indexes, Shape::new([output_size])), device),
+                B::from_data(Data::new(weights, Shape::new(shape)), device),
+            )
+        })
+        .collect()
+}
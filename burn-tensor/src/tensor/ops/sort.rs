@@ -0,0 +1,120 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Data, Element};
+
+/// Compares two elements with a total order, NaN being larger than any other value.
+///
+/// This is the order used by the sorting operations, `partial_cmp` alone isn't a total order once
+/// a NaN is present.
+pub fn compare_elements<E: PartialOrd>(a: &E, b: &E) -> Ordering {
+    #[allow(clippy::eq_op)]
+    let is_nan = |value: &E| value != value;
+
+    match a.partial_cmp(b) {
+        Some(ordering) => ordering,
+        None => match (is_nan(a), is_nan(b)) {
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            _ => Ordering::Equal,
+        },
+    }
+}
+
+/// Computes the indexes that sort the data along the given dimension.
+///
+/// The sort is stable: equal elements keep their relative order. The elements are compared in
+/// their own type, so large integers keep their exact order, and NaN is sorted as the largest
+/// value.
+pub(crate) fn argsort_data<E: Element, const D: usize>(
+    data: &Data<E, D>,
+    dim: usize,
+    descending: bool,
+) -> Data<i64, D> {
+    let dims = data.shape.dims;
+    let dim_size = dims[dim];
+    let stride: usize = dims[dim + 1..].iter().product();
+    let num_elements = data.value.len();
+    let mut output = vec![0; num_elements];
+
+    if num_elements == 0 {
+        return Data::new(output, data.shape.clone());
+    }
+
+    for outer in 0..num_elements / (dim_size * stride) {
+        for inner in 0..stride {
+            let start = outer * dim_size * stride + inner;
+            let value = |index: usize| data.value[start + index * stride];
+
+            let mut indexes: Vec<usize> = (0..dim_size).collect();
+            indexes.sort_by(|a, b| {
+                let ordering = compare_elements(&value(*a), &value(*b));
+
+                match descending {
+                    true => ordering.reverse(),
+                    false => ordering,
+                }
+            });
+
+            for (position, index) in indexes.into_iter().enumerate() {
+                output[start + position * stride] = index as i64;
+            }
+        }
+    }
+
+    Data::new(output, data.shape.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Shape;
+
+    #[test]
+    fn test_argsort_data_inner_dim() {
+        let data = Data::new(vec![3.0, 1.0, 2.0, 0.0, 5.0, 5.0], Shape::new([2, 3]));
+
+        assert_eq!(
+            argsort_data(&data, 1, false),
+            Data::new(vec![1, 2, 0, 0, 1, 2], Shape::new([2, 3]))
+        );
+        assert_eq!(
+            argsort_data(&data, 1, true),
+            Data::new(vec![0, 2, 1, 1, 2, 0], Shape::new([2, 3]))
+        );
+    }
+
+    #[test]
+    fn test_argsort_data_outer_dim() {
+        let data = Data::new(vec![3, 1, 2, 0, 5, 4], Shape::new([2, 3]));
+
+        assert_eq!(
+            argsort_data(&data, 0, false),
+            Data::new(vec![1, 0, 0, 0, 1, 1], Shape::new([2, 3]))
+        );
+    }
+
+    #[test]
+    fn test_compare_elements_nan_is_largest() {
+        assert_eq!(compare_elements(&f32::NAN, &1.0), Ordering::Greater);
+        assert_eq!(compare_elements(&1.0, &f32::NAN), Ordering::Less);
+        assert_eq!(compare_elements(&f32::NAN, &f32::NAN), Ordering::Equal);
+        assert_eq!(
+            compare_elements(&f32::NAN, &f32::INFINITY),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_argsort_data_large_integers() {
+        // Those integers are equal once converted to f64.
+        let value = 1_i64 << 54;
+        let data = Data::new(vec![value + 1, value, value + 2], Shape::new([3]));
+
+        assert_eq!(
+            argsort_data(&data, 0, false),
+            Data::new(vec![1, 0, 2], Shape::new([3]))
+        );
+    }
+}
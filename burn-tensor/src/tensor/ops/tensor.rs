@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::{cumulative, sort};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
 
 /// Operations on float tensors.
@@ -731,6 +732,84 @@ pub trait TensorOps<B: Backend> {
     fn mean_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize)
         -> B::TensorPrimitive<D>;
 
+    /// Product of all elements in a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the product of all elements in `tensor`.
+    fn prod<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1> {
+        let num_elements = B::shape(&tensor).num_elements();
+        let tensor = B::reshape(tensor, Shape::new([num_elements]));
+
+        B::prod_dim(tensor, 0)
+    }
+
+    /// Product of all elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension along which to compute the product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the product of all elements in `tensor` along `dim`.
+    fn prod_dim<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let mut ranges = B::shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = ranges[dim].end - 1..ranges[dim].end;
+
+        B::index(B::cumprod(tensor, dim), ranges)
+    }
+
+    /// Cumulative sum of the elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the sum of all the
+    /// elements up to and including it along `dim`.
+    fn cumsum<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor);
+
+        B::from_data(
+            cumulative::cumulative_data(&data, dim, |acc, value| acc + value),
+            &device,
+        )
+    }
+
+    /// Cumulative product of the elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the product of all the
+    /// elements up to and including it along `dim`.
+    fn cumprod<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor);
+
+        B::from_data(
+            cumulative::cumulative_data(&data, dim, |acc, value| acc * value),
+            &device,
+        )
+    }
+
     /// Converts a tensor to full precision.
     ///
     /// # Arguments
@@ -857,6 +936,154 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn erf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with absolute values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the absolute value of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with absolute values.
+    fn abs<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the signs of the elements, `-1`, `0` or `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with sign values.
+    fn sign<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the values rounded down to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round down.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with floor values.
+    fn floor<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the values rounded up to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round up.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with ceil values.
+    fn ceil<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the values rounded to the nearest integer.
+    ///
+    /// Halfway values are rounded to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with rounded values.
+    fn round<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with reciprocal values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the reciprocal of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `1 / x` values.
+    fn recip<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Clamps the values of a tensor to be greater or equal to `min`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the values lower than `min` replaced by `min`.
+    fn clamp_min<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+    ) -> B::TensorPrimitive<D>;
+
+    /// Clamps the values of a tensor to be lower or equal to `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `max` - The maximum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the values greater than `max` replaced by `max`.
+    fn clamp_max<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D>;
+
+    /// Clamps the values of a tensor between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with values in the range `[min, max]`.
+    fn clamp<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        B::clamp_max(B::clamp_min(tensor, min), max)
+    }
+
+    /// Element-wise arctangent of `lhs / rhs`, using the signs of both arguments to determine
+    /// the quadrant.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor, the `y` coordinates.
+    /// * `rhs` - The right hand side tensor, the `x` coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the angles in radians, in the range `[-pi, pi]`.
+    fn atan2<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+
+    /// Element-wise power with a tensor exponent.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor, the base.
+    /// * `rhs` - The right hand side tensor, the exponent.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `lhs` raised to the power of the elements of `rhs`.
+    fn pow<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1005,4 +1232,70 @@ pub trait TensorOps<B: Backend> {
 
         (values, index)
     }
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with its elements sorted along `dim`.
+    fn sort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::TensorPrimitive<D> {
+        B::sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their indexes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted elements of `tensor` along `dim` and their original indexes.
+    fn sort_with_indices<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let indices = B::argsort(tensor.clone(), dim, descending);
+        let values = B::gather(dim, tensor, indices.clone());
+
+        (values, indices)
+    }
+
+    /// Gets the indexes that sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements are sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the indexes of its sorted elements along `dim`.
+    fn argsort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor);
+
+        B::int_from_data(
+            sort::argsort_data(&data, dim, descending).convert(),
+            &device,
+        )
+    }
 }
@@ -17,20 +17,35 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
+        burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
+        burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_avg_pool3d!();
+        burn_tensor::testgen_module_adaptive_avg_pool1d!();
+        burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
+        burn_tensor::testgen_module_interpolate!();
+        burn_tensor::testgen_module_attention!();
 
         // test ops
+        burn_tensor::testgen_abs!();
         burn_tensor::testgen_add!();
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_cat!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_atan2!();
+        burn_tensor::testgen_bool!();
         burn_tensor::testgen_maxmin!();
+        burn_tensor::testgen_clamp!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
@@ -45,15 +60,22 @@ macro_rules! testgen_all {
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_pad!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_pow!();
+        burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_round!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_flip!();
         burn_tensor::testgen_sin!();
+        burn_tensor::testgen_sort!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_triangular!();
 
         // test stats
         burn_tensor::testgen_stats!();
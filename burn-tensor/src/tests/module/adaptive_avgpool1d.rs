@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool1d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool1d_simple() {
+        let test = AdaptiveAvgPool1dTestCase {
+            batch_size: 1,
+            channels: 2,
+            length: 8,
+            output_size: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [0.5, 2.5, 4.5, 6.5],
+            [8.5, 10.5, 12.5, 14.5],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool1d_dyn_window() {
+        let test = AdaptiveAvgPool1dTestCase {
+            batch_size: 1,
+            channels: 2,
+            length: 5,
+            output_size: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [0.5, 2.0, 3.5],
+            [5.5, 7.0, 8.5],
+        ]]));
+    }
+
+    struct AdaptiveAvgPool1dTestCase {
+        batch_size: usize,
+        channels: usize,
+        length: usize,
+        output_size: usize,
+    }
+
+    impl AdaptiveAvgPool1dTestCase {
+        fn assert_output(self, y: TestTensor<3>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.length]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_avg_pool1d(x, self.output_size);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
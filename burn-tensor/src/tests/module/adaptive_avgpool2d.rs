@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool2d;
+    use burn_tensor::ops::pool::adaptive_avg_pool2d_from_index_select;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool2d_dyn_window() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 5,
+            width: 4,
+            output_size_1: 3,
+            output_size_2: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[2.5, 3.5, 4.5], [8.5, 9.5, 10.5], [14.5, 15.5, 16.5]],
+            [[22.5, 23.5, 24.5], [28.5, 29.5, 30.5], [34.5, 35.5, 36.5]],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_output_bigger_than_input() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 5,
+            width: 3,
+            output_size_1: 2,
+            output_size_2: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[3.0, 3.5, 4.5, 5.0], [9.0, 9.5, 10.5, 11.0]],
+            [[18.0, 18.5, 19.5, 20.0], [24.0, 24.5, 25.5, 26.0]],
+        ]]));
+    }
+
+    struct AdaptiveAvgPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        output_size_1: usize,
+        output_size_2: usize,
+    }
+
+    impl AdaptiveAvgPool2dTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output_size = [self.output_size_1, self.output_size_2];
+            let output = adaptive_avg_pool2d(x.clone(), output_size);
+            let output_fallback =
+                TestTensor::from_primitive(adaptive_avg_pool2d_from_index_select::<TestBackend>(
+                    x.into_primitive(),
+                    output_size,
+                ));
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
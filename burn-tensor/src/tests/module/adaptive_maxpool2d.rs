@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool2d, adaptive_max_pool2d_with_indexes};
+    use burn_tensor::ops::{pool::adaptive_max_pool2d_with_indexes_from_max_dim, IntTensorOps};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_adaptive_max_pool2d_simple() {
+        let x = TestTensor::from_floats([[
+            [
+                [0.0000, 0.9917, -0.2555, -0.9258],
+                [0.4941, 0.7985, -0.6999, -0.6181],
+                [0.8592, 0.3967, -0.9614, -0.1490],
+                [0.9998, -0.1086, -0.9718, 0.3591],
+                [0.8793, -0.5856, -0.7284, 0.7733],
+            ],
+            [
+                [0.5291, -0.9097, -0.2947, 0.9856],
+                [0.0407, -0.9961, 0.2160, 0.9404],
+                [-0.4583, -0.8223, 0.6702, 0.6496],
+                [-0.8376, -0.4338, 0.9494, 0.1891],
+                [-0.9981, 0.0681, 0.9806, -0.3208],
+            ],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [
+                [0.9917, 0.9917, -0.2555],
+                [0.9998, 0.7985, 0.3591],
+                [0.9998, -0.1086, 0.7733],
+            ],
+            [
+                [0.5291, 0.2160, 0.9856],
+                [0.0407, 0.9494, 0.9494],
+                [0.0681, 0.9806, 0.9806],
+            ],
+        ]]);
+
+        let output = adaptive_max_pool2d(x.clone(), [3, 3]);
+        let output_fallback = TestTensor::from_primitive(
+            adaptive_max_pool2d_with_indexes_from_max_dim::<TestBackend>(
+                x.into_primitive(),
+                [3, 3],
+            )
+            .output,
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        y.to_data()
+            .assert_approx_eq(&output_fallback.into_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_with_indexes() {
+        let x = TestTensor::from_floats([[[
+            [0.0000, 0.9917, -0.2555, -0.9258],
+            [0.4941, 0.7985, -0.6999, -0.6181],
+            [0.8592, 0.3967, -0.9614, -0.1490],
+            [0.9998, -0.1086, -0.9718, 0.3591],
+            [0.8793, -0.5856, -0.7284, 0.7733],
+        ]]]);
+        let indexes = Data::<i64, 4>::from([[[[1, 1, 2], [12, 5, 15], [12, 13, 19]]]]);
+
+        let (_output, output_indexes) = adaptive_max_pool2d_with_indexes(x.clone(), [3, 3]);
+        let output_fallback = adaptive_max_pool2d_with_indexes_from_max_dim::<TestBackend>(
+            x.into_primitive(),
+            [3, 3],
+        );
+
+        assert_eq!(indexes.value, output_indexes.into_data().value);
+        assert_eq!(
+            indexes.value,
+            TestBackend::int_into_data(output_fallback.indexes).value
+        );
+    }
+}
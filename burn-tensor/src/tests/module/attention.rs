@@ -0,0 +1,108 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::AttentionOptions;
+    use burn_tensor::{activation, Bool, Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_scaled_dot_product_attention() {
+        let test = AttentionTestCase { causal: false };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0144, 1.8886],
+            [0.0411, 1.8781],
+            [-0.0768, 1.6259],
+        ]]]));
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_causal_mask() {
+        let test = AttentionTestCase { causal: true };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [1.0000, 2.0000],
+            [0.0624, 1.2968],
+            [-0.0768, 1.6259],
+        ]]]));
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_should_match_ops() {
+        let [batch_size, n_heads, seq_length_1, seq_length_2, d_k] = [2, 3, 4, 5, 6];
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+        let query = TestTensor::random([batch_size, n_heads, seq_length_1, d_k], distribution);
+        let key = TestTensor::random([batch_size, n_heads, seq_length_2, d_k], distribution);
+        let value = TestTensor::random([batch_size, n_heads, seq_length_2, d_k], distribution);
+        let mask = Tensor::<TestBackend, 1>::random([batch_size * seq_length_2], distribution)
+            .greater_elem(0.5)
+            .reshape([batch_size, 1, 1, seq_length_2]);
+        let options = AttentionOptions::new(0.4, -1.0e4);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            Some(mask.clone()),
+            options,
+        );
+
+        let scores = query
+            .matmul(key.transpose())
+            .mul_scalar(0.4)
+            .mask_fill(mask, -1.0e4);
+        let expected = activation::softmax(scores, 3).matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_without_keys() {
+        let query = TestTensor::from_floats([[[[0.1, -0.2], [0.4, 0.3]]]]);
+        let key = TestTensor::zeros([1, 1, 0, 2]);
+        let value = TestTensor::zeros([1, 1, 0, 3]);
+
+        let output = scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            None,
+            AttentionOptions::new(0.5, -1.0e4),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TestTensor::zeros([1, 1, 2, 3]).into_data(), 3);
+    }
+
+    struct AttentionTestCase {
+        causal: bool,
+    }
+
+    impl AttentionTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let query = TestTensor::from_floats([[[[0.1, -0.2], [0.4, 0.3], [-0.5, 0.6]]]]);
+            let key = TestTensor::from_floats([[[[0.3, 0.1], [-0.4, 0.2], [0.7, -0.6]]]]);
+            let value = TestTensor::from_floats([[[[1.0, 2.0], [-1.0, 0.5], [0.0, 3.0]]]]);
+            let mask = match self.causal {
+                true => Some(Tensor::<TestBackend, 4, Bool>::from_data(Data::new(
+                    vec![false, true, true, false, false, true, false, false, false],
+                    Shape::new([1, 1, 3, 3]),
+                ))),
+                false => None,
+            };
+
+            let output = scaled_dot_product_attention(
+                query,
+                key,
+                value,
+                mask,
+                AttentionOptions::new(0.5, -1.0e4),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::ops::pool::avg_pool3d_from_avg_pool2d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [[6.5, 7.5], [9.5, 10.5]],
+            [[15.5, 16.5], [18.5, 19.5]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 2,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[0.8333, 1.5], [0.8333, 1.5]],
+                [[9.6667, 11.], [9.6667, 11.]],
+            ],
+            [
+                [[8.8333, 9.5], [8.8333, 9.5]],
+                [[25.6667, 27.], [25.6667, 27.]],
+            ],
+        ]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let kernel_size = [self.kernel_size_1, self.kernel_size_2, self.kernel_size_3];
+            let stride = [self.stride_1, self.stride_2, self.stride_3];
+            let padding = [self.padding_1, self.padding_2, self.padding_3];
+            let output = avg_pool3d(x.clone(), kernel_size, stride, padding);
+            let output_fallback =
+                TestTensor::from_primitive(avg_pool3d_from_avg_pool2d::<TestBackend>(
+                    x.into_primitive(),
+                    kernel_size,
+                    stride,
+                    padding,
+                ));
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
@@ -69,6 +69,33 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv2d_groups_different_channels() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[19., 25.], [37., 43.]],
+            [[52., 74.], [118., 140.]],
+            [[427., 465.], [541., 579.]],
+            [[604., 658.], [766., 820.]],
+        ]]));
+    }
+
     #[test]
     fn test_conv2d_complex() {
         let test = Conv2dTestCase {
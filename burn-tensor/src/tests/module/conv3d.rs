@@ -0,0 +1,238 @@
+#[burn_tensor_testgen::testgen(module_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv3d;
+    use burn_tensor::ops::{conv::conv3d_from_conv2d, ConvOptions};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv3d_simple() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [270., 544., 586., 292.],
+                    [570., 1138., 1214., 600.],
+                    [690., 1366., 1442., 708.],
+                    [342., 672., 706., 344.],
+                ],
+                [
+                    [666., 1314., 1382., 676.],
+                    [1332., 2608., 2728., 1324.],
+                    [1524., 2968., 3088., 1492.],
+                    [726., 1402., 1454., 696.],
+                ],
+                [
+                    [324., 626., 652., 312.],
+                    [618., 1182., 1226., 580.],
+                    [690., 1314., 1358., 640.],
+                    [312., 586., 604., 280.],
+                ],
+            ],
+            [
+                [
+                    [559., 1153., 1259., 645.],
+                    [1243., 2547., 2751., 1401.],
+                    [1555., 3159., 3363., 1701.],
+                    [823., 1665., 1763., 889.],
+                ],
+                [
+                    [1531., 3107., 3303., 1669.],
+                    [3253., 6577., 6953., 3501.],
+                    [3829., 7705., 8081., 4053.],
+                    [1975., 3963., 4143., 2073.],
+                ],
+                [
+                    [901., 1811., 1901., 953.],
+                    [1867., 3743., 3915., 1957.],
+                    [2131., 4259., 4431., 2209.],
+                    [1081., 2155., 2237., 1113.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 1,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 2,
+            depth: 3,
+            height: 2,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[[0., 0.], [34., 40.], [52., 58.], [0., 0.]]],
+            [[[1., 1.], [91., 113.], [157., 179.], [1., 1.]]],
+            [[[2., 2.], [832., 870.], [946., 984.], [2., 2.]]],
+            [[[3., 3.], [1177., 1231.], [1339., 1393.], [3., 3.]]],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels_in: 3,
+            channels_out: 2,
+            kernel_size_1: 1,
+            kernel_size_2: 2,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 2,
+            stride_3: 2,
+            dilation_1: 2,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 3,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [
+                [[[0., 0.]], [[4053., 6078.]], [[5349., 7914.]], [[0., 0.]]],
+                [
+                    [[1., 1.]],
+                    [[9778., 15151.]],
+                    [[13666., 20875.]],
+                    [[1., 1.]],
+                ],
+            ],
+            [
+                [
+                    [[0., 0.]],
+                    [[11829., 17094.]],
+                    [[13125., 18930.]],
+                    [[0., 0.]],
+                ],
+                [
+                    [[1., 1.]],
+                    [[33106., 49495.]],
+                    [[36994., 55219.]],
+                    [[1., 1.]],
+                ],
+            ],
+        ]));
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let options = ConvOptions::new(
+                [self.stride_1, self.stride_2, self.stride_3],
+                [self.padding_1, self.padding_2, self.padding_3],
+                [self.dilation_1, self.dilation_2, self.dilation_3],
+                self.groups,
+            );
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                options.clone(),
+            );
+            let output_fallback = TestTensor::from_primitive(conv3d_from_conv2d::<TestBackend>(
+                x.into_primitive(),
+                weight.into_primitive(),
+                Some(bias.into_primitive()),
+                options,
+            ));
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::{conv::conv_transpose3d_from_conv_transpose2d, ConvTransposeOptions};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[128., 280., 154.], [304., 664., 364.], [184., 400., 218.]],
+                [[352., 768., 420.], [832., 1808., 984.], [496., 1072., 580.]],
+                [[256., 552., 298.], [592., 1272., 684.], [344., 736., 394.]],
+            ],
+            [
+                [[193., 425., 235.], [465., 1017., 557.], [281., 609., 331.]],
+                [
+                    [545., 1185., 645.],
+                    [1281., 2769., 1497.],
+                    [753., 1617., 869.],
+                ],
+                [
+                    [385., 825., 443.],
+                    [881., 1881., 1005.],
+                    [505., 1073., 571.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_stride_padding_dilation() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 2,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [568., 0., 1232., 0., 668., 0.],
+                    [608., 0., 1320., 0., 716., 0.],
+                ],
+                [
+                    [656., 0., 1400., 0., 748., 0.],
+                    [728., 0., 1552., 0., 828., 0.],
+                ],
+                [
+                    [872., 0., 1856., 0., 988., 0.],
+                    [944., 0., 2008., 0., 1068., 0.],
+                ],
+            ],
+            [
+                [
+                    [809., 1., 1761., 1., 957., 1.],
+                    [849., 1., 1849., 1., 1005., 1.],
+                ],
+                [
+                    [1089., 1., 2313., 1., 1229., 1.],
+                    [1161., 1., 2465., 1., 1309., 1.],
+                ],
+                [
+                    [1305., 1., 2769., 1., 1469., 1.],
+                    [1377., 1., 2921., 1., 1549., 1.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 1,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 1,
+            height: 2,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[[0., 1.], [5., 11.], [17., 22.]]],
+            [[[5., 14.], [38., 60.], [46., 59.]]],
+            [[[112., 129.], [299., 337.], [201., 222.]]],
+            [[[165., 190.], [428., 482.], [278., 307.]]],
+        ]]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weights = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weights.num_elements())
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let options = ConvTransposeOptions::new(
+                [self.stride_1, self.stride_2, self.stride_3],
+                [self.padding_1, self.padding_2, self.padding_3],
+                [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                [self.dilation_1, self.dilation_2, self.dilation_3],
+                self.groups,
+            );
+            let output = conv_transpose3d(
+                x.clone(),
+                weights.clone(),
+                Some(bias.clone()),
+                options.clone(),
+            );
+            let output_fallback =
+                TestTensor::from_primitive(conv_transpose3d_from_conv_transpose2d::<TestBackend>(
+                    x.into_primitive(),
+                    weights.into_primitive(),
+                    Some(bias.into_primitive()),
+                    options,
+                ));
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
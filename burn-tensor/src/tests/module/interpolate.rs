@@ -0,0 +1,157 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{
+        interpolate::interpolate_from_index_select, InterpolateMode, InterpolateOptions,
+    };
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest_upsample() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [4, 5],
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0., 0., 1., 1., 2.],
+            [0., 0., 1., 1., 2.],
+            [3., 3., 4., 4., 5.],
+            [6., 6., 7., 7., 8.],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_nearest_downsample() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [2, 2],
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[[0., 1.], [3., 4.]]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [4, 5],
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.4000, 1.0000, 1.6000, 2.0000],
+            [1.8750, 2.2750, 2.8750, 3.4750, 3.8750],
+            [4.1250, 4.5250, 5.1250, 5.7250, 6.1250],
+            [6.0000, 6.4000, 7.0000, 7.6000, 8.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [4, 5],
+            mode: InterpolateMode::Bilinear,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0, 0.5, 1.0, 1.5, 2.0],
+            [2.0, 2.5, 3.0, 3.5, 4.0],
+            [4.0, 4.5, 5.0, 5.5, 6.0],
+            [6.0, 6.5, 7.0, 7.5, 8.0],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_downsample() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [2, 2],
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[[1.0, 2.5], [5.5, 7.0]]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [4, 5],
+            mode: InterpolateMode::Bicubic,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [-0.3113, 0.1007, 0.7847, 1.4687, 1.8807],
+            [1.4934, 1.9054, 2.5894, 3.2734, 3.6854],
+            [4.3146, 4.7266, 5.4106, 6.0946, 6.5066],
+            [6.1193, 6.5313, 7.2153, 7.8993, 8.3113],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic_align_corners() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            output_size: [4, 5],
+            mode: InterpolateMode::Bicubic,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.4062, 1.0000, 1.5938, 2.0000],
+            [1.7222, 2.1285, 2.7222, 3.3160, 3.7222],
+            [4.2778, 4.6840, 5.2778, 5.8715, 6.2778],
+            [6.0000, 6.4062, 7.0000, 7.5938, 8.0000],
+        ]]]));
+    }
+
+    struct InterpolateTestCase {
+        height: usize,
+        width: usize,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+        align_corners: bool,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, self.height, self.width]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let options = InterpolateOptions::new(self.mode, self.align_corners);
+            let output = interpolate(x.clone(), self.output_size, options.clone());
+            let output_fallback =
+                TestTensor::from_primitive(interpolate_from_index_select::<TestBackend>(
+                    x.into_primitive(),
+                    self.output_size,
+                    options,
+                ));
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(module_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool1d;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 3;
+        let padding = 0;
+        let stride = 1;
+
+        let x = TestTensor::from_floats([[[
+            0.0000, 0.7457, -0.9937, 0.5784, 0.2229, -0.8755, 0.9437, -0.3821,
+        ]]]);
+        let y = TestTensor::from_floats([[[0.7457, 0.7457, 0.5784, 0.5784, 0.9437, 0.9437]]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_padding_and_stride() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestTensor::from_floats([[
+            [
+                0.0000, 0.7457, -0.9937, 0.5784, 0.2229, -0.8755, 0.9437, -0.3821,
+            ],
+            [
+                -0.3821, 0.9437, -0.8755, 0.2229, 0.5784, -0.9937, 0.7457, 0.0000,
+            ],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [0.7457, 0.7457, 0.5784, 0.9437],
+            [0.9437, 0.9437, 0.5784, 0.7457],
+        ]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
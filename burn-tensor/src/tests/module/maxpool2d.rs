@@ -222,6 +222,18 @@ mod tests {
         assert_eq!(indexes.value, output_indexes.into_data().value);
     }
 
+    #[test]
+    fn test_max_pool2d_with_indexes_non_square() {
+        let x = TestTensor::from_floats([[[[0.1, 0.2, 0.3], [0.6, 0.5, 0.4]]]]);
+        let indexes = Data::<i64, 4>::from([[[[3, 4]]]]);
+        let y = TestTensor::from_floats([[[[0.6, 0.5]]]]);
+
+        let (output, output_indexes) = max_pool2d_with_indexes(x, [2, 2], [1, 1], [0, 0]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indexes.value, output_indexes.into_data().value);
+    }
+
     #[test]
     fn test_max_pool2d_complex() {
         let batch_size = 1;
@@ -0,0 +1,143 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indexes};
+    use burn_tensor::ops::{pool::max_pool3d_with_indexes_from_max_pool2d, IntTensorOps};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [[13., 14.], [16., 17.]],
+            [[22., 23.], [25., 26.]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let test = MaxPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            depth: 3,
+            height: 2,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[[5., 7.], [5., 7.]], [[21., 23.], [21., 23.]]],
+            [[[29., 31.], [29., 31.]], [[45., 47.], [45., 47.]]],
+        ]]));
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indexes() {
+        let kernel_size = [2, 2, 2];
+        let padding = [1, 0, 1];
+        let stride = [1, 1, 2];
+
+        let x = TestTensor::from_floats([[[
+            [[0.2479, -0.6386, 0.3166], [0.7065, 0.1940, -0.6305]],
+            [[-0.5416, 0.8602, 0.8129], [0.3358, -0.3059, 0.8293]],
+        ]]]);
+        let indexes = Data::<i64, 5>::from([[[[[3, 2]], [[3, 7]], [[9, 7]]]]]);
+        let y = TestTensor::from_floats([[[
+            [[0.7065, 0.3166]],
+            [[0.7065, 0.8602]],
+            [[0.3358, 0.8602]],
+        ]]]);
+
+        let (output, output_indexes) =
+            max_pool3d_with_indexes(x.clone(), kernel_size, stride, padding);
+        let output_fallback = max_pool3d_with_indexes_from_max_pool2d::<TestBackend>(
+            x.into_primitive(),
+            kernel_size,
+            stride,
+            padding,
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indexes.value, output_indexes.into_data().value);
+        assert_eq!(
+            indexes.value,
+            TestBackend::int_into_data(output_fallback.indexes).value
+        );
+    }
+
+    struct MaxPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl MaxPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let kernel_size = [self.kernel_size_1, self.kernel_size_2, self.kernel_size_3];
+            let stride = [self.stride_1, self.stride_2, self.stride_3];
+            let padding = [self.padding_1, self.padding_2, self.padding_3];
+            let output = max_pool3d(x.clone(), kernel_size, stride, padding);
+            let output_fallback = TestTensor::from_primitive(
+                max_pool3d_with_indexes_from_max_pool2d::<TestBackend>(
+                    x.into_primitive(),
+                    kernel_size,
+                    stride,
+                    padding,
+                )
+                .output,
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+            y.to_data()
+                .assert_approx_eq(&output_fallback.into_data(), 3);
+        }
+    }
+}
@@ -86,4 +86,41 @@ mod tests {
             Data::new(vec![5.0, 5.0, 3.0, 11.0, -3.0, 6.0], Shape::new([2, 1, 3]))
         );
     }
+
+    #[test]
+    fn test_should_prod() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [-1.0, 4.0, 0.5]]);
+
+        let data_actual = tensor.prod().to_data();
+
+        assert_eq!(data_actual, Data::from([-12.0]));
+    }
+
+    #[test]
+    fn test_should_prod_last_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [-1.0, 4.0, 0.5]]);
+
+        let data_actual = tensor.prod_dim(1).to_data();
+
+        assert_eq!(data_actual, Data::from([[6.0], [-2.0]]));
+    }
+
+    #[test]
+    fn test_should_prod_first_dim_int() {
+        let tensor = TestTensorInt::from_ints([[1, 2, 3], [-1, 4, 5]]);
+
+        let data_actual = tensor.prod_dim(0).to_data();
+
+        assert_eq!(data_actual, Data::from([[-1, 8, 15]]));
+    }
+
+    #[test]
+    fn test_should_logsumexp_last_dim() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [1000.0, 1000.0, 1000.0]]);
+
+        let data_actual = tensor.logsumexp(1).to_data();
+
+        // The second row would overflow without subtracting the maximum first.
+        data_actual.assert_approx_eq(&Data::from([[2.407606], [1001.098612]]), 3);
+    }
 }
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_atan2_ops() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0], [-1.0, 0.0]]));
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, -1.0], [-1.0, 2.0]]));
+
+        let data_actual = tensor_1.atan2(tensor_2).into_data();
+
+        let data_expected = Data::from([[0.7854, 2.3562], [-2.3562, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_atan2_broadcasting() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0], [-2.0]]));
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0]]));
+
+        let data_actual = tensor_1.atan2(tensor_2).into_data();
+
+        let data_expected = Data::from([[0.7854, 0.4636], [-1.1071, -0.7854]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
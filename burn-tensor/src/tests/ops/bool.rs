@@ -0,0 +1,122 @@
+#[burn_tensor_testgen::testgen(bool)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn test_bool_and_or_xor() {
+        let lhs = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, true, false],
+            [false, true, false],
+        ]));
+        let rhs = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, false, false],
+            [true, true, true],
+        ]));
+
+        let and = lhs.clone() & rhs.clone();
+        let or = lhs.clone() | rhs.clone();
+        let xor = lhs ^ rhs;
+
+        assert_eq!(
+            and.into_data(),
+            Data::from([[true, false, false], [false, true, false]])
+        );
+        assert_eq!(
+            or.into_data(),
+            Data::from([[true, true, false], [true, true, true]])
+        );
+        assert_eq!(
+            xor.into_data(),
+            Data::from([[false, true, false], [true, false, true]])
+        );
+    }
+
+    #[test]
+    fn test_bool_or_broadcast() {
+        let lhs = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true], [false]]));
+        let rhs = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, true, false]]));
+
+        let data_actual = lhs.bool_or(rhs).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[true, true, true], [false, true, false]])
+        );
+    }
+
+    #[test]
+    fn test_bool_not() {
+        let tensor =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+
+        let data_actual = (!tensor).into_data();
+
+        assert_eq!(data_actual, Data::from([[false, true], [true, false]]));
+    }
+
+    #[test]
+    fn test_bool_equal() {
+        let lhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, true, false, false]));
+        let rhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false, true, false]));
+
+        let data_actual = lhs.equal(rhs).into_data();
+
+        assert_eq!(data_actual, Data::from([true, false, false, true]));
+    }
+
+    #[test]
+    fn test_bool_any_all() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, false, false],
+            [true, true, true],
+        ]));
+        let falses = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, false]]));
+
+        assert_eq!(tensor.clone().any().into_data(), Data::from([true]));
+        assert_eq!(tensor.all().into_data(), Data::from([false]));
+        assert_eq!(falses.clone().any().into_data(), Data::from([false]));
+        assert_eq!(falses.bool_not().all().into_data(), Data::from([true]));
+    }
+
+    #[test]
+    fn test_bool_any_all_dim() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, false, false],
+            [true, true, false],
+        ]));
+
+        assert_eq!(
+            tensor.clone().any_dim(0).into_data(),
+            Data::from([[true, true, false]])
+        );
+        assert_eq!(
+            tensor.clone().any_dim(1).into_data(),
+            Data::from([[true], [true]])
+        );
+        assert_eq!(
+            tensor.clone().all_dim(0).into_data(),
+            Data::from([[true, false, false]])
+        );
+        assert_eq!(
+            tensor.all_dim(1).into_data(),
+            Data::from([[false], [false]])
+        );
+    }
+
+    #[test]
+    fn test_bool_nonzero_argwhere() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, true, false],
+            [true, false, true],
+        ]));
+
+        let indexes = tensor.clone().nonzero();
+        let argwhere = tensor.argwhere();
+
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0].to_data(), Data::from([0, 1, 1]));
+        assert_eq!(indexes[1].to_data(), Data::from([1, 0, 2]));
+        assert_eq!(argwhere.into_data(), Data::from([[0, 1], [1, 0], [1, 2]]));
+    }
+}
@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_cumsum_last_dim() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.cumsum(1).into_data();
+
+        assert_eq!(data_actual, Data::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]));
+    }
+
+    #[test]
+    fn test_cumsum_first_dim() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.cumsum(0).into_data();
+
+        assert_eq!(data_actual, Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]));
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::from_ints([[1, -2, 3], [4, 5, -6]]);
+
+        let data_actual = tensor.cumsum(1).into_data();
+
+        assert_eq!(data_actual, Data::from([[1, -1, 2], [4, 9, 3]]));
+    }
+
+    #[test]
+    fn test_cumprod_last_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]);
+
+        let data_actual = tensor.cumprod(1).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[1.0, 2.0, 6.0], [-1.0, -0.5, -2.0]])
+        );
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::from_ints([[1, 2, 3], [4, -5, 6]]);
+
+        let data_actual = tensor.cumprod(0).into_data();
+
+        assert_eq!(data_actual, Data::from([[1, 2, 3], [4, -10, 18]]));
+    }
+
+    #[test]
+    fn test_cumsum_mid_dim_3d_non_continuous() {
+        let tensor = TestTensor::from_data([
+            [[2.0, 4.0, 1.0], [7.0, -5.0, 3.0]],
+            [[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]],
+        ]);
+
+        let data_actual = tensor.swap_dims(0, 2).cumsum(1).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::new(
+                vec![2.0, 3.0, 9.0, 7.0, 4.0, 1.0, -1.0, 3.0, 1.0, 2.0, 4.0, 5.0],
+                Shape::new([3, 2, 2])
+            )
+        );
+    }
+}
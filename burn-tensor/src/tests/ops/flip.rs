@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_flip_ops() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor.flip(&[1]).into_data();
+
+        let data_expected = Data::from([[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_flip_ops_multiple_dims() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 2, 3], [4, 5, 6]]));
+
+        let data_actual = tensor.flip(&[0, 1]).into_data();
+
+        let data_expected = Data::from([[6, 5, 4], [3, 2, 1]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_roll_ops() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor.roll(&[1], &[1]).into_data();
+
+        let data_expected = Data::from([[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_roll_ops_negative_and_wrapping_shifts() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 2, 3], [4, 5, 6]]));
+
+        let data_actual = tensor.roll(&[-1, 3], &[1, 0]).into_data();
+
+        let data_expected = Data::from([[5, 6, 4], [2, 3, 1]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -1,13 +1,19 @@
+mod abs;
 mod add;
 mod aggregation;
 mod arange;
 mod arg;
+mod atan2;
+mod bool;
 mod cat;
+mod clamp;
 mod cos;
+mod cumulative;
 mod div;
 mod erf;
 mod exp;
 mod flatten;
+mod flip;
 mod gather_scatter;
 mod index;
 mod index_select;
@@ -19,12 +25,18 @@ mod matmul;
 mod maxmin;
 mod mul;
 mod neg;
+mod pad;
+mod pow;
 mod powf;
+mod recip;
 mod repeat;
 mod reshape;
+mod round;
 mod sin;
+mod sort;
 mod sqrt;
 mod squeeze;
 mod sub;
 mod tanh;
 mod transpose;
+mod triangular;
@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, PadMode, Tensor};
+
+    #[test]
+    fn should_support_constant_pad() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor
+            .pad([(1, 0), (1, 2)], PadMode::Constant(0.5))
+            .into_data();
+
+        let data_expected = Data::from([
+            [0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+            [0.5, 1.0, 2.0, 3.0, 0.5, 0.5],
+            [0.5, 4.0, 5.0, 6.0, 0.5, 0.5],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_constant_pad_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 2], [3, 4]]));
+
+        let data_actual = tensor
+            .pad([(0, 1), (1, 0)], PadMode::Constant(0.0))
+            .into_data();
+
+        let data_expected = Data::from([[0, 1, 2], [0, 3, 4], [0, 0, 0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_reflect_pad() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect).into_data();
+
+        let data_expected = Data::from([
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_replicate_pad() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor.pad([(1, 1), (1, 2)], PadMode::Replicate).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 1.0, 2.0, 3.0, 3.0, 3.0],
+            [1.0, 1.0, 2.0, 3.0, 3.0, 3.0],
+            [4.0, 4.0, 5.0, 6.0, 6.0, 6.0],
+            [4.0, 4.0, 5.0, 6.0, 6.0, 6.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_circular_pad() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let data_actual = tensor.pad([(1, 0), (2, 1)], PadMode::Circular).into_data();
+
+        let data_expected = Data::from([
+            [5.0, 6.0, 4.0, 5.0, 6.0, 4.0],
+            [2.0, 3.0, 1.0, 2.0, 3.0, 1.0],
+            [5.0, 6.0, 4.0, 5.0, 6.0, 4.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_padding_exceeds_dimension() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0]]));
+
+        let _ = tensor.pad([(0, 0), (3, 0)], PadMode::Reflect);
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(pow)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_pow_ops() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[3.0, 0.5], [2.0, 0.0]]));
+
+        let data_actual = tensor_1.pow(tensor_2).into_data();
+
+        let data_expected = Data::from([[1.0, 1.4142], [9.0, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_pow_broadcasting() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 3.0], [4.0, 5.0]]));
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0], [-1.0]]));
+
+        let data_actual = tensor_1.pow(tensor_2).into_data();
+
+        let data_expected = Data::from([[4.0, 9.0], [0.25, 0.2]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
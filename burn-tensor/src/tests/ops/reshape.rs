@@ -24,4 +24,15 @@ mod tests {
         let data_expected = Data::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_reshape_transposed() {
+        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.transpose().reshape([6]).into_data();
+
+        let data_expected = Data::from([0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(round)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_floor_ops() {
+        let data = Data::from([[-1.5, -0.2, 0.0], [0.7, 1.0, 2.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.floor().into_data();
+
+        let data_expected = Data::from([[-2.0, -1.0, 0.0], [0.0, 1.0, 2.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_ceil_ops() {
+        let data = Data::from([[-1.5, -0.2, 0.0], [0.7, 1.0, 2.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.ceil().into_data();
+
+        let data_expected = Data::from([[-1.0, 0.0, 0.0], [1.0, 1.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_round_half_to_even() {
+        let data = Data::from([[-1.5, -0.5, 0.5], [1.5, 2.5, 2.7]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-2.0, 0.0, 0.0], [2.0, 2.0, 3.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,157 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_sort_float() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+
+        let ascending = tensor.clone().sort(1);
+        let descending = tensor.sort_descending(1);
+
+        assert_eq!(
+            ascending.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [0.0, 4.0, 5.0]])
+        );
+        assert_eq!(
+            descending.into_data(),
+            Data::from([[3.0, 2.0, 1.0], [5.0, 4.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn test_sort_int() {
+        let tensor = TestTensorInt::from_ints([[3, 1, 2], [0, 5, 4]]);
+
+        let ascending = tensor.clone().sort(1);
+        let descending = tensor.sort_descending(1);
+
+        assert_eq!(ascending.into_data(), Data::from([[1, 2, 3], [0, 4, 5]]));
+        assert_eq!(descending.into_data(), Data::from([[3, 2, 1], [5, 4, 0]]));
+    }
+
+    #[test]
+    fn test_sort_3d_along_first_dim() {
+        let tensor = TestTensor::from_floats([
+            [[4.0, 1.0], [0.0, 7.0]],
+            [[2.0, 3.0], [9.0, 6.0]],
+            [[5.0, 2.0], [1.0, 8.0]],
+        ]);
+
+        let output = tensor.sort(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[2.0, 1.0], [0.0, 6.0]],
+                [[4.0, 2.0], [1.0, 7.0]],
+                [[5.0, 3.0], [9.0, 8.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_with_indices_should_be_stable() {
+        let tensor = TestTensor::from_floats([[2.0, 1.0, 2.0, 1.0], [0.0, 0.0, 3.0, -1.0]]);
+
+        let (values, indices) = tensor.clone().sort_with_indices(1);
+        let (values_descending, indices_descending) = tensor.sort_descending_with_indices(1);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[1.0, 1.0, 2.0, 2.0], [-1.0, 0.0, 0.0, 3.0]])
+        );
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[1, 3, 0, 2], [3, 0, 1, 2]])
+        );
+        assert_eq!(
+            values_descending.into_data(),
+            Data::from([[2.0, 2.0, 1.0, 1.0], [3.0, 0.0, 0.0, -1.0]])
+        );
+        assert_eq!(
+            indices_descending.into_data(),
+            Data::from([[0, 2, 1, 3], [2, 0, 1, 3]])
+        );
+    }
+
+    #[test]
+    fn test_argsort() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+
+        let ascending = tensor.clone().argsort(1);
+        let descending = tensor.clone().argsort_descending(1);
+        let along_first_dim = tensor.argsort(0);
+
+        assert_eq!(ascending.into_data(), Data::from([[1, 2, 0], [0, 2, 1]]));
+        assert_eq!(descending.into_data(), Data::from([[0, 2, 1], [1, 2, 0]]));
+        assert_eq!(
+            along_first_dim.into_data(),
+            Data::from([[1, 0, 0], [0, 1, 1]])
+        );
+    }
+
+    #[test]
+    fn test_sort_float_nan_is_largest() {
+        let tensor = TestTensor::from_floats([3.0, f32::NAN, 1.0, 2.0]);
+
+        let ascending = tensor.clone().sort(0).into_data().value;
+        let descending = tensor.sort_descending(0).into_data().value;
+
+        assert_eq!(&ascending[..3], &[1.0, 2.0, 3.0]);
+        assert!(ascending[3].is_nan());
+        assert!(descending[0].is_nan());
+        assert_eq!(&descending[1..], &[3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_argsort_float_nan_is_largest() {
+        let tensor =
+            TestTensor::from_floats([[3.0, f32::NAN, 1.0, 2.0], [f32::NAN, 0.0, f32::NAN, -1.0]]);
+
+        let ascending = tensor.clone().argsort(1);
+        let descending = tensor.argsort_descending(1);
+
+        assert_eq!(
+            ascending.into_data(),
+            Data::from([[2, 3, 0, 1], [3, 1, 0, 2]])
+        );
+        assert_eq!(
+            descending.into_data(),
+            Data::from([[1, 0, 3, 2], [0, 2, 1, 3]])
+        );
+    }
+
+    #[test]
+    fn test_topk() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0, 7.0], [0.0, 5.0, 4.0, 6.0]]);
+
+        let output = tensor.clone().topk(2, 1);
+        let (values, indices) = tensor.topk_with_indices(3, 1);
+
+        assert_eq!(output.into_data(), Data::from([[7.0, 3.0], [6.0, 5.0]]));
+        assert_eq!(
+            values.into_data(),
+            Data::from([[7.0, 3.0, 2.0], [6.0, 5.0, 4.0]])
+        );
+        assert_eq!(indices.into_data(), Data::from([[3, 0, 2], [3, 1, 2]]));
+    }
+
+    #[test]
+    fn test_topk_int() {
+        let tensor = TestTensorInt::from_ints([5, 9, 1, 7]);
+
+        let output = tensor.topk(2, 0);
+
+        assert_eq!(output.into_data(), Data::from([9, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_should_panic_when_k_is_too_large() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0]]);
+
+        let _output = tensor.topk(4, 1);
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(triangular)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_tril_ops() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]));
+
+        let data_actual = tensor.clone().tril(0).into_data();
+        let data_expected = Data::from([[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(data_expected, data_actual);
+
+        let data_actual = tensor.tril(-1).into_data();
+        let data_expected = Data::from([[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [7.0, 8.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_tril_ops_non_square() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 2, 3], [4, 5, 6]]));
+
+        let data_actual = tensor.tril(1).into_data();
+
+        let data_expected = Data::from([[1, 2, 0], [4, 5, 6]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_triu_ops() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]));
+
+        let data_actual = tensor.triu(1).into_data();
+
+        let data_expected = Data::from([[0.0, 2.0, 3.0], [0.0, 0.0, 6.0], [0.0, 0.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_triu_ops_batched() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data(Data::from([
+            [[1, 2, 3], [4, 5, 6]],
+            [[7, 8, 9], [10, 11, 12]],
+        ]));
+
+        let data_actual = tensor.triu(0).into_data();
+
+        let data_expected = Data::from([[[1, 2, 3], [0, 5, 6]], [[7, 8, 9], [0, 11, 12]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -35,4 +35,8 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before they were clipped, only available when the
+    /// gradients are clipped and the optimizer stepped during the iteration.
+    pub grad_norm: Option<f64>,
 }
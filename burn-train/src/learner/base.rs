@@ -1,8 +1,9 @@
 use crate::checkpoint::Checkpointer;
-use crate::LearnerCallback;
+use crate::{LRSchedulerMetric, LearnerCallback};
+use burn_core::grad_clipping::GradientClipping;
 use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::{ADModule, Module};
-use burn_core::optim::Optimizer;
+use burn_core::optim::{ModelEma, ModelEmaRecord, Optimizer};
 use burn_core::tensor::backend::ADBackend;
 
 /// Learner struct encapsulating all components necessary to train a Neural Network model.
@@ -18,19 +19,25 @@ where
     pub(super) model: M,
     pub(super) optim: O,
     pub(super) lr_scheduler: LR,
+    pub(super) lr_scheduler_metric: Option<LRSchedulerMetric<VO>>,
     pub(super) num_epochs: usize,
     pub(super) callback: Box<dyn LearnerCallback<TO, VO>>,
     pub(super) checkpoint: Option<usize>,
     pub(super) checkpointer_model: CheckpointModel<M, B>,
     pub(super) checkpointer_optimizer: CheckpointOptim<O, M, B>,
     pub(super) checkpointer_scheduler: CheckpointScheduler<LR>,
+    pub(super) checkpointer_model_ema: CheckpointModelEma<M, B>,
     pub(super) grad_accumulation: Option<usize>,
+    pub(super) grad_clipping: Option<GradientClipping>,
+    pub(super) model_ema: Option<ModelEma<B, M>>,
+    pub(super) validate_with_ema: bool,
     pub(super) devices: Vec<B::Device>,
 }
 
 type CheckpointModel<M, B> = Option<Box<dyn Checkpointer<<M as Module<B>>::Record>>>;
 type CheckpointOptim<O, M, B> = Option<Box<dyn Checkpointer<<O as Optimizer<M, B>>::Record>>>;
 type CheckpointScheduler<LR> = Option<Box<dyn Checkpointer<<LR as LRScheduler>::Record>>>;
+type CheckpointModelEma<M, B> = Option<Box<dyn Checkpointer<ModelEmaRecord<B, M>>>>;
 
 impl<B, M, O, LR, TO, VO> Learner<B, M, O, LR, TO, VO>
 where
@@ -65,6 +72,16 @@ where
         }
     }
 
+    pub(super) fn checkpoint_model_ema(
+        model_ema: &Option<ModelEma<B, M>>,
+        checkpointer_model_ema: &CheckpointModelEma<M, B>,
+        epoch: usize,
+    ) {
+        if let (Some(model_ema), Some(checkpointer)) = (model_ema, checkpointer_model_ema) {
+            checkpointer.save(epoch, model_ema.to_record()).unwrap();
+        }
+    }
+
     pub(super) fn load_checkpoint(mut self, epoch: usize) -> Self {
         if let Some(checkpointer) = &self.checkpointer_model {
             let record = checkpointer.restore(epoch).unwrap();
@@ -81,6 +98,13 @@ where
             self.lr_scheduler = self.lr_scheduler.load_record(record);
         }
 
+        if let Some(checkpointer) = &self.checkpointer_model_ema {
+            if let Some(model_ema) = self.model_ema {
+                let record = checkpointer.restore(epoch).unwrap();
+                self.model_ema = Some(model_ema.load_record(record));
+            }
+        }
+
         self
     }
 }
@@ -5,10 +5,11 @@ use crate::logger::FileMetricLogger;
 use crate::metric::dashboard::cli::CLIDashboardRenderer;
 use crate::metric::dashboard::Dashboard;
 use crate::metric::{Adaptor, Metric, Numeric};
-use crate::AsyncTrainerCallback;
+use crate::{AsyncTrainerCallback, LRSchedulerMetric};
+use burn_core::grad_clipping::GradientClipping;
 use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::ADModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{ModelEmaConfig, ModelEmaRecord, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::ADBackend;
 
@@ -25,13 +26,18 @@ where
     S: LRScheduler,
 {
     dashboard: Dashboard<T, V>,
+    lr_scheduler_metric: Option<LRSchedulerMetric<V>>,
     checkpointer_model: Option<Arc<dyn Checkpointer<M::Record> + Send + Sync>>,
     checkpointer_optimizer: Option<Arc<dyn Checkpointer<O::Record> + Send + Sync>>,
     checkpointer_scheduler: Option<Arc<dyn Checkpointer<S::Record> + Send + Sync>>,
+    checkpointer_model_ema: Option<Arc<dyn Checkpointer<ModelEmaRecord<B, M>> + Send + Sync>>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
     grad_accumulation: Option<usize>,
+    grad_clipping: Option<GradientClipping>,
+    model_ema: Option<ModelEmaConfig>,
+    validate_with_ema: bool,
     devices: Vec<B::Device>,
 }
 
@@ -56,13 +62,18 @@ where
 
         Self {
             dashboard: Dashboard::new(renderer, logger_train, logger_valid),
+            lr_scheduler_metric: None,
             num_epochs: 1,
             checkpoint: None,
             checkpointer_model: None,
             checkpointer_optimizer: None,
             checkpointer_scheduler: None,
+            checkpointer_model_ema: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            grad_clipping: None,
+            model_ema: None,
+            validate_with_ema: false,
             devices: vec![B::Device::default()],
         }
     }
@@ -100,6 +111,40 @@ where
         self
     }
 
+    /// Enable gradient clipping.
+    ///
+    /// # Notes
+    ///
+    /// The gradients are clipped after being accumulated, right before the optimizer step. The
+    /// optimizer must not clip the gradients as well, otherwise [build](Self::build) panics.
+    pub fn grad_clipping(mut self, grad_clipping: GradientClipping) -> Self {
+        self.grad_clipping = Some(grad_clipping);
+        self
+    }
+
+    /// Keep an [exponential moving average](burn_core::optim::ModelEma) of the model weights,
+    /// updated after each optimizer step.
+    ///
+    /// # Notes
+    ///
+    /// The averaged model is saved along with the model by the
+    /// [file checkpointer](Self::with_file_checkpointer).
+    pub fn model_ema(mut self, config: ModelEmaConfig) -> Self {
+        self.model_ema = Some(config);
+        self
+    }
+
+    /// Run the validation on the [averaged model](Self::model_ema) instead of the trained one.
+    ///
+    /// # Notes
+    ///
+    /// The averaged model is then the one returned at the end of the training. The
+    /// [moving average](Self::model_ema) must be enabled, otherwise [build](Self::build) panics.
+    pub fn validate_with_ema(mut self) -> Self {
+        self.validate_with_ema = true;
+        self
+    }
+
     /// Register a training metric and displays it on a plot.
     ///
     /// # Notes
@@ -131,6 +176,24 @@ where
         self
     }
 
+    /// Register a validation metric reported to the [learning rate scheduler](LRScheduler) at the
+    /// end of each epoch.
+    ///
+    /// # Notes
+    ///
+    /// Only metric-aware schedulers, such as
+    /// [reduce on plateau](burn_core::lr_scheduler::plateau::ReduceLROnPlateau), use the reported
+    /// value. The metric isn't displayed, register it with [metric_valid](Self::metric_valid) or
+    /// [metric_valid_plot](Self::metric_valid_plot) as well to do so.
+    pub fn lr_scheduler_metric<M>(mut self, metric: M) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        V: Adaptor<M::Input>,
+    {
+        self.lr_scheduler_metric = Some(LRSchedulerMetric::new(metric));
+        self
+    }
+
     /// The number of epochs the training should last.
     pub fn num_epochs(mut self, num_epochs: usize) -> Self {
         self.num_epochs = num_epochs;
@@ -172,17 +235,31 @@ where
             num_keep,
         )));
         self.checkpointer_scheduler = Some(Arc::new(FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
             num_keep,
         )));
+        self.checkpointer_model_ema = Some(Arc::new(FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "model_ema",
+            num_keep,
+        )));
         self
     }
 
     /// Create the [learner](Learner) from a [model](ADModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LRScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
+    ///
+    /// # Panics
+    ///
+    /// If [gradient clipping](Self::grad_clipping) is enabled while the optimizer already clips
+    /// the gradients, since they would be clipped twice.
+    ///
+    /// If the validation should [use the averaged model](Self::validate_with_ema) while the
+    /// [moving average](Self::model_ema) isn't enabled.
     pub fn build(
         self,
         model: Model,
@@ -193,7 +270,17 @@ where
         Model::Record: 'static,
         Optim::Record: 'static,
         LR::Record: 'static,
+        ModelEmaRecord<B, Model>: 'static,
     {
+        assert!(
+            self.grad_clipping.is_none() || !optim.has_grad_clipping(),
+            "The gradients are clipped by both the learner and the optimizer, only one of them \
+             should clip the gradients."
+        );
+        assert!(
+            !self.validate_with_ema || self.model_ema.is_some(),
+            "The validation can't use the averaged model when the moving average isn't enabled."
+        );
         self.init_logger();
         let callack = Box::new(self.dashboard);
         let callback = Box::new(AsyncTrainerCallback::new(callack));
@@ -222,18 +309,32 @@ where
             }
             None => None,
         };
+        let checkpointer_model_ema = match self.checkpointer_model_ema {
+            Some(checkpointer) if self.model_ema.is_some() => {
+                let checkpointer: Box<dyn Checkpointer<ModelEmaRecord<B, Model>>> =
+                    Box::new(AsyncCheckpointer::new(checkpointer));
+                Some(checkpointer)
+            }
+            _ => None,
+        };
+        let model_ema = self.model_ema.map(|config| config.init(&model));
 
         Learner {
             model,
             optim,
             lr_scheduler,
+            lr_scheduler_metric: self.lr_scheduler_metric,
             num_epochs: self.num_epochs,
             callback,
             checkpoint: self.checkpoint,
             checkpointer_model,
             checkpointer_optimizer,
             checkpointer_scheduler,
+            checkpointer_model_ema,
             grad_accumulation: self.grad_accumulation,
+            grad_clipping: self.grad_clipping,
+            model_ema,
+            validate_with_ema: self.validate_with_ema,
             devices: self.devices,
         }
     }
@@ -243,3 +344,58 @@ where
         update_log_file(file_path.as_str());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::grad_clipping::GradientClippingConfig;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+
+    type TestADBackend = burn_autodiff::ADBackendDecorator<TestBackend>;
+
+    fn directory(name: &str) -> String {
+        let directory = std::env::temp_dir().join(format!("burn-train-learner-builder-{name}"));
+        directory.to_str().unwrap().to_string()
+    }
+
+    fn model() -> Linear<TestADBackend> {
+        LinearConfig::new(4, 2).init()
+    }
+
+    #[test]
+    fn should_clip_the_gradients_in_the_learner() {
+        let optim = SgdConfig::new().init::<TestADBackend, Linear<TestADBackend>>();
+        let learner = LearnerBuilder::<_, (), (), _, _, _>::new(&directory("learner"))
+            .grad_clipping(GradientClipping::Norm(1.0))
+            .build(model(), optim, 1e-2);
+
+        assert!(matches!(
+            learner.grad_clipping,
+            Some(GradientClipping::Norm(threshold)) if threshold == 1.0
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "clipped by both")]
+    fn should_not_clip_the_gradients_in_both_the_learner_and_the_optimizer() {
+        let optim = SgdConfig::new()
+            .with_gradient_clipping(Some(GradientClippingConfig::Norm(1.0)))
+            .init::<TestADBackend, Linear<TestADBackend>>();
+
+        LearnerBuilder::<_, (), (), _, _, _>::new(&directory("both"))
+            .grad_clipping(GradientClipping::Norm(1.0))
+            .build(model(), optim, 1e-2);
+    }
+
+    #[test]
+    #[should_panic(expected = "averaged model")]
+    fn should_not_validate_with_ema_without_model_ema() {
+        let optim = SgdConfig::new().init::<TestADBackend, Linear<TestADBackend>>();
+
+        LearnerBuilder::<_, (), (), _, _, _>::new(&directory("ema"))
+            .validate_with_ema()
+            .build(model(), optim, 1e-2);
+    }
+}
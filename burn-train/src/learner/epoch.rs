@@ -1,13 +1,16 @@
 use burn_core::{
     data::dataloader::DataLoader,
+    grad_clipping::GradientClipping,
     lr_scheduler::LRScheduler,
     module::ADModule,
-    optim::{GradientsAccumulator, Optimizer},
-    tensor::backend::ADBackend,
+    optim::{GradientsAccumulator, GradientsParams, ModelEma, Optimizer},
+    tensor::{backend::ADBackend, ElementConversion, Tensor},
 };
 use std::sync::Arc;
 
-use crate::{LearnerCallback, LearnerItem, MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    LRSchedulerMetric, LearnerCallback, LearnerItem, MultiDevicesTrainStep, TrainStep, ValidStep,
+};
 
 /// A validation epoch.
 #[derive(new)]
@@ -24,6 +27,7 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    grad_clipping: Option<GradientClipping>,
 }
 
 impl<I> ValidEpoch<I> {
@@ -33,7 +37,17 @@ impl<I> ValidEpoch<I> {
     ///
     /// * `model` - The model to validate.
     /// * `callback` - The callback to use.
-    pub fn run<B, M, TO, VO>(&self, model: &M, callback: &mut Box<dyn LearnerCallback<TO, VO>>)
+    /// * `lr_scheduler_metric` - The metric reported to the learning rate scheduler, if any.
+    ///
+    /// # Returns
+    ///
+    /// The value of the metric reported to the learning rate scheduler for the epoch.
+    pub fn run<B, M, TO, VO>(
+        &self,
+        model: &M,
+        callback: &mut Box<dyn LearnerCallback<TO, VO>>,
+        mut lr_scheduler_metric: Option<&mut LRSchedulerMetric<VO>>,
+    ) -> Option<f64>
     where
         B: ADBackend,
         M: ADModule<B>,
@@ -57,11 +71,18 @@ impl<I> ValidEpoch<I> {
                 self.epoch_total,
                 iteration,
                 None,
+                None,
             );
 
+            if let Some(metric) = lr_scheduler_metric.as_mut() {
+                metric.update(&item);
+            }
+
             callback.on_valid_item(item);
         }
         callback.on_valid_end_epoch(self.epoch);
+
+        lr_scheduler_metric.and_then(|metric| metric.end_epoch())
     }
 }
 
@@ -73,6 +94,7 @@ impl<TI> TrainEpoch<TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
+    /// * `model_ema` - The averaged model to update after each optimizer step, if any.
     /// * `callback` - The callback to use.
     ///
     /// # Returns
@@ -83,6 +105,7 @@ impl<TI> TrainEpoch<TI> {
         mut model: M,
         mut optim: O,
         scheduler: &mut LR,
+        mut model_ema: Option<&mut ModelEma<B, M>>,
         callback: &mut Box<dyn LearnerCallback<TO, VO>>,
     ) -> (M, O)
     where
@@ -106,6 +129,7 @@ impl<TI> TrainEpoch<TI> {
 
             let progress = iterator.progress();
             let item = model.step(item);
+            let mut grad_norm = None;
 
             match self.grad_accumulation {
                 Some(accumulation) => {
@@ -113,12 +137,19 @@ impl<TI> TrainEpoch<TI> {
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
+                        let (grads, norm) = self.clip_gradients(&model, accumulator.grads());
                         model = optim.step(lr, model, grads);
+                        grad_norm = read_grad_norm(norm, &optim);
+                        update_model_ema(&mut model_ema, &model);
                         accumulation_current = 0;
                     }
                 }
-                None => model = optim.step(lr, model, item.grads),
+                None => {
+                    let (grads, norm) = self.clip_gradients(&model, item.grads);
+                    model = optim.step(lr, model, grads);
+                    grad_norm = read_grad_norm(norm, &optim);
+                    update_model_ema(&mut model_ema, &model);
+                }
             }
 
             let item = LearnerItem::new(
@@ -128,6 +159,7 @@ impl<TI> TrainEpoch<TI> {
                 self.epoch_total,
                 iteration,
                 Some(lr),
+                grad_norm,
             );
 
             callback.on_train_item(item);
@@ -146,6 +178,7 @@ impl<TI> TrainEpoch<TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
+    /// * `model_ema` - The averaged model to update after each optimizer step, if any.
     /// * `callback` - The callback to use.
     /// * `devices` - The devices to use.
     ///
@@ -157,6 +190,7 @@ impl<TI> TrainEpoch<TI> {
         mut model: M,
         mut optim: O,
         lr_scheduler: &mut S,
+        mut model_ema: Option<&mut ModelEma<B, M>>,
         callback: &mut Box<dyn LearnerCallback<TO, VO>>,
         devices: Vec<B::Device>,
     ) -> (M, O)
@@ -198,13 +232,16 @@ impl<TI> TrainEpoch<TI> {
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
+                let mut grad_norm = None;
 
                 accumulator.accumulate(&model, grads);
                 accumulation_current += 1;
 
                 if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
+                    let (grads, norm) = self.clip_gradients(&model, accumulator.grads());
                     model = optim.step(lr, model, grads);
+                    grad_norm = read_grad_norm(norm, &optim);
+                    update_model_ema(&mut model_ema, &model);
                     accumulation_current = 0;
                 }
 
@@ -215,6 +252,7 @@ impl<TI> TrainEpoch<TI> {
                     self.epoch_total,
                     iteration,
                     Some(lr),
+                    grad_norm,
                 );
 
                 callback.on_train_item(item);
@@ -226,3 +264,47 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// Clips the gradients, once accumulated, before they are used by the optimizer.
+    ///
+    /// The global norm of the gradients before clipping is returned along with them.
+    fn clip_gradients<B, M>(
+        &self,
+        model: &M,
+        grads: GradientsParams,
+    ) -> (GradientsParams, Option<Tensor<B::InnerBackend, 1>>)
+    where
+        B: ADBackend,
+        M: ADModule<B>,
+    {
+        match &self.grad_clipping {
+            Some(grad_clipping) => {
+                let (grads, norm) = grad_clipping.clip_gradients_with_norm(model, grads);
+                (grads, Some(norm))
+            }
+            None => (grads, None),
+        }
+    }
+}
+
+/// Reads the norm of the gradients clipped by the learner, or else by the optimizer, so that it
+/// can be logged.
+fn read_grad_norm<B, M, O>(norm: Option<Tensor<B::InnerBackend, 1>>, optim: &O) -> Option<f64>
+where
+    B: ADBackend,
+    M: ADModule<B>,
+    O: Optimizer<M, B>,
+{
+    norm.or_else(|| optim.grad_norm())
+        .map(|norm| norm.into_scalar().elem::<f64>())
+}
+
+fn update_model_ema<B: ADBackend, M: ADModule<B>>(
+    model_ema: &mut Option<&mut ModelEma<B, M>>,
+    model: &M,
+) {
+    if let Some(model_ema) = model_ema {
+        model_ema.update(model);
+    }
+}
@@ -3,6 +3,7 @@ mod builder;
 mod classification;
 mod epoch;
 mod regression;
+mod scheduler;
 mod step;
 mod train_val;
 
@@ -13,6 +14,7 @@ pub use builder::*;
 pub use classification::*;
 pub use epoch::*;
 pub use regression::*;
+pub use scheduler::*;
 pub use step::*;
 pub use train::*;
 pub use train_val::*;
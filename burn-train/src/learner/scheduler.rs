@@ -1 +1,122 @@
+use crate::metric::{Adaptor, Metric, MetricMetadata, Numeric};
+use crate::LearnerItem;
 
+/// Validation metric reported to the [learning rate scheduler](burn_core::lr_scheduler::LRScheduler)
+/// at the end of each epoch.
+///
+/// The reported value is the mean of the metric values computed on each validation item,
+/// weighted by the size of their batch. The size of a batch is the number of items processed
+/// by the data loader since the previous item.
+pub struct LRSchedulerMetric<V> {
+    metric: Box<dyn NumericMetricUpdater<V>>,
+    sum: f64,
+    count: usize,
+    items_processed: usize,
+}
+
+impl<V> LRSchedulerMetric<V> {
+    pub(crate) fn new<M>(metric: M) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        V: Adaptor<M::Input>,
+    {
+        Self {
+            metric: Box::new(metric),
+            sum: 0.0,
+            count: 0,
+            items_processed: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, item: &LearnerItem<V>) {
+        let value = self.metric.update(item);
+        let batch_size = item
+            .progress
+            .items_processed
+            .saturating_sub(self.items_processed);
+        self.items_processed = item.progress.items_processed;
+
+        if !value.is_nan() {
+            self.sum += value * batch_size as f64;
+            self.count += batch_size;
+        }
+    }
+
+    /// Returns the value of the metric for the epoch and clears the state.
+    pub(crate) fn end_epoch(&mut self) -> Option<f64> {
+        let value = match self.count {
+            0 => None,
+            count => Some(self.sum / count as f64),
+        };
+
+        self.metric.clear();
+        self.sum = 0.0;
+        self.count = 0;
+        self.items_processed = 0;
+
+        value
+    }
+}
+
+trait NumericMetricUpdater<V>: Send + Sync {
+    fn update(&mut self, item: &LearnerItem<V>) -> f64;
+    fn clear(&mut self);
+}
+
+impl<V, M> NumericMetricUpdater<V> for M
+where
+    M: Metric + Numeric + 'static,
+    V: Adaptor<M::Input>,
+{
+    fn update(&mut self, item: &LearnerItem<V>) -> f64 {
+        let metadata = MetricMetadata::from(item);
+        Metric::update(self, &item.item.adapt(), &metadata);
+
+        self.value()
+    }
+
+    fn clear(&mut self) {
+        Metric::clear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{LossInput, LossMetric};
+    use crate::TestBackend;
+    use burn_core::data::dataloader::Progress;
+    use burn_core::tensor::Tensor;
+
+    struct Loss(f32);
+
+    impl Adaptor<LossInput<TestBackend>> for Loss {
+        fn adapt(&self) -> LossInput<TestBackend> {
+            LossInput::new(Tensor::from_data([self.0]))
+        }
+    }
+
+    fn item(loss: f32, items_processed: usize) -> LearnerItem<Loss> {
+        let progress = Progress {
+            items_processed,
+            items_total: 5,
+        };
+
+        LearnerItem::new(Loss(loss), progress, 0, 1, 0, None, None)
+    }
+
+    #[test]
+    fn test_epoch_value_should_be_weighted_by_batch_size() {
+        let mut metric = LRSchedulerMetric::new(LossMetric::<TestBackend>::new());
+
+        metric.update(&item(1.0, 4));
+        metric.update(&item(6.0, 5));
+
+        assert_eq!(metric.end_epoch(), Some(2.0));
+
+        metric.update(&item(3.0, 2));
+
+        assert_eq!(metric.end_epoch(), Some(3.0));
+        assert_eq!(metric.end_epoch(), None);
+    }
+}
@@ -81,7 +81,8 @@ where
     ///
     /// # Returns
     ///
-    /// The fitted model.
+    /// The fitted model, or the averaged model when the validation is done with the
+    /// [model EMA](burn_core::optim::ModelEma).
     pub fn fit<TI, VI>(
         mut self,
         dataloader_train: Arc<dyn DataLoader<TI>>,
@@ -97,6 +98,7 @@ where
         // The reference model is always on the first device provided.
         if let Some(device) = self.devices.get(0) {
             self.model = self.model.fork(device);
+            self.model_ema = self.model_ema.map(|model_ema| model_ema.to_device(device));
         }
 
         let starting_epoch = match self.checkpoint {
@@ -116,6 +118,7 @@ where
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
+                self.grad_clipping.clone(),
             );
 
             if self.devices.len() > 1 {
@@ -123,16 +126,34 @@ where
                     model,
                     optim,
                     &mut self.lr_scheduler,
+                    self.model_ema.as_mut(),
                     &mut self.callback,
                     self.devices.clone(),
                 )
             } else {
-                (model, optim) =
-                    epoch_train.run(model, optim, &mut self.lr_scheduler, &mut self.callback);
+                (model, optim) = epoch_train.run(
+                    model,
+                    optim,
+                    &mut self.lr_scheduler,
+                    self.model_ema.as_mut(),
+                    &mut self.callback,
+                );
             }
 
+            let model_valid = match (&self.model_ema, self.validate_with_ema) {
+                (Some(model_ema), true) => model_ema.model(),
+                _ => &model,
+            };
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run(&model, &mut self.callback);
+            let metric = epoch_valid.run(
+                model_valid,
+                &mut self.callback,
+                self.lr_scheduler_metric.as_mut(),
+            );
+
+            if let Some(metric) = metric {
+                self.lr_scheduler.report(metric);
+            }
 
             Self::checkpoint(
                 &model,
@@ -143,8 +164,12 @@ where
                 &self.checkpointer_scheduler,
                 epoch,
             );
+            Self::checkpoint_model_ema(&self.model_ema, &self.checkpointer_model_ema, epoch);
         }
 
-        model
+        match (self.model_ema, self.validate_with_ema) {
+            (Some(model_ema), true) => model_ema.into_model(),
+            _ => model,
+        }
     }
 }
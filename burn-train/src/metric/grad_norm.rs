@@ -0,0 +1,49 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+
+/// Track the global norm of the gradients before they are clipped.
+///
+/// The norm is only available when gradient clipping is enabled, either on the
+/// [learner](crate::learner::LearnerBuilder::grad_clipping) or on the optimizer.
+pub struct GradientNormMetric {
+    state: NumericMetricState,
+}
+
+impl GradientNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Default for GradientNormMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradientNormMetric {
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let norm = metadata.grad_norm.unwrap_or(0.0);
+
+        self.state
+            .update(norm, 1, FormatOptions::new("Gradient Norm").precision(3))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for GradientNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
@@ -7,11 +7,13 @@ pub mod state;
 mod acc;
 mod base;
 mod cuda;
+mod grad_norm;
 mod learning_rate;
 mod loss;
 
 pub use acc::*;
 pub use base::*;
 pub use cuda::*;
+pub use grad_norm::*;
 pub use learning_rate::*;
 pub use loss::*;
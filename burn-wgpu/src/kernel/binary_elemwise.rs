@@ -26,6 +26,39 @@ macro_rules! binary_elemwise {
             }
         }
     };
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template().register(
+                    "body",
+                    format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template()
+                    .register(
+                        "body",
+                        format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
 }
 
 /// Creates a binary elementwise inplace kernel.
@@ -46,6 +79,39 @@ macro_rules! binary_elemwise_inplace {
             }
         }
     };
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template().register(
+                    "body",
+                    format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template()
+                    .register(
+                        "body",
+                        format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
 }
 
 /// Execute a binary kernel using the default settings.
@@ -55,6 +55,15 @@ mod tests {
     burn_tensor::testgen_sin!();
     burn_tensor::testgen_tanh!();
     burn_tensor::testgen_erf!();
+    burn_tensor::testgen_abs!();
+    burn_tensor::testgen_clamp!();
+    burn_tensor::testgen_round!();
+    burn_tensor::testgen_recip!();
+    burn_tensor::testgen_atan2!();
+    burn_tensor::testgen_pow!();
+    burn_tensor::testgen_flip!();
+    burn_tensor::testgen_pad!();
+    burn_tensor::testgen_triangular!();
     burn_tensor::testgen_relu!();
     burn_tensor::testgen_matmul!();
     burn_tensor::testgen_reshape!();
@@ -1,10 +1,11 @@
 use super::numeric::NumericOps;
 use super::{BaseOps, BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
 use crate::kernel::{
-    matmul, unary_default, unary_inplace_default, unary_scalar_default,
-    unary_scalar_inplace_default,
+    binary_elemwise_default, binary_elemwise_inplace_default, matmul, unary_default,
+    unary_inplace_default, unary_scalar_default, unary_scalar_inplace_default,
 };
 use crate::unary_scalar_inplace;
+use crate::{binary_elemwise, binary_elemwise_inplace};
 use crate::{
     element::{FloatElement, IntElement},
     unary, unary_inplace, unary_scalar, GraphicsApi, WgpuBackend, SEED,
@@ -422,6 +423,129 @@ where
         unary_default::<Erf, F, D>(tensor)
     }
 
+    fn abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Abs, func "abs");
+        unary_inplace!(AbsInplace, func "abs");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<AbsInplace, F, D>(tensor);
+        }
+
+        unary_default::<Abs, F, D>(tensor)
+    }
+
+    fn sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Sign, func "sign");
+        unary_inplace!(SignInplace, func "sign");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<SignInplace, F, D>(tensor);
+        }
+
+        unary_default::<Sign, F, D>(tensor)
+    }
+
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Floor, func "floor");
+        unary_inplace!(FloorInplace, func "floor");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<FloorInplace, F, D>(tensor);
+        }
+
+        unary_default::<Floor, F, D>(tensor)
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Ceil, func "ceil");
+        unary_inplace!(CeilInplace, func "ceil");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<CeilInplace, F, D>(tensor);
+        }
+
+        unary_default::<Ceil, F, D>(tensor)
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // The WGSL round function rounds halfway values to the nearest even integer.
+        unary!(Round, func "round");
+        unary_inplace!(RoundInplace, func "round");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<RoundInplace, F, D>(tensor);
+        }
+
+        unary_default::<Round, F, D>(tensor)
+    }
+
+    fn recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Recip, body "output[id] = 1.0 / input[id];");
+        unary_inplace!(RecipInplace, body "input[id] = 1.0 / input[id];");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<RecipInplace, F, D>(tensor);
+        }
+
+        unary_default::<Recip, F, D>(tensor)
+    }
+
+    fn clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        unary_scalar!(ClampMin, func "max");
+        unary_scalar_inplace!(ClampMinInplace, func "max");
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<ClampMinInplace, F, D>(tensor, min);
+        }
+
+        unary_scalar_default::<ClampMin, F, D>(tensor, min)
+    }
+
+    fn clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        unary_scalar!(ClampMax, func "min");
+        unary_scalar_inplace!(ClampMaxInplace, func "min");
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<ClampMaxInplace, F, D>(tensor, max);
+        }
+
+        unary_scalar_default::<ClampMax, F, D>(tensor, max)
+    }
+
+    fn atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_elemwise!(Atan2, func "atan2");
+        binary_elemwise_inplace!(Atan2Inplace, func "atan2");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<Atan2Inplace, F, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<Atan2, F, D>(lhs, rhs)
+    }
+
+    fn pow<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_elemwise!(Pow, func "powf", include "../template/powf.wgsl");
+        binary_elemwise_inplace!(PowInplace, func "powf", include "../template/powf.wgsl");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<PowInplace, F, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<Pow, F, D>(lhs, rhs)
+    }
+
     fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
         BaseOps::<G>::cat(tensors, dim)
     }